@@ -0,0 +1,63 @@
+//! Offline email address syntax checks
+
+/// Whether `address` is a plausible `local@domain` mailbox
+///
+/// Deliberately loose: it catches typos such as a missing `@` or a stray
+/// space, and leaves deliverability to the verification API.
+pub(crate) fn is_valid(address: &str) -> bool {
+    let Some((local, domain)) = address.rsplit_once('@') else {
+        return false;
+    };
+    let no_space = |s: &str| !s.chars().any(|c| c.is_whitespace() || c.is_control());
+    !local.is_empty()
+        && no_space(local)
+        && !local.contains(['<', '>'])
+        && no_space(domain)
+        && domain.contains('.')
+        && domain.split('.').all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_alphanumeric() || c == '-')
+        })
+}
+
+/// Like [`is_valid`], also accepting the `Display Name <local@domain>` form
+pub(crate) fn is_valid_mailbox(mailbox: &str) -> bool {
+    let mailbox = mailbox.trim();
+    match mailbox
+        .strip_suffix('>')
+        .and_then(|rest| rest.rsplit_once('<'))
+    {
+        Some((_, address)) => is_valid(address),
+        None => is_valid(mailbox),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address_syntax() {
+        for valid in [
+            "jane@example.com",
+            "jane.doe+tag@mail.example.co.uk",
+            "a@b-c.io",
+        ] {
+            assert!(is_valid(valid), "{}", valid);
+        }
+        for invalid in [
+            "",
+            "jane",
+            "jane@",
+            "@example.com",
+            "jane@example",
+            "jane @example.com",
+            "jane@example..com",
+            "jane@exa_mple.com",
+            "Jane <jane@example.com>",
+        ] {
+            assert!(!is_valid(invalid), "{}", invalid);
+        }
+        assert!(is_valid_mailbox("Acme <notifications@acme.example>"));
+        assert!(!is_valid_mailbox("Acme <notifications>"));
+    }
+}
@@ -0,0 +1,203 @@
+//! Pluggable authentication
+//!
+//! By default requests carry the configured API key in `X-API-Key`. Enterprise
+//! accounts that authenticate with short-lived tokens can plug in an
+//! [`AuthProvider`] such as [`OAuth2ClientCredentials`].
+
+use crate::client::ClientConfig;
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Deserialize;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// Refresh tokens this long before they expire, to absorb clock skew and latency
+const EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+/// Credential attached to a single request
+#[derive(Clone, PartialEq, Eq)]
+pub enum Credential {
+    /// Sent as `X-API-Key`
+    ApiKey(String),
+    /// Sent as `Authorization: Bearer <token>`
+    Bearer(String),
+}
+
+impl std::fmt::Debug for Credential {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Credential::ApiKey(_) => f.write_str("ApiKey([REDACTED])"),
+            Credential::Bearer(_) => f.write_str("Bearer([REDACTED])"),
+        }
+    }
+}
+
+/// Supplies the credential for each request
+///
+/// Called once per request (not per retry attempt); implementations are
+/// responsible for caching and refreshing tokens.
+#[async_trait]
+pub trait AuthProvider: std::fmt::Debug + Send + Sync {
+    async fn credential(&self) -> Result<Credential>;
+
+    /// Forget `credential` after the API rejected it with a 401
+    ///
+    /// The client then asks for a fresh credential and retries the request
+    /// once, so a revoked token is replaced before it expires.
+    async fn invalidate(&self, _credential: &Credential) {}
+
+    /// Apply the client's timeout and TLS settings to requests the provider makes itself
+    ///
+    /// Called when a client is built with this provider.
+    fn configure(&self, _config: &ClientConfig) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A fixed credential, e.g. a static bearer token issued out of band
+#[async_trait]
+impl AuthProvider for Credential {
+    async fn credential(&self) -> Result<Credential> {
+        Ok(self.clone())
+    }
+}
+
+/// OAuth2 client-credentials grant with automatic token refresh
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::{MailBreeze, OAuth2ClientCredentials};
+///
+/// # fn main() -> mailbreeze::Result<()> {
+/// let client = MailBreeze::builder("")
+///     .auth(
+///         OAuth2ClientCredentials::new(
+///             "https://auth.mailbreeze.com/oauth/token",
+///             "client_id",
+///             "client_secret",
+///         )
+///         .scope("emails:send"),
+///     )
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct OAuth2ClientCredentials {
+    token_url: String,
+    client_id: String,
+    client_secret: String,
+    scope: Option<String>,
+    /// Built from the client's configuration in `configure`
+    http: OnceLock<Client>,
+    token: Mutex<Option<(String, Instant)>>,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+impl OAuth2ClientCredentials {
+    pub fn new(
+        token_url: impl Into<String>,
+        client_id: impl Into<String>,
+        client_secret: impl Into<String>,
+    ) -> Self {
+        Self {
+            token_url: token_url.into(),
+            client_id: client_id.into(),
+            client_secret: client_secret.into(),
+            scope: None,
+            http: OnceLock::new(),
+            token: Mutex::new(None),
+        }
+    }
+
+    /// Request a space-separated set of scopes
+    pub fn scope(mut self, scope: impl Into<String>) -> Self {
+        self.scope = Some(scope.into());
+        self
+    }
+
+    async fn fetch_token(&self) -> Result<(String, Instant)> {
+        let mut form = vec![
+            ("grant_type", "client_credentials"),
+            ("client_id", &self.client_id),
+            ("client_secret", &self.client_secret),
+        ];
+        if let Some(scope) = &self.scope {
+            form.push(("scope", scope));
+        }
+
+        let response = self
+            .http
+            .get_or_init(Client::new)
+            .post(&self.token_url)
+            .form(&form)
+            .send()
+            .await
+            .map_err(Error::Http)?;
+
+        if !response.status().is_success() {
+            return Err(Error::Authentication {
+                message: format!("token request failed with status {}", response.status()),
+                code: None,
+                request_id: None,
+            });
+        }
+
+        let token: TokenResponse = response.json().await.map_err(Error::Http)?;
+        // Tokens without an expiry are refreshed hourly
+        let lifetime = Duration::from_secs(token.expires_in.unwrap_or(3600));
+        let expires_at = Instant::now() + lifetime.saturating_sub(EXPIRY_MARGIN);
+        Ok((token.access_token, expires_at))
+    }
+}
+
+impl std::fmt::Debug for OAuth2ClientCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OAuth2ClientCredentials")
+            .field("token_url", &self.token_url)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"[REDACTED]")
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
+
+#[async_trait]
+impl AuthProvider for OAuth2ClientCredentials {
+    async fn credential(&self) -> Result<Credential> {
+        // Holding the lock across the refresh makes concurrent requests share one token fetch
+        let mut token = self.token.lock().await;
+        match &*token {
+            Some((access_token, expires_at)) if Instant::now() < *expires_at => {
+                Ok(Credential::Bearer(access_token.clone()))
+            }
+            _ => {
+                let (access_token, expires_at) = self.fetch_token().await?;
+                *token = Some((access_token.clone(), expires_at));
+                Ok(Credential::Bearer(access_token))
+            }
+        }
+    }
+
+    async fn invalidate(&self, credential: &Credential) {
+        let mut token = self.token.lock().await;
+        // Keep a token another request has already refreshed
+        if matches!((&*token, credential), (Some((cached, _)), Credential::Bearer(rejected)) if cached == rejected)
+        {
+            *token = None;
+        }
+    }
+
+    fn configure(&self, config: &ClientConfig) -> Result<()> {
+        // A provider shared between clients keeps the first client's settings
+        let _ = self.http.set(crate::transport::reqwest_client(config)?);
+        Ok(())
+    }
+}
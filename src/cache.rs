@@ -0,0 +1,69 @@
+//! Conditional GET cache, enabled with `ClientConfig::etag_cache`
+//!
+//! Responses carrying an `ETag` are stored by URL. Later GETs of the same URL
+//! send `If-None-Match`, and a `304 Not Modified` is answered from the cache.
+
+use crate::transport::TransportResponse;
+use reqwest::header::{HeaderMap, ETAG};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+#[derive(Debug, Default)]
+struct Entries {
+    responses: HashMap<String, TransportResponse>,
+    /// Insertion order, oldest first, for eviction
+    order: VecDeque<String>,
+}
+
+/// Bounded store of the last ETag-bearing response per URL
+#[derive(Debug)]
+pub(crate) struct ResponseCache {
+    capacity: usize,
+    entries: Mutex<Entries>,
+}
+
+impl ResponseCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            entries: Mutex::default(),
+        }
+    }
+
+    /// The ETag to send as `If-None-Match` for `key`, if a response is cached
+    pub(crate) fn etag(&self, key: &str) -> Option<String> {
+        let entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+        let response = entries.responses.get(key)?;
+        Some(etag(&response.headers)?.to_string())
+    }
+
+    /// Resolve a response: serve the cached copy for a 304, or store a fresh one
+    pub(crate) fn resolve(&self, key: &str, response: TransportResponse) -> TransportResponse {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
+
+        if response.status == reqwest::StatusCode::NOT_MODIFIED {
+            return entries.responses.get(key).cloned().unwrap_or(response);
+        }
+        if etag(&response.headers).is_none() {
+            return response;
+        }
+
+        if entries
+            .responses
+            .insert(key.to_string(), response.clone())
+            .is_none()
+        {
+            entries.order.push_back(key.to_string());
+            if entries.order.len() > self.capacity {
+                if let Some(oldest) = entries.order.pop_front() {
+                    entries.responses.remove(&oldest);
+                }
+            }
+        }
+        response
+    }
+}
+
+fn etag(headers: &HeaderMap) -> Option<&str> {
+    headers.get(ETAG)?.to_str().ok()
+}
@@ -0,0 +1,173 @@
+//! Operation classes a client may perform, see [`MailBreeze::restricted`](crate::MailBreeze::restricted)
+
+use reqwest::Method;
+use std::fmt;
+use std::ops::BitOr;
+
+/// Set of operation classes a client is allowed to perform
+///
+/// Every request is classified by method and path before it is sent; a
+/// request outside the client's set fails with
+/// [`Error::CapabilityDenied`](crate::Error::CapabilityDenied) and never
+/// reaches the network. Combine sets with `|`.
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::Capabilities;
+///
+/// let caps = Capabilities::SEND | Capabilities::READ;
+/// assert!(caps.contains(Capabilities::SEND));
+/// assert!(!caps.contains(Capabilities::CONTACTS));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Capabilities(u8);
+
+impl Capabilities {
+    /// Send emails and drafts, and upload attachments for them
+    pub const SEND: Self = Self(1);
+    /// Any GET outside email verification
+    pub const READ: Self = Self(1 << 1);
+    /// Create, update and delete contacts and lists
+    pub const CONTACTS: Self = Self(1 << 2);
+    /// Run and read email verifications
+    pub const VERIFY: Self = Self(1 << 3);
+    /// Every other write: templates, automations, webhooks, account settings
+    pub const MANAGE: Self = Self(1 << 4);
+
+    pub const NONE: Self = Self(0);
+    pub const SEND_ONLY: Self = Self::SEND;
+    pub const ALL: Self = Self(0b1_1111);
+
+    const NAMES: [(Self, &'static str); 5] = [
+        (Self::SEND, "SEND"),
+        (Self::READ, "READ"),
+        (Self::CONTACTS, "CONTACTS"),
+        (Self::VERIFY, "VERIFY"),
+        (Self::MANAGE, "MANAGE"),
+    ];
+
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub const fn intersection(self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    /// The capability needed to call `method path`, where `path` excludes `/api/v1`
+    ///
+    /// Paths are matched segment by segment, so `/attachmentsX` is not an
+    /// attachments path. The client classifies the path after `.` and `..`
+    /// segments are resolved.
+    pub fn required_for(method: &Method, path: &str) -> Self {
+        let path = path.split('?').next().unwrap_or(path);
+        let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+        let is_send = *method == Method::POST
+            && matches!(
+                segments.as_slice(),
+                ["emails"]
+                    | ["emails", "validate"]
+                    | ["emails", "broadcast"]
+                    | ["emails", "drafts", _, "send"]
+                    | ["attachments", ..]
+            );
+
+        if is_send {
+            Self::SEND
+        } else if segments[0] == "email-verification" {
+            Self::VERIFY
+        } else if *method == Method::GET {
+            Self::READ
+        } else if segments[0] == "contact-lists" {
+            Self::CONTACTS
+        } else {
+            Self::MANAGE
+        }
+    }
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl BitOr for Capabilities {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl fmt::Display for Capabilities {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<&str> = Self::NAMES
+            .iter()
+            .filter(|(cap, _)| self.contains(*cap))
+            .map(|(_, name)| *name)
+            .collect();
+        match names.is_empty() {
+            true => f.write_str("NONE"),
+            false => f.write_str(&names.join(" | ")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_required_for() {
+        let cases = [
+            (Method::POST, "/emails", Capabilities::SEND),
+            (Method::POST, "/emails/validate", Capabilities::SEND),
+            (Method::POST, "/emails/broadcast", Capabilities::SEND),
+            (Method::POST, "/emails/drafts/d_1/send", Capabilities::SEND),
+            (
+                Method::POST,
+                "/attachments/presigned-url",
+                Capabilities::SEND,
+            ),
+            (Method::POST, "/emails/drafts", Capabilities::MANAGE),
+            (Method::GET, "/emails/e_1", Capabilities::READ),
+            (Method::GET, "/email-verification/v_1", Capabilities::VERIFY),
+            (
+                Method::DELETE,
+                "/contact-lists/l_1/contacts/c_1",
+                Capabilities::CONTACTS,
+            ),
+            (Method::PUT, "/webhooks/wh_1", Capabilities::MANAGE),
+            (Method::POST, "/attachmentsX", Capabilities::MANAGE),
+            (
+                Method::POST,
+                "/emails/drafts/d_1/x/send",
+                Capabilities::MANAGE,
+            ),
+            (Method::GET, "/email-verifications", Capabilities::READ),
+        ];
+        for (method, path, expected) in cases {
+            assert_eq!(
+                Capabilities::required_for(&method, path),
+                expected,
+                "{} {}",
+                method,
+                path
+            );
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            (Capabilities::SEND | Capabilities::VERIFY).to_string(),
+            "SEND | VERIFY"
+        );
+        assert_eq!(Capabilities::NONE.to_string(), "NONE");
+    }
+}
@@ -1,8 +1,33 @@
+use crate::auth::{AuthProvider, Credential};
+use crate::cache::ResponseCache;
+use crate::capabilities::Capabilities;
+use crate::deprecation::{DeprecationHandler, DeprecationNotice};
+use crate::diagnostics::{Diagnostics, Recorder};
 use crate::error::{Error, Result};
-use reqwest::{Client, Method, Response, StatusCode};
+use crate::observer::ContactObserver;
+use crate::options::RequestOptions;
+use crate::response::{RateLimitInfo, Response, ResponseMeta};
+use crate::retry::{RetryClassifier, RetryPolicy};
+use crate::schema::{
+    schema_version, DriftTracker, SchemaDrift, SchemaDriftHandler, SCHEMA_VERSION,
+};
+use crate::secret::SecretString;
+use crate::signing::RequestSigner;
+use crate::telemetry;
+use crate::throttle::SendThrottle;
+use crate::transform::{camel_case_keys, FieldCase, ResponseTransform};
+use crate::transport::{ReqwestTransport, Transport, TransportRequest, TransportResponse};
+use bytes::Bytes;
+use futures_util::stream::{self, BoxStream, StreamExt};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Certificate, Identity, Method, StatusCode, Url};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::collections::HashMap;
-use std::time::Duration;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{Semaphore, SemaphorePermit};
+use tokio_util::sync::CancellationToken;
 
 /// API response wrapper - all responses from the API are wrapped in this structure
 #[derive(Debug, Deserialize)]
@@ -20,26 +45,143 @@ struct ApiErrorBody {
 
 const DEFAULT_BASE_URL: &str = "https://api.mailbreeze.com";
 const API_VERSION: &str = "/api/v1";
+
+/// Characters escaped in a path segment: everything but RFC 3986 unreserved
+/// characters and `@`, which the API accepts unescaped in addresses
+const PATH_SEGMENT: &percent_encoding::AsciiSet = &percent_encoding::NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~')
+    .remove(b'@');
+
 const DEFAULT_TIMEOUT_SECS: u64 = 30;
 const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_MAX_RETRY_AFTER_SECS: u64 = 60;
+/// Consecutive connect failures on the primary endpoint before switching to the fallback
+const FAILOVER_AFTER_CONNECT_FAILURES: u32 = 2;
+/// Placeholder for secrets and PII in debug output and logs
+const REDACTED: &str = "[REDACTED]";
+const USER_AGENT: &str = concat!("mailbreeze-rust/", env!("CARGO_PKG_VERSION"));
 
 /// Configuration for the MailBreeze client
 #[derive(Clone)]
 pub struct ClientConfig {
-    pub api_key: String,
+    /// Sent as `X-API-Key`; wiped from memory when the last copy is dropped
+    pub api_key: SecretString,
     pub base_url: String,
+    /// Secondary endpoint (e.g. another region) used after repeated connect failures
+    pub fallback_base_url: Option<String>,
     pub timeout: Duration,
     pub max_retries: u32,
+    /// Backoff strategy between retries
+    pub retry_policy: RetryPolicy,
+    /// Automatically retry 429 responses after the server's `Retry-After` delay
+    pub retry_rate_limits: bool,
+    /// Longest `Retry-After` delay the client will wait out before giving up
+    pub max_retry_after: Duration,
+    /// Wall-clock budget for a request across all attempts and backoff sleeps
+    pub retry_deadline: Option<Duration>,
+    /// Decides which errors are retried, replacing `Error::is_retryable`
+    pub retry_classifier: Option<Arc<dyn RetryClassifier>>,
+    /// Credential source used instead of `api_key`, e.g. OAuth2 client credentials
+    pub auth: Option<Arc<dyn AuthProvider>>,
+    /// Notified after successful contact mutations
+    pub contact_observer: Option<Arc<dyn ContactObserver>>,
+    /// Log a redacted summary of every request at debug level (target `mailbreeze`)
+    pub debug_logging: bool,
+    /// Extra trusted root certificates, e.g. for an inspecting egress proxy (default transport only)
+    pub root_certificates: Vec<Certificate>,
+    /// Client certificate presented for mutual TLS (default transport only)
+    pub client_identity: Option<Identity>,
+    /// Custom HTTP transport; defaults to [`ReqwestTransport`] when unset
+    pub transport: Option<Arc<dyn Transport>>,
+    /// Adds signature headers to every attempt, e.g. [`HmacSigner`](crate::HmacSigner)
+    pub signer: Option<Arc<dyn RequestSigner>>,
+    /// Called when a response carries `Deprecation`/`Sunset` headers; logs a warning when unset
+    pub on_deprecation: Option<DeprecationHandler>,
+    /// Called once per newer server schema version; logs a warning when unset
+    pub on_schema_drift: Option<SchemaDriftHandler>,
+    /// Record per-endpoint latency and retry statistics, see [`HttpClient::diagnostics`]
+    pub diagnostics: bool,
+    /// Accept gzip/brotli-encoded responses (default transport only)
+    pub decompress_responses: bool,
+    /// Gzip request bodies larger than this many bytes
+    pub compress_requests_over: Option<usize>,
+    /// Cap on requests in flight at once; further requests wait their turn in FIFO order
+    pub max_concurrent_requests: Option<usize>,
+    /// Race a second attempt when a GET has had no response after this long
+    pub hedge_after: Option<Duration>,
+    /// Cache up to this many ETag-bearing GET responses and revalidate them with `If-None-Match`
+    pub etag_cache: Option<usize>,
+    /// Reject request bodies larger than this many bytes (after compression) before sending
+    pub max_body_size: Option<usize>,
+    /// Reject sends with more to/cc/bcc recipients than this before sending
+    pub max_recipients: Option<usize>,
+    /// Send every email in test mode: accepted and recorded, never delivered
+    pub sandbox: bool,
+    /// Pace sends to this many messages per minute per `from` domain
+    pub send_rate_per_domain: Option<u32>,
+    /// Per-domain messages-per-minute caps, overriding `send_rate_per_domain`
+    pub domain_send_rates: Vec<(String, u32)>,
+    /// Headers added to every request, before any per-request headers
+    pub default_headers: Vec<(String, String)>,
+    /// Application identifier (`name/version`) appended to the User-Agent
+    pub app_info: Option<String>,
+    /// Sent as `MailBreeze-Version` to pin response shapes to a dated API version
+    pub api_version: Option<String>,
+    /// Field naming of response bodies, for MailBreeze-compatible gateways
+    pub response_case: FieldCase,
+    /// Called with every JSON response body before it is decoded
+    pub transform_response: Option<ResponseTransform>,
 }
 
 // Custom Debug implementation that redacts the API key
 impl std::fmt::Debug for ClientConfig {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("ClientConfig")
-            .field("api_key", &"[REDACTED]")
+            .field("api_key", &REDACTED)
             .field("base_url", &self.base_url)
+            .field("fallback_base_url", &self.fallback_base_url)
             .field("timeout", &self.timeout)
             .field("max_retries", &self.max_retries)
+            .field("retry_policy", &self.retry_policy)
+            .field("retry_rate_limits", &self.retry_rate_limits)
+            .field("max_retry_after", &self.max_retry_after)
+            .field("retry_deadline", &self.retry_deadline)
+            .field("retry_classifier", &self.retry_classifier.is_some())
+            .field("auth", &self.auth)
+            .field("contact_observer", &self.contact_observer)
+            .field("root_certificates", &self.root_certificates.len())
+            .field("client_identity", &self.client_identity.is_some())
+            .field("transport", &self.transport)
+            .field("signer", &self.signer)
+            .field("on_deprecation", &self.on_deprecation.is_some())
+            .field("on_schema_drift", &self.on_schema_drift.is_some())
+            .field("diagnostics", &self.diagnostics)
+            .field("debug_logging", &self.debug_logging)
+            .field("decompress_responses", &self.decompress_responses)
+            .field("compress_requests_over", &self.compress_requests_over)
+            .field("max_concurrent_requests", &self.max_concurrent_requests)
+            .field("hedge_after", &self.hedge_after)
+            .field("etag_cache", &self.etag_cache)
+            .field("max_body_size", &self.max_body_size)
+            .field("max_recipients", &self.max_recipients)
+            .field("sandbox", &self.sandbox)
+            .field("send_rate_per_domain", &self.send_rate_per_domain)
+            .field("domain_send_rates", &self.domain_send_rates)
+            .field(
+                "default_headers",
+                &self
+                    .default_headers
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>(),
+            )
+            .field("app_info", &self.app_info)
+            .field("api_version", &self.api_version)
+            .field("response_case", &self.response_case)
+            .field("transform_response", &self.transform_response.is_some())
             .finish()
     }
 }
@@ -47,10 +189,41 @@ impl std::fmt::Debug for ClientConfig {
 impl ClientConfig {
     pub fn new(api_key: impl Into<String>) -> Self {
         Self {
-            api_key: api_key.into(),
+            api_key: SecretString::new(api_key),
             base_url: DEFAULT_BASE_URL.to_string(),
+            fallback_base_url: None,
             timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
             max_retries: DEFAULT_MAX_RETRIES,
+            retry_policy: RetryPolicy::default(),
+            retry_rate_limits: false,
+            max_retry_after: Duration::from_secs(DEFAULT_MAX_RETRY_AFTER_SECS),
+            retry_deadline: None,
+            retry_classifier: None,
+            auth: None,
+            contact_observer: None,
+            root_certificates: Vec::new(),
+            client_identity: None,
+            transport: None,
+            signer: None,
+            on_deprecation: None,
+            on_schema_drift: None,
+            diagnostics: false,
+            debug_logging: false,
+            decompress_responses: true,
+            compress_requests_over: None,
+            max_concurrent_requests: None,
+            hedge_after: None,
+            etag_cache: None,
+            max_body_size: None,
+            max_recipients: None,
+            sandbox: false,
+            send_rate_per_domain: None,
+            domain_send_rates: Vec::new(),
+            default_headers: Vec::new(),
+            app_info: None,
+            api_version: None,
+            response_case: FieldCase::default(),
+            transform_response: None,
         }
     }
 
@@ -59,6 +232,11 @@ impl ClientConfig {
         self
     }
 
+    pub fn fallback_base_url(mut self, url: impl Into<String>) -> Self {
+        self.fallback_base_url = Some(url.into());
+        self
+    }
+
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.timeout = timeout;
         self
@@ -68,40 +246,365 @@ impl ClientConfig {
         self.max_retries = retries;
         self
     }
+
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    pub fn retry_rate_limits(mut self, enabled: bool) -> Self {
+        self.retry_rate_limits = enabled;
+        self
+    }
+
+    pub fn max_retry_after(mut self, max: Duration) -> Self {
+        self.max_retry_after = max;
+        self
+    }
+
+    pub fn auth(mut self, provider: impl AuthProvider + 'static) -> Self {
+        self.auth = Some(Arc::new(provider));
+        self
+    }
+
+    pub fn contact_observer(mut self, observer: impl ContactObserver + 'static) -> Self {
+        self.contact_observer = Some(Arc::new(observer));
+        self
+    }
+
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    pub fn client_identity(mut self, identity: Identity) -> Self {
+        self.client_identity = Some(identity);
+        self
+    }
+
+    pub fn retry_deadline(mut self, budget: Duration) -> Self {
+        self.retry_deadline = Some(budget);
+        self
+    }
+
+    pub fn retry_classifier(mut self, classifier: impl RetryClassifier + 'static) -> Self {
+        self.retry_classifier = Some(Arc::new(classifier));
+        self
+    }
+
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    pub fn signer(mut self, signer: impl RequestSigner + 'static) -> Self {
+        self.signer = Some(Arc::new(signer));
+        self
+    }
+
+    pub fn on_deprecation(
+        mut self,
+        handler: impl Fn(&DeprecationNotice) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_deprecation = Some(Arc::new(handler));
+        self
+    }
+
+    pub fn on_schema_drift(
+        mut self,
+        handler: impl Fn(&SchemaDrift) + Send + Sync + 'static,
+    ) -> Self {
+        self.on_schema_drift = Some(Arc::new(handler));
+        self
+    }
+
+    pub fn diagnostics(mut self, enabled: bool) -> Self {
+        self.diagnostics = enabled;
+        self
+    }
+
+    pub fn debug_logging(mut self, enabled: bool) -> Self {
+        self.debug_logging = enabled;
+        self
+    }
+
+    pub fn decompress_responses(mut self, enabled: bool) -> Self {
+        self.decompress_responses = enabled;
+        self
+    }
+
+    pub fn compress_requests_over(mut self, bytes: usize) -> Self {
+        self.compress_requests_over = Some(bytes);
+        self
+    }
+
+    pub fn max_concurrent_requests(mut self, requests: usize) -> Self {
+        self.max_concurrent_requests = Some(requests);
+        self
+    }
+
+    pub fn hedge_after(mut self, threshold: Duration) -> Self {
+        self.hedge_after = Some(threshold);
+        self
+    }
+
+    pub fn etag_cache(mut self, entries: usize) -> Self {
+        self.etag_cache = Some(entries);
+        self
+    }
+
+    pub fn max_body_size(mut self, bytes: usize) -> Self {
+        self.max_body_size = Some(bytes);
+        self
+    }
+
+    pub fn max_recipients(mut self, recipients: usize) -> Self {
+        self.max_recipients = Some(recipients);
+        self
+    }
+
+    pub fn sandbox(mut self, sandbox: bool) -> Self {
+        self.sandbox = sandbox;
+        self
+    }
+
+    pub fn send_rate_per_domain(mut self, per_minute: u32) -> Self {
+        self.send_rate_per_domain = Some(per_minute);
+        self
+    }
+
+    pub fn domain_send_rate(mut self, domain: impl Into<String>, per_minute: u32) -> Self {
+        self.domain_send_rates.push((domain.into(), per_minute));
+        self
+    }
+
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn app_info(mut self, name: impl Into<String>, version: impl Into<String>) -> Self {
+        self.app_info = Some(format!("{}/{}", name.into(), version.into()));
+        self
+    }
+
+    pub fn api_version(mut self, version: impl Into<String>) -> Self {
+        self.api_version = Some(version.into());
+        self
+    }
+
+    pub fn response_case(mut self, case: FieldCase) -> Self {
+        self.response_case = case;
+        self
+    }
+
+    pub fn transform_response(
+        mut self,
+        transform: impl Fn(&mut serde_json::Value) + Send + Sync + 'static,
+    ) -> Self {
+        self.transform_response = Some(Arc::new(transform));
+        self
+    }
 }
 
 /// HTTP client for MailBreeze API
 #[derive(Debug, Clone)]
 pub struct HttpClient {
-    client: Client,
+    transport: Arc<dyn Transport>,
     config: ClientConfig,
+    /// Options applied to every request made through this client
+    options: RequestOptions,
+    /// Shared statistics recorder when diagnostics are enabled
+    diagnostics: Option<Arc<Recorder>>,
+    /// Metadata of the most recent response, shared with derived clients
+    last_response: Arc<Mutex<Option<ResponseMeta>>>,
+    /// Permits for `max_concurrent_requests`, shared with derived clients
+    limiter: Option<Arc<Semaphore>>,
+    /// Conditional GET cache; shared with `with_options` clients but not across API keys
+    cache: Option<Arc<ResponseCache>>,
+    /// Per-domain send pacing, shared with derived clients
+    send_throttle: Option<Arc<SendThrottle>>,
+    /// Operations this client may perform; narrowed by `restricted`, never widened
+    capabilities: Capabilities,
+    /// Newest server schema version already reported, shared with derived clients
+    schema_drift: Arc<DriftTracker>,
 }
 
 impl HttpClient {
     /// Create a new HTTP client with the given configuration
     pub fn new(config: ClientConfig) -> Result<Self> {
-        let client = Client::builder()
-            .timeout(config.timeout)
-            .build()
-            .map_err(Error::Http)?;
+        if let Some(provider) = &config.auth {
+            provider.configure(&config)?;
+        }
+        let transport = match &config.transport {
+            Some(transport) => transport.clone(),
+            None => Arc::new(ReqwestTransport::from_config(&config)?),
+        };
+
+        let diagnostics = config.diagnostics.then(Arc::default);
+        let limiter = config
+            .max_concurrent_requests
+            .map(|n| Arc::new(Semaphore::new(n.max(1))));
+        let cache = config
+            .etag_cache
+            .map(|entries| Arc::new(ResponseCache::new(entries)));
+        let send_throttle =
+            SendThrottle::new(config.send_rate_per_domain, &config.domain_send_rates).map(Arc::new);
+
+        Ok(Self {
+            transport,
+            config,
+            options: RequestOptions::default(),
+            diagnostics,
+            last_response: Arc::default(),
+            limiter,
+            cache,
+            send_throttle,
+            capabilities: Capabilities::ALL,
+            schema_drift: Arc::default(),
+        })
+    }
+
+    /// Derive a client that applies `options` to every request
+    ///
+    /// The underlying transport (and its connection pool) is shared.
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self {
+            transport: self.transport.clone(),
+            config: self.config.clone(),
+            options: self.options.merge(&options),
+            diagnostics: self.diagnostics.clone(),
+            last_response: self.last_response.clone(),
+            limiter: self.limiter.clone(),
+            cache: self.cache.clone(),
+            send_throttle: self.send_throttle.clone(),
+            capabilities: self.capabilities,
+            schema_drift: self.schema_drift.clone(),
+        }
+    }
+
+    /// Derive a client that authenticates with a different API key
+    ///
+    /// The transport and its connection pool are shared; the last response
+    /// metadata is not, since rate limits apply per key. Any configured
+    /// [`AuthProvider`] is dropped in favour of the key.
+    pub fn with_api_key(&self, api_key: impl Into<String>) -> Self {
+        let mut config = self.config.clone();
+        config.api_key = SecretString::new(api_key);
+        config.auth = None;
+        Self {
+            transport: self.transport.clone(),
+            config,
+            options: self.options.clone(),
+            diagnostics: self.diagnostics.clone(),
+            last_response: Arc::default(),
+            limiter: self.limiter.clone(),
+            // Never serve one account's cached responses to another
+            cache: self
+                .config
+                .etag_cache
+                .map(|entries| Arc::new(ResponseCache::new(entries))),
+            // Receiving ISPs see the domain, not the key
+            send_throttle: self.send_throttle.clone(),
+            capabilities: self.capabilities,
+            schema_drift: self.schema_drift.clone(),
+        }
+    }
+
+    /// Derive a client limited to `capabilities`
+    ///
+    /// The result can only narrow this client's capabilities, never widen
+    /// them, and clients derived from it keep the restriction.
+    pub fn restricted(&self, capabilities: Capabilities) -> Self {
+        let mut client = self.with_options(RequestOptions::default());
+        client.capabilities = self.capabilities.intersection(capabilities);
+        client
+    }
+
+    /// Rate-limit headers from the most recent response
+    ///
+    /// Use `remaining` to pace bulk sends before the API starts returning 429s.
+    pub fn last_rate_limit(&self) -> Option<RateLimitInfo> {
+        self.last_response().and_then(|meta| meta.rate_limit)
+    }
 
-        Ok(Self { client, config })
+    /// Status and request ID of the most recent API response
+    pub fn last_response(&self) -> Option<ResponseMeta> {
+        self.last_response
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// The configuration this client was built with
+    pub fn config(&self) -> &ClientConfig {
+        &self.config
+    }
+
+    /// Latency percentiles and retry ratios recorded so far
+    ///
+    /// Returns `None` unless `ClientConfig::diagnostics` is enabled.
+    pub fn diagnostics(&self) -> Option<Diagnostics> {
+        self.diagnostics
+            .as_ref()
+            .map(|recorder| recorder.snapshot())
     }
 
     /// Perform a GET request
     pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
-        self.request_impl(Method::GET, path, None, None).await
+        self.request_impl(
+            Method::GET,
+            path,
+            None::<&()>,
+            None,
+            &RequestOptions::default(),
+        )
+        .await
+    }
+
+    /// Perform a GET request, also returning the response's `ETag`
+    ///
+    /// Pass the ETag to [`RequestOptions::if_match`] to make a later change conditional.
+    pub async fn get_with_etag<T: DeserializeOwned>(
+        &self,
+        path: &str,
+    ) -> Result<(T, Option<String>)> {
+        let response = self
+            .execute(Method::GET, path, None, None, &RequestOptions::default())
+            .await?;
+        let etag = response
+            .headers
+            .get(reqwest::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.to_string());
+        Ok((self.handle_response(response)?, etag))
+    }
+
+    /// Perform a GET request, keeping the envelope and header metadata
+    pub async fn get_with_meta<T: DeserializeOwned>(&self, path: &str) -> Result<Response<T>> {
+        self.request_with_meta(Method::GET, path, None::<&()>, &RequestOptions::default())
+            .await
     }
 
     /// Perform a GET request with query parameters
+    ///
+    /// `params` are encoded with `serde_qs`: numbers and enums as bare values,
+    /// sequences as repeated keys (`statuses=sent&statuses=bounced`) and
+    /// nested filters with bracketed keys (`created_at[gte]=2024-01-01`).
     pub async fn get_with_params<T, Q>(&self, path: &str, params: &Q) -> Result<T>
     where
         T: DeserializeOwned,
         Q: Serialize,
     {
-        let query = serde_json::to_value(params).ok();
-        self.request_impl(Method::GET, path, None, query.as_ref())
-            .await
+        let query = crate::query::to_pairs(params)?;
+        self.request_impl(
+            Method::GET,
+            path,
+            None::<&()>,
+            Some(&query),
+            &RequestOptions::default(),
+        )
+        .await
     }
 
     /// Perform a POST request
@@ -110,14 +613,50 @@ impl HttpClient {
         T: DeserializeOwned,
         B: Serialize,
     {
-        let body_value = serde_json::to_value(body)?;
-        self.request_impl(Method::POST, path, Some(&body_value), None)
+        self.post_with_options(path, body, &RequestOptions::default())
+            .await
+    }
+
+    /// Perform a POST request with per-request options (e.g. an idempotency key)
+    pub async fn post_with_options<T, B>(
+        &self,
+        path: &str,
+        body: &B,
+        options: &RequestOptions,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        self.request_impl(Method::POST, path, Some(body), None, options)
+            .await
+    }
+
+    /// Perform a POST request, keeping the envelope and header metadata
+    pub async fn post_with_meta<T, B>(
+        &self,
+        path: &str,
+        body: &B,
+        options: &RequestOptions,
+    ) -> Result<Response<T>>
+    where
+        T: DeserializeOwned,
+        B: Serialize,
+    {
+        self.request_with_meta(Method::POST, path, Some(body), options)
             .await
     }
 
     /// Perform a POST request without a body
     pub async fn post_empty<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
-        self.request_impl(Method::POST, path, None, None).await
+        self.request_impl(
+            Method::POST,
+            path,
+            None::<&()>,
+            None,
+            &RequestOptions::default(),
+        )
+        .await
     }
 
     /// Perform a PATCH request
@@ -126,9 +665,14 @@ impl HttpClient {
         T: DeserializeOwned,
         B: Serialize,
     {
-        let body_value = serde_json::to_value(body)?;
-        self.request_impl(Method::PATCH, path, Some(&body_value), None)
-            .await
+        self.request_impl(
+            Method::PATCH,
+            path,
+            Some(body),
+            None,
+            &RequestOptions::default(),
+        )
+        .await
     }
 
     /// Perform a PUT request
@@ -137,229 +681,836 @@ impl HttpClient {
         T: DeserializeOwned,
         B: Serialize,
     {
-        let body_value = serde_json::to_value(body)?;
-        self.request_impl(Method::PUT, path, Some(&body_value), None)
-            .await
+        self.request_impl(
+            Method::PUT,
+            path,
+            Some(body),
+            None,
+            &RequestOptions::default(),
+        )
+        .await
     }
 
     /// Perform a DELETE request
     pub async fn delete(&self, path: &str) -> Result<()> {
-        self.request_no_response(Method::DELETE, path, None).await
+        self.request_no_response(
+            Method::DELETE,
+            path,
+            None::<&()>,
+            &RequestOptions::default(),
+        )
+        .await
     }
 
     /// Perform a POST request with body but expecting no response body (204 No Content)
     pub async fn post_no_response<B: Serialize>(&self, path: &str, body: &B) -> Result<()> {
-        let body_value = serde_json::to_value(body)?;
-        self.request_no_response(Method::POST, path, Some(&body_value))
+        self.request_no_response(Method::POST, path, Some(body), &RequestOptions::default())
             .await
     }
 
     /// Internal request implementation
-    async fn request_impl<T: DeserializeOwned>(
+    ///
+    /// The body is serialized straight to JSON bytes once, keeping field order,
+    /// and the same bytes are reused for every retry.
+    async fn request_impl<T, B>(
         &self,
         method: Method,
         path: &str,
-        body: Option<&serde_json::Value>,
-        query: Option<&serde_json::Value>,
-    ) -> Result<T> {
-        let url = format!("{}{}{}", self.config.base_url, API_VERSION, path);
+        body: Option<&B>,
+        query: Option<&[(String, String)]>,
+        options: &RequestOptions,
+    ) -> Result<T>
+    where
+        T: DeserializeOwned,
+        B: Serialize + ?Sized,
+    {
+        let body = body.map(serde_json::to_vec).transpose()?;
+        let response = self.execute(method, path, body, query, options).await?;
+        self.handle_response(response)
+    }
+
+    /// Like `request_impl`, wrapping the data in a [`Response`]
+    async fn request_with_meta<T, B>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+        options: &RequestOptions,
+    ) -> Result<Response<T>>
+    where
+        T: DeserializeOwned,
+        B: Serialize + ?Sized,
+    {
+        let body = body.map(serde_json::to_vec).transpose()?;
+        let response = self
+            .execute(method.clone(), path, body, None, options)
+            .await?;
+        let meta = Response::new((), method.as_str(), path, &response);
+        let data = self.handle_response(response)?;
+        Ok(meta.map(|()| data))
+    }
+
+    /// Perform a request that expects no response body
+    async fn request_no_response<B: Serialize + ?Sized>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&B>,
+        options: &RequestOptions,
+    ) -> Result<()> {
+        let body = body.map(serde_json::to_vec).transpose()?;
+        self.execute(method, path, body, None, options).await?;
+        Ok(())
+    }
+
+    /// Check the client's capabilities and build the request with its credential
+    async fn authorized_request(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<Vec<u8>>,
+        query: Option<&[(String, String)]>,
+        options: &RequestOptions,
+    ) -> Result<(TransportRequest, Option<Credential>)> {
+        // Classify the path the request will reach, after `..` is resolved
+        let url = self.request_url(path)?;
+        let api_path = self.api_path(&url)?;
+        let required = Capabilities::required_for(&method, api_path);
+        if !self.capabilities.contains(required) {
+            return Err(Error::CapabilityDenied {
+                required,
+                method: method.to_string(),
+                path: redact_path(api_path),
+            });
+        }
+        // Without a provider the configured key is read in place, never copied
+        let credential = match &self.config.auth {
+            Some(provider) => Some(provider.credential().await?),
+            None => None,
+        };
+        let request =
+            self.build_request(method, path, credential.as_ref(), body, query, options)?;
+        Ok((request, credential))
+    }
+
+    /// GET `path`, handing over the response body as it arrives
+    ///
+    /// Failures before the response headers arrive are retried like
+    /// [`execute`](Self::execute); once the body is streaming, an error ends
+    /// the stream. The request holds a `max_concurrent_requests` slot until
+    /// the body is finished, and is never hedged or cached.
+    pub(crate) async fn execute_stream(
+        &self,
+        path: &str,
+        query: Option<&[(String, String)]>,
+        options: &RequestOptions,
+    ) -> Result<BoxStream<'static, Result<Bytes>>> {
+        let method = Method::GET;
+        let (mut request, _) = self
+            .authorized_request(method.clone(), path, None, query, options)
+            .await?;
+        let cancellation = options
+            .cancellation
+            .as_ref()
+            .or(self.options.cancellation.as_ref());
+        let request_started = Instant::now();
         let mut attempt = 0;
 
         loop {
             attempt += 1;
-
-            let mut request = self.client.request(method.clone(), &url);
-            request = request
-                .header("X-API-Key", &self.config.api_key)
-                .header("Content-Type", "application/json")
-                .header("Accept", "application/json")
-                .header("User-Agent", "mailbreeze-rust/0.2.0");
-
-            if let Some(b) = body {
-                request = request.json(b);
+            if let Some(signer) = &self.config.signer {
+                signer.sign(&mut request)?;
             }
 
-            if let Some(q) = query {
-                if let Some(obj) = q.as_object() {
-                    for (key, value) in obj {
-                        if let Some(s) = value.as_str() {
-                            request = request.query(&[(key, s)]);
-                        } else if !value.is_null() {
-                            request = request.query(&[(key, value.to_string())]);
-                        }
-                    }
+            let started = Instant::now();
+            let attempted = cancellable(cancellation, async {
+                let permit = match &self.limiter {
+                    Some(limiter) => limiter.clone().acquire_owned().await.ok(),
+                    None => None,
+                };
+                let response = self.transport.send_streaming(request.clone()).await?;
+                Ok((response, permit))
+            })
+            .await;
+            let status = attempted.as_ref().ok().map(|(r, _)| r.status.as_u16());
+            telemetry::record_attempt(&method, path, status, started.elapsed());
+
+            let error = match attempted {
+                Ok((response, permit)) if response.status.is_success() => {
+                    // Header-only view for the checks that never read the body
+                    let head = TransportResponse {
+                        status: response.status,
+                        headers: response.headers.clone(),
+                        body: Vec::new(),
+                    };
+                    *self.last_response.lock().unwrap_or_else(|e| e.into_inner()) =
+                        Some(ResponseMeta::from_response(&head));
+                    self.check_deprecation(&method, path, &head);
+                    self.record_request(&method, path, true);
+                    self.log_request(&method, path, status, request_started.elapsed(), attempt);
+                    // Carry the permit along until the body ends or is dropped
+                    let body =
+                        stream::unfold((response.body, permit), |(mut body, permit)| async move {
+                            let chunk = body.next().await?;
+                            Some((chunk, (body, permit)))
+                        });
+                    return Ok(body.boxed());
                 }
-            }
+                Ok((response, _)) => match response.buffer().await {
+                    Ok(response) => self.parse_error_response(&response),
+                    Err(e) => e,
+                },
+                Err(e) => e,
+            };
 
-            let response = match request.send().await {
-                Ok(resp) => resp,
-                Err(e) => {
-                    if attempt < self.config.max_retries && (e.is_connect() || e.is_timeout()) {
-                        self.wait_before_retry(attempt).await;
-                        continue;
+            let error = match self.retry_delay(&method, &error, attempt) {
+                Some(delay) => {
+                    telemetry::record_retry(&method, path);
+                    let sleep = async {
+                        tokio::time::sleep(delay).await;
+                        Ok(())
+                    };
+                    match cancellable(cancellation, sleep).await {
+                        Ok(()) => continue,
+                        Err(cancelled) => cancelled,
                     }
-                    return Err(Error::Http(e));
                 }
+                None => error,
             };
-
-            match self.handle_response(response).await {
-                Ok(data) => return Ok(data),
-                Err(e) if e.is_retryable() && attempt < self.config.max_retries => {
-                    self.wait_before_retry(attempt).await;
-                    continue;
-                }
-                Err(e) => return Err(e),
-            }
+            self.record_request(&method, path, false);
+            self.log_request(&method, path, status, request_started.elapsed(), attempt);
+            return Err(error);
         }
     }
 
-    /// Perform a request that expects no response body
-    async fn request_no_response(
+    /// Send a request through the transport, retrying transient failures
+    ///
+    /// Returns the first successful (2xx or 304) response, or the error from
+    /// the last attempt.
+    pub(crate) async fn execute(
         &self,
         method: Method,
         path: &str,
-        body: Option<&serde_json::Value>,
-    ) -> Result<()> {
-        let url = format!("{}{}{}", self.config.base_url, API_VERSION, path);
+        body: Option<Vec<u8>>,
+        query: Option<&[(String, String)]>,
+        options: &RequestOptions,
+    ) -> Result<TransportResponse> {
+        let (mut request, mut credential) = self
+            .authorized_request(method.clone(), path, body, query, options)
+            .await?;
+        let mut fallback_url = self.fallback_url(&request.url, path)?;
+        let cache_key = self.cache_key(&request);
+        if let (Some(cache), Some(key)) = (&self.cache, &cache_key) {
+            if let Some(etag) = cache.etag(key) {
+                self.insert_header(&mut request.headers, "If-None-Match", &etag)?;
+            }
+        }
+        let span = telemetry::RequestSpan::start(&method, path, &mut request);
+        let request_started = Instant::now();
+        let deadline = self
+            .config
+            .retry_deadline
+            .map(|budget| tokio::time::Instant::from_std(request_started + budget));
+        let cancellation = options
+            .cancellation
+            .as_ref()
+            .or(self.options.cancellation.as_ref());
         let mut attempt = 0;
+        let mut connect_failures = 0;
+        let mut reauthenticated = false;
 
         loop {
             attempt += 1;
+            if let Some(signer) = &self.config.signer {
+                signer.sign(&mut request)?;
+            }
 
-            let mut request = self
-                .client
-                .request(method.clone(), &url)
-                .header("X-API-Key", &self.config.api_key)
-                .header("Content-Type", "application/json")
-                .header("Accept", "application/json")
-                .header("User-Agent", "mailbreeze-rust/0.2.0");
+            let attempted = cancellable(cancellation, async {
+                let send = async {
+                    let _permit = self.acquire_permit().await;
+                    let started = Instant::now();
+                    let result = self.send_hedged(&request).await;
+                    (result, started.elapsed())
+                };
+                Ok(match deadline {
+                    // Cut the attempt short rather than overrun the budget
+                    Some(deadline) => tokio::time::timeout_at(deadline, send)
+                        .await
+                        .unwrap_or_else(|_| (Err(Error::Timeout), Duration::ZERO)),
+                    None => send.await,
+                })
+            })
+            .await;
+            // A cancelled attempt never reached the transport
+            let (result, elapsed) = attempted.unwrap_or_else(|e| (Err(e), Duration::ZERO));
+            let status = result.as_ref().ok().map(|r| r.status.as_u16());
+            telemetry::record_attempt(&method, path, status, elapsed);
+            if let Some(recorder) = &self.diagnostics {
+                recorder.record_attempt(&method, path, elapsed);
+            }
 
-            if let Some(b) = body {
-                request = request.json(b);
+            if let Ok(response) = &result {
+                *self.last_response.lock().unwrap_or_else(|e| e.into_inner()) =
+                    Some(ResponseMeta::from_response(response));
+                self.check_deprecation(&method, path, response);
+                self.check_schema_version(&method, path, response);
             }
 
-            let response = match request.send().await {
-                Ok(resp) => resp,
-                Err(e) => {
-                    if attempt < self.config.max_retries && (e.is_connect() || e.is_timeout()) {
-                        self.wait_before_retry(attempt).await;
-                        continue;
+            let error = match result {
+                Ok(response)
+                    if response.status.is_success()
+                        || response.status == StatusCode::NOT_MODIFIED =>
+                {
+                    span.finish(status, None, attempt);
+                    self.record_request(&method, path, true);
+                    self.log_request(&method, path, status, request_started.elapsed(), attempt);
+                    return Ok(match (&self.cache, &cache_key) {
+                        (Some(cache), Some(key)) => cache.resolve(key, response),
+                        _ => response,
+                    });
+                }
+                Ok(response) => self.parse_error_response(&response),
+                Err(e) => e,
+            };
+
+            // A rejected provider credential may have been revoked: replace it once
+            let error = match (&error, &self.config.auth, &credential) {
+                (Error::Authentication { .. }, Some(provider), Some(rejected))
+                    if !reauthenticated =>
+                {
+                    reauthenticated = true;
+                    provider.invalidate(rejected).await;
+                    match provider.credential().await {
+                        Ok(fresh) => {
+                            self.insert_credential(&mut request.headers, Some(&fresh))?;
+                            credential = Some(fresh);
+                            continue;
+                        }
+                        Err(e) => e,
                     }
-                    return Err(Error::Http(e));
                 }
+                _ => error,
             };
 
-            let status = response.status();
-            if status == StatusCode::NO_CONTENT || status.is_success() {
-                return Ok(());
+            if is_connect_failure(&error) {
+                connect_failures += 1;
+                if connect_failures >= FAILOVER_AFTER_CONNECT_FAILURES {
+                    if let Some(url) = fallback_url.take() {
+                        log::warn!(
+                            target: "mailbreeze",
+                            "{} {} failing over to {}",
+                            method,
+                            redact_path(path),
+                            url.origin().ascii_serialization()
+                        );
+                        request.url = url;
+                    }
+                }
+            } else {
+                connect_failures = 0;
             }
 
-            let error = self.parse_error_response(response).await?;
-            if error.is_retryable() && attempt < self.config.max_retries {
-                self.wait_before_retry(attempt).await;
-                continue;
+            if matches!(error, Error::RateLimit { .. }) {
+                telemetry::record_rate_limit(&method, path);
             }
+
+            let within_deadline = |delay: &Duration| match deadline {
+                Some(deadline) => tokio::time::Instant::now() + *delay < deadline,
+                None => true,
+            };
+            let error = match self
+                .retry_delay(&method, &error, attempt)
+                .filter(within_deadline)
+            {
+                Some(delay) => {
+                    telemetry::record_retry(&method, path);
+                    let sleep = async {
+                        tokio::time::sleep(delay).await;
+                        Ok(())
+                    };
+                    match cancellable(cancellation, sleep).await {
+                        Ok(()) => continue,
+                        Err(cancelled) => cancelled,
+                    }
+                }
+                None => error,
+            };
+            span.finish(status, Some(&error), attempt);
+            self.record_request(&method, path, false);
+            self.log_request(&method, path, status, request_started.elapsed(), attempt);
             return Err(error);
         }
     }
 
-    /// Handle the response and parse JSON or error
-    async fn handle_response<T: DeserializeOwned>(&self, response: Response) -> Result<T> {
-        let status = response.status();
+    /// `url` rebased onto `fallback_base_url`, keeping its query string
+    fn fallback_url(&self, url: &Url, path: &str) -> Result<Option<Url>> {
+        let Some(base) = &self.config.fallback_base_url else {
+            return Ok(None);
+        };
+        let mut fallback = Url::parse(&format!("{}{}{}", base, API_VERSION, path))
+            .map_err(|e| Error::InvalidUrl(e.to_string()))?;
+        fallback.set_query(url.query());
+        Ok(Some(fallback))
+    }
 
-        if status.is_success() {
-            let text = response.text().await.map_err(Error::Http)?;
-            if text.is_empty() {
-                return Err(Error::Json(serde_json::Error::io(std::io::Error::new(
-                    std::io::ErrorKind::InvalidData,
-                    "Empty response body",
-                ))));
-            }
+    /// Send one attempt, racing a duplicate GET if the first is slower than `hedge_after`
+    ///
+    /// The first response wins and the other request is dropped. A transport
+    /// error from one copy waits for the other rather than failing the attempt.
+    /// The duplicate takes its own `max_concurrent_requests` slot and is
+    /// skipped when none is free.
+    async fn send_hedged(&self, request: &TransportRequest) -> Result<TransportResponse> {
+        let threshold = match self.config.hedge_after {
+            Some(threshold) if request.method == Method::GET => threshold,
+            _ => return self.transport.send(request.clone()).await,
+        };
 
-            // Parse the API response wrapper
-            let api_response: ApiResponse<T> = serde_json::from_str(&text).map_err(Error::Json)?;
+        let mut primary = self.transport.send(request.clone());
+        tokio::select! {
+            result = &mut primary => return result,
+            _ = tokio::time::sleep(threshold) => {}
+        }
+        let _hedge_permit = match &self.limiter {
+            Some(limiter) => match limiter.try_acquire() {
+                Ok(permit) => Some(permit),
+                Err(_) => return primary.await,
+            },
+            None => None,
+        };
+        log::debug!(
+            target: "mailbreeze",
+            "GET {} hedging after {:?}",
+            redact_path(request.url.path()),
+            threshold
+        );
+        let hedge = self.transport.send(request.clone());
+        futures_util::future::select_ok([primary, hedge])
+            .await
+            .map(|(response, _)| response)
+    }
 
-            // Check if the API returned success: false
-            if !api_response.success {
-                let error_body = api_response.error.unwrap_or(ApiErrorBody {
-                    code: None,
-                    message: Some("Unknown error".to_string()),
-                });
-                return Err(Error::BadRequest {
-                    message: error_body
-                        .message
-                        .unwrap_or_else(|| "Unknown error".to_string()),
-                    code: error_body.code,
-                });
-            }
+    /// Wait for a `max_concurrent_requests` slot, held for a single attempt
+    ///
+    /// Backoff sleeps happen without a permit, so a retrying request never
+    /// blocks others from making progress.
+    async fn acquire_permit(&self) -> Option<SemaphorePermit<'_>> {
+        match &self.limiter {
+            Some(limiter) => limiter.acquire().await.ok(),
+            None => None,
+        }
+    }
 
-            // Extract the data field
-            api_response.data.ok_or_else(|| {
-                Error::Json(serde_json::Error::io(std::io::Error::new(
-                    std::io::ErrorKind::InvalidData,
-                    "Response missing data field",
-                )))
-            })
-        } else {
-            Err(self.parse_error_response(response).await?)
+    /// Wait until a send from `from` fits within its domain's configured rate
+    pub(crate) async fn throttle_send(&self, from: &str) {
+        if let Some(throttle) = &self.send_throttle {
+            throttle.wait(from).await;
         }
     }
 
-    /// Parse an error response
-    async fn parse_error_response(&self, response: Response) -> Result<Error> {
-        let status = response.status();
-        let retry_after = response
-            .headers()
-            .get("Retry-After")
-            .and_then(|v| v.to_str().ok())
-            .and_then(|v| self.parse_retry_after(v));
+    /// Whether any per-domain send rate is configured
+    pub(crate) fn throttles_sends(&self) -> bool {
+        self.send_throttle.is_some()
+    }
 
-        let body: HashMap<String, serde_json::Value> = response.json().await.unwrap_or_default();
+    /// Cache key for a request eligible for the ETag cache: plain GETs, not range downloads
+    fn cache_key(&self, request: &TransportRequest) -> Option<String> {
+        let eligible = self.cache.is_some()
+            && request.method == Method::GET
+            && !request.headers.contains_key(reqwest::header::RANGE);
+        eligible.then(|| request.url.to_string())
+    }
 
-        let message = body
-            .get("error")
-            .and_then(|v| v.as_str())
-            .unwrap_or("Unknown error")
-            .to_string();
+    fn record_request(&self, method: &Method, path: &str, success: bool) {
+        if let Some(recorder) = &self.diagnostics {
+            recorder.record_request(method, path, success);
+        }
+    }
 
-        let code = body
-            .get("code")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string());
+    /// Log a request summary when `debug_logging` is enabled
+    ///
+    /// Only the method, redacted path, status, duration and attempt count are
+    /// logged; headers, query strings and bodies never are.
+    fn log_request(
+        &self,
+        method: &Method,
+        path: &str,
+        status: Option<u16>,
+        elapsed: Duration,
+        attempts: u32,
+    ) {
+        if !self.config.debug_logging {
+            return;
+        }
+        let status = status.map_or_else(|| "error".to_string(), |s| s.to_string());
+        log::debug!(
+            target: "mailbreeze",
+            "{} {} -> {} in {}ms ({} attempt{})",
+            method,
+            redact_path(path),
+            status,
+            elapsed.as_millis(),
+            attempts,
+            if attempts == 1 { "" } else { "s" }
+        );
+    }
 
-        let error = match status {
-            StatusCode::BAD_REQUEST => Error::BadRequest { message, code },
-            StatusCode::UNAUTHORIZED => Error::Authentication { message, code },
-            StatusCode::NOT_FOUND => Error::NotFound { message, code },
-            StatusCode::UNPROCESSABLE_ENTITY => {
-                let errors = body
-                    .get("errors")
-                    .and_then(|v| serde_json::from_value(v.clone()).ok())
-                    .unwrap_or_default();
-                Error::Validation {
-                    message,
-                    errors,
-                    code,
-                }
-            }
-            StatusCode::TOO_MANY_REQUESTS => Error::RateLimit {
+    /// Report deprecation headers to the configured handler, or log them
+    fn check_deprecation(&self, method: &Method, path: &str, response: &TransportResponse) {
+        let Some(notice) =
+            DeprecationNotice::from_headers(method.as_str(), path, &response.headers)
+        else {
+            return;
+        };
+
+        match &self.config.on_deprecation {
+            Some(handler) => handler(&notice),
+            None => log::warn!("MailBreeze API: {}", notice),
+        }
+    }
+
+    /// Report a server schema newer than [`SCHEMA_VERSION`], once per version
+    fn check_schema_version(&self, method: &Method, path: &str, response: &TransportResponse) {
+        let Some(server_version) = schema_version(&response.headers) else {
+            return;
+        };
+        if !self.schema_drift.first_sighting(server_version) {
+            return;
+        }
+
+        let drift = SchemaDrift {
+            sdk_version: SCHEMA_VERSION,
+            server_version,
+            method: method.to_string(),
+            path: redact_path(path),
+        };
+        match &self.config.on_schema_drift {
+            Some(handler) => handler(&drift),
+            None => log::warn!("MailBreeze API: {}", drift),
+        }
+    }
+
+    /// Absolute URL of the API path `path`
+    fn request_url(&self, path: &str) -> Result<Url> {
+        let url = format!("{}{}{}", self.config.base_url, API_VERSION, path);
+        Url::parse(&url).map_err(|e| Error::InvalidUrl(e.to_string()))
+    }
+
+    /// The path of `url` below `/api/v1`
+    ///
+    /// Fails when `..` segments took the URL outside the API.
+    fn api_path<'a>(&self, url: &'a Url) -> Result<&'a str> {
+        let root = self.request_url("")?;
+        url.path()
+            .strip_prefix(root.path())
+            .filter(|rest| rest.is_empty() || rest.starts_with('/'))
+            .ok_or_else(|| {
+                Error::InvalidUrl(format!(
+                    "{} is outside {}",
+                    redact_path(url.path()),
+                    root.path()
+                ))
+            })
+    }
+
+    /// Build the transport request: URL, query string, headers and body
+    fn build_request(
+        &self,
+        method: Method,
+        path: &str,
+        credential: Option<&Credential>,
+        mut body: Option<Vec<u8>>,
+        query: Option<&[(String, String)]>,
+        options: &RequestOptions,
+    ) -> Result<TransportRequest> {
+        let mut url = self.request_url(path)?;
+
+        let options = self.options.merge(options);
+
+        let pairs: Vec<&(String, String)> = query
+            .unwrap_or_default()
+            .iter()
+            .chain(&options.query)
+            .collect();
+        if !pairs.is_empty() {
+            url.query_pairs_mut()
+                .extend_pairs(pairs.into_iter().map(|(k, v)| (k, v)));
+        }
+
+        let mut headers = HeaderMap::new();
+        self.insert_header(&mut headers, "Content-Type", "application/json")?;
+        self.insert_header(
+            &mut headers,
+            "Accept",
+            &format!("application/json; schema-version={}", SCHEMA_VERSION),
+        )?;
+        match &self.config.app_info {
+            Some(app) => self.insert_header(
+                &mut headers,
+                "User-Agent",
+                &format!("{} {}", USER_AGENT, app),
+            )?,
+            None => self.insert_header(&mut headers, "User-Agent", USER_AGENT)?,
+        }
+        if let Some(version) = &self.config.api_version {
+            self.insert_header(&mut headers, "MailBreeze-Version", version)?;
+        }
+        for (name, value) in self.config.default_headers.iter().chain(&options.headers) {
+            self.insert_header(&mut headers, name, value)?;
+        }
+        // Last, so a default or per-request header cannot replace the credential
+        self.insert_credential(&mut headers, credential)?;
+        if let Some(etag) = &options.if_match {
+            self.insert_header(&mut headers, "If-Match", etag)?;
+        }
+        if let Some(key) = &options.idempotency_key {
+            self.insert_header(&mut headers, "Idempotency-Key", key)?;
+        }
+
+        if let (Some(raw), Some(threshold)) = (&body, self.config.compress_requests_over) {
+            if raw.len() > threshold {
+                body = Some(gzip(raw)?);
+                self.insert_header(&mut headers, "Content-Encoding", "gzip")?;
+            }
+        }
+        if let (Some(raw), Some(limit)) = (&body, self.config.max_body_size) {
+            if raw.len() > limit {
+                return Err(Error::PayloadTooLarge {
+                    message: format!(
+                        "request body is {} bytes, limit is {} bytes",
+                        raw.len(),
+                        limit
+                    ),
+                    size: raw.len(),
+                    limit,
+                });
+            }
+        }
+
+        Ok(TransportRequest {
+            method,
+            url,
+            headers,
+            body,
+            timeout: options.timeout,
+        })
+    }
+
+    /// Insert a header, rejecting names or values that are not valid HTTP
+    fn insert_header(&self, headers: &mut HeaderMap, name: &str, value: &str) -> Result<()> {
+        let name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|_| Error::InvalidHeader(name.to_string()))?;
+        let value =
+            HeaderValue::from_str(value).map_err(|_| Error::InvalidHeader(name.to_string()))?;
+        headers.insert(name, value);
+        Ok(())
+    }
+
+    /// Authenticate with `credential`, or with the configured API key when there is none
+    fn insert_credential(
+        &self,
+        headers: &mut HeaderMap,
+        credential: Option<&Credential>,
+    ) -> Result<()> {
+        headers.remove("X-API-Key");
+        headers.remove("Authorization");
+        match credential {
+            None => {
+                self.insert_secret_header(headers, "X-API-Key", self.config.api_key.expose_secret())
+            }
+            Some(Credential::ApiKey(key)) => self.insert_secret_header(headers, "X-API-Key", key),
+            Some(Credential::Bearer(token)) => {
+                self.insert_secret_header(headers, "Authorization", &format!("Bearer {}", token))
+            }
+        }
+    }
+
+    /// Insert a credential header, flagged sensitive so it is left out of `Debug` output
+    fn insert_secret_header(&self, headers: &mut HeaderMap, name: &str, value: &str) -> Result<()> {
+        let mut value =
+            HeaderValue::from_str(value).map_err(|_| Error::InvalidHeader(name.to_string()))?;
+        value.set_sensitive(true);
+        headers.insert(
+            HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| Error::InvalidHeader(name.to_string()))?,
+            value,
+        );
+        Ok(())
+    }
+
+    /// Decode a successful response and unwrap the API envelope
+    fn handle_response<T: DeserializeOwned>(&self, response: TransportResponse) -> Result<T> {
+        if response.body.is_empty() {
+            return Err(decode_error(&response, "Empty response body"));
+        }
+
+        // Parse the API response wrapper
+        let parsed = match self.normalized_body(&response.body) {
+            Some(value) => serde_json::from_value(value),
+            None => serde_json::from_slice(&response.body),
+        };
+        let api_response: ApiResponse<T> = parsed.map_err(|source| Error::Decode {
+            raw_body: String::from_utf8_lossy(&response.body).into_owned(),
+            source,
+            status: response.status.as_u16(),
+            request_id: response_request_id(&response.headers),
+        })?;
+
+        // Check if the API returned success: false
+        if !api_response.success {
+            let error_body = api_response.error.unwrap_or(ApiErrorBody {
+                code: None,
+                message: Some("Unknown error".to_string()),
+            });
+            return Err(Error::BadRequest {
+                message: error_body
+                    .message
+                    .unwrap_or_else(|| "Unknown error".to_string()),
+                code: error_body.code,
+                request_id: response_request_id(&response.headers),
+            });
+        }
+
+        // Extract the data field
+        api_response
+            .data
+            .ok_or_else(|| decode_error(&response, "Response missing data field"))
+    }
+
+    /// Apply the configured field-case conversion and transform hook
+    ///
+    /// Returns `None` when neither is configured, or the body is not JSON, so
+    /// the raw bytes can be decoded directly.
+    fn normalized_body(&self, body: &[u8]) -> Option<serde_json::Value> {
+        if self.config.response_case == FieldCase::Camel && self.config.transform_response.is_none()
+        {
+            return None;
+        }
+
+        let mut value: serde_json::Value = serde_json::from_slice(body).ok()?;
+        if self.config.response_case == FieldCase::Snake {
+            camel_case_keys(&mut value);
+        }
+        if let Some(transform) = &self.config.transform_response {
+            transform(&mut value);
+        }
+        Some(value)
+    }
+
+    /// Parse an error response
+    fn parse_error_response(&self, response: &TransportResponse) -> Error {
+        let status = response.status;
+        let retry_after = response
+            .headers
+            .get("Retry-After")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| self.parse_retry_after(v));
+
+        let request_id = response_request_id(&response.headers);
+
+        let body: HashMap<String, serde_json::Value> = match self.normalized_body(&response.body) {
+            Some(value) => serde_json::from_value(value).unwrap_or_default(),
+            None => serde_json::from_slice(&response.body).unwrap_or_default(),
+        };
+
+        let message = body
+            .get("error")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown error")
+            .to_string();
+
+        let code = body
+            .get("code")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+
+        match status {
+            StatusCode::BAD_REQUEST => match suppressed_recipient(&body) {
+                Some((email, reason)) => Error::RecipientSuppressed {
+                    email,
+                    reason,
+                    message,
+                    code,
+                    request_id,
+                },
+                None => Error::BadRequest {
+                    message,
+                    code,
+                    request_id,
+                },
+            },
+            StatusCode::UNAUTHORIZED => Error::Authentication {
+                message,
+                code,
+                request_id,
+            },
+            StatusCode::FORBIDDEN => {
+                let required_scope = body
+                    .get("requiredScope")
+                    .and_then(|v| v.as_str())
+                    .or_else(|| {
+                        response
+                            .headers
+                            .get("X-Required-Scope")
+                            .and_then(|v| v.to_str().ok())
+                    })
+                    .map(|s| s.to_string());
+                Error::Forbidden {
+                    message,
+                    required_scope,
+                    code,
+                    request_id,
+                }
+            }
+            StatusCode::NOT_FOUND => Error::NotFound {
+                message,
+                code,
+                request_id,
+            },
+            StatusCode::CONFLICT => Error::Conflict {
+                message,
+                existing_id: body
+                    .get("existingId")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string()),
+                code,
+                request_id,
+            },
+            StatusCode::PRECONDITION_FAILED => Error::PreconditionFailed {
+                message,
+                code,
+                request_id,
+            },
+            StatusCode::UNPROCESSABLE_ENTITY => {
+                let errors = body
+                    .get("errors")
+                    .and_then(|v| serde_json::from_value(v.clone()).ok())
+                    .unwrap_or_default();
+                Error::Validation {
+                    message,
+                    errors,
+                    code,
+                    request_id,
+                }
+            }
+            StatusCode::TOO_MANY_REQUESTS => Error::RateLimit {
                 message,
                 retry_after,
                 code,
+                request_id,
             },
             _ if status.is_server_error() => Error::Server {
                 message,
                 status_code: status.as_u16(),
                 code,
+                request_id,
             },
             _ => Error::Server {
                 message,
                 status_code: status.as_u16(),
                 code,
+                request_id,
             },
-        };
-
-        Ok(error)
+        }
     }
 
     /// Parse Retry-After header (integer seconds or HTTP-date)
@@ -381,17 +1532,160 @@ impl HttpClient {
         None
     }
 
-    /// Wait before retrying with exponential backoff
-    async fn wait_before_retry(&self, attempt: u32) {
-        let delay = Duration::from_millis(100 * (1 << (attempt - 1)));
-        tokio::time::sleep(delay).await;
+    /// Delay before the next attempt, or `None` if the error should be returned
+    ///
+    /// Rate limit errors are only retried when `retry_rate_limits` is enabled,
+    /// and only if the server's `Retry-After` fits within `max_retry_after`.
+    /// Other errors go through `retry_classifier` when one is configured.
+    fn retry_delay(&self, method: &Method, error: &Error, attempt: u32) -> Option<Duration> {
+        if attempt >= self.config.max_retries {
+            return None;
+        }
+
+        if let Error::RateLimit { retry_after, .. } = error {
+            if !self.config.retry_rate_limits {
+                return None;
+            }
+            return match retry_after {
+                Some(seconds) => {
+                    let delay = Duration::from_secs(*seconds);
+                    (delay <= self.config.max_retry_after).then_some(delay)
+                }
+                None => self.config.retry_policy.delay(attempt),
+            };
+        }
+
+        if !self.is_retryable(method, error) {
+            return None;
+        }
+        self.config.retry_policy.delay(attempt)
+    }
+
+    /// Whether `error` is transient, by `retry_classifier` when one is configured
+    pub(crate) fn is_retryable(&self, method: &Method, error: &Error) -> bool {
+        match &self.config.retry_classifier {
+            Some(classifier) => classifier.should_retry(method, error),
+            None => error.is_retryable(),
+        }
+    }
+}
+
+/// [`Error::Decode`] for a body that parsed but lacks what the caller needs
+fn decode_error(response: &TransportResponse, reason: &str) -> Error {
+    Error::Decode {
+        raw_body: String::from_utf8_lossy(&response.body).into_owned(),
+        source: serde_json::Error::io(std::io::Error::new(std::io::ErrorKind::InvalidData, reason)),
+        status: response.status.as_u16(),
+        request_id: response_request_id(&response.headers),
+    }
+}
+
+/// Whether the request never reached the server, so another endpoint may do better
+fn is_connect_failure(error: &Error) -> bool {
+    match error {
+        Error::Http(e) => e.is_connect(),
+        Error::Transport { retryable, .. } => *retryable,
+        _ => false,
+    }
+}
+
+/// Run `future`, bailing out with [`Error::Cancelled`] as soon as `token` fires
+async fn cancellable<T>(
+    token: Option<&CancellationToken>,
+    future: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    match token {
+        Some(token) => tokio::select! {
+            biased;
+            _ = token.cancelled() => Err(Error::Cancelled),
+            result = future => result,
+        },
+        None => future.await,
+    }
+}
+
+/// Address and reason from a `RECIPIENT_SUPPRESSED` error body
+fn suppressed_recipient(
+    body: &HashMap<String, serde_json::Value>,
+) -> Option<(String, crate::types::SuppressReason)> {
+    if body.get("code").and_then(|v| v.as_str()) != Some("RECIPIENT_SUPPRESSED") {
+        return None;
+    }
+    let email = body.get("email")?.as_str()?.to_string();
+    let reason = serde_json::from_value(body.get("reason")?.clone()).ok()?;
+    Some((email, reason))
+}
+
+/// Path with email addresses (e.g. `/suppressions/a@b.com`) masked and the query dropped
+pub(crate) fn redact_path(path: &str) -> String {
+    let path = path.split('?').next().unwrap_or_default();
+    path.split('/')
+        .map(|segment| {
+            if segment.contains('@') || segment.to_ascii_lowercase().contains("%40") {
+                REDACTED
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// `id` percent-encoded as a single path segment
+///
+/// Rejects values that would reach a different endpoint once the URL is
+/// resolved: empty, `.`, `..` or containing `/`.
+pub(crate) fn path_id(id: &str) -> Result<String> {
+    if id.contains('/') {
+        return Err(Error::InvalidUrl(format!(
+            "{:?} is not a valid ID: it contains '/'",
+            id
+        )));
+    }
+    path_segment(id)
+}
+
+/// `value` percent-encoded as a single path segment, `/` included
+///
+/// For values such as email addresses that may legitimately contain `/`;
+/// use [`path_id`] for IDs.
+pub(crate) fn path_segment(value: &str) -> Result<String> {
+    if matches!(value, "" | "." | "..") {
+        return Err(Error::InvalidUrl(format!(
+            "{:?} is not a valid path segment",
+            value
+        )));
     }
+    Ok(percent_encoding::utf8_percent_encode(value, PATH_SEGMENT).to_string())
+}
+
+/// The API's request ID for a response, if present
+pub(crate) fn response_request_id(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get("X-Request-Id")
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
+}
+
+/// Gzip-compress a request body
+fn gzip(data: &[u8]) -> Result<Vec<u8>> {
+    use flate2::write::GzEncoder;
+    use std::io::Write;
+
+    let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder
+        .write_all(data)
+        .and_then(|_| encoder.finish())
+        .map_err(|e| Error::Transport {
+            message: format!("failed to compress request body: {}", e),
+            retryable: false,
+        })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use wiremock::matchers::{header, method, path};
+    use wiremock::matchers::{header, method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     #[tokio::test]
@@ -459,55 +1753,145 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_authentication_error() {
+    async fn test_query_params_and_raw_pairs() {
         let mock_server = MockServer::start().await;
 
         Mock::given(method("GET"))
             .and(path("/api/v1/test"))
-            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
-                "error": "Invalid API key"
+            .and(query_param("status", "delivered"))
+            .and(query_param("page", "2"))
+            .and(query_param("tags[]", "b"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": []
             })))
+            .expect(1)
             .mount(&mock_server)
             .await;
 
-        let config = ClientConfig::new("bad_key").base_url(mock_server.uri());
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap().with_options(
+            RequestOptions::new()
+                .query("tags[]", "a")
+                .query("tags[]", "b"),
+        );
+
+        let params = crate::types::ListEmailsParams {
+            status: Some(crate::types::EmailStatus::Delivered),
+            page: Some(2),
+            ..Default::default()
+        };
+        let result: Vec<serde_json::Value> =
+            client.get_with_params("/test", &params).await.unwrap();
+        assert!(result.is_empty());
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(
+            requests[0].url.query(),
+            Some("status=delivered&page=2&tags%5B%5D=a&tags%5B%5D=b")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_with_params_encodes_sequences_and_ranges() {
+        use crate::types::{EmailStatus, ListEmailsParams, RangeFilter};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": []
+            })))
+            .mount(&mock_server)
+            .await;
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
         let client = HttpClient::new(config).unwrap();
 
-        let result: std::result::Result<serde_json::Value, _> = client.get("/test").await;
-        assert!(matches!(result, Err(Error::Authentication { .. })));
+        let params = ListEmailsParams {
+            limit: Some(25),
+            statuses: vec![EmailStatus::Sent, EmailStatus::Bounced],
+            created_at: Some(RangeFilter::between("2024-01-01", "2024-02-01")),
+            ..Default::default()
+        };
+        let _: Vec<serde_json::Value> = client.get_with_params("/test", &params).await.unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(
+            requests[0].url.query(),
+            Some(
+                "limit=25&statuses=sent&statuses=bounced\
+                 &created_at%5Bgte%5D=2024-01-01&created_at%5Blte%5D=2024-02-01"
+            )
+        );
     }
 
     #[tokio::test]
-    async fn test_not_found_error() {
+    async fn test_deprecation_headers_reach_handler() {
         let mock_server = MockServer::start().await;
 
         Mock::given(method("GET"))
-            .and(path("/api/v1/test/nonexistent"))
-            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
-                "error": "Not found"
+            .and(path("/api/v1/test"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("Deprecation", "true")
+                    .insert_header("Sunset", "Sat, 01 Mar 2025 00:00:00 GMT")
+                    .set_body_json(serde_json::json!({
+                        "success": true,
+                        "data": {"id": "123"}
+                    })),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let notices = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = notices.clone();
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .on_deprecation(move |notice| seen.lock().unwrap().push(notice.clone()));
+        let client = HttpClient::new(config).unwrap();
+
+        let _: serde_json::Value = client.get("/test").await.unwrap();
+
+        let notices = notices.lock().unwrap();
+        assert_eq!(notices.len(), 1);
+        assert_eq!(notices[0].path, "/test");
+        assert_eq!(notices[0].deprecation.as_deref(), Some("true"));
+        assert_eq!(
+            notices[0].sunset.as_deref(),
+            Some("Sat, 01 Mar 2025 00:00:00 GMT")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_authentication_error() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "error": "Invalid API key"
             })))
             .mount(&mock_server)
             .await;
 
-        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let config = ClientConfig::new("bad_key").base_url(mock_server.uri());
         let client = HttpClient::new(config).unwrap();
 
-        let result: std::result::Result<serde_json::Value, _> =
-            client.get("/test/nonexistent").await;
-        assert!(matches!(result, Err(Error::NotFound { .. })));
+        let result: std::result::Result<serde_json::Value, _> = client.get("/test").await;
+        assert!(matches!(result, Err(Error::Authentication { .. })));
     }
 
     #[tokio::test]
-    async fn test_validation_error() {
+    async fn test_forbidden_error_with_scope_hint() {
         let mock_server = MockServer::start().await;
 
         Mock::given(method("POST"))
             .and(path("/api/v1/test"))
-            .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
-                "error": "Validation failed",
-                "errors": {
-                    "email": ["Required"]
-                }
+            .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+                "error": "API key is missing a required scope",
+                "code": "insufficient_scope",
+                "requiredScope": "emails:send"
             })))
             .mount(&mock_server)
             .await;
@@ -515,58 +1899,822 @@ mod tests {
         let config = ClientConfig::new("test_key").base_url(mock_server.uri());
         let client = HttpClient::new(config).unwrap();
 
-        let body = serde_json::json!({});
-        let result: std::result::Result<serde_json::Value, _> = client.post("/test", &body).await;
-
-        match result {
-            Err(Error::Validation { errors, .. }) => {
-                assert!(errors.contains_key("email"));
-            }
-            _ => panic!("Expected validation error"),
-        }
+        let result: Result<serde_json::Value> = client.post("/test", &serde_json::json!({})).await;
+        let error = result.unwrap_err();
+        assert!(matches!(error, Error::Forbidden { .. }));
+        assert_eq!(error.required_scope(), Some("emails:send"));
+        assert_eq!(error.code(), Some("insufficient_scope"));
+        assert_eq!(error.status_code(), Some(403));
     }
 
     #[tokio::test]
-    async fn test_rate_limit_error() {
+    async fn test_request_id_on_errors_and_responses() {
         let mock_server = MockServer::start().await;
 
         Mock::given(method("GET"))
-            .and(path("/api/v1/test"))
+            .and(path("/api/v1/ok"))
             .respond_with(
-                ResponseTemplate::new(429)
-                    .insert_header("Retry-After", "30")
-                    .set_body_json(serde_json::json!({
-                        "error": "Rate limit exceeded"
-                    })),
+                ResponseTemplate::new(200)
+                    .insert_header("X-Request-Id", "req_ok")
+                    .set_body_json(serde_json::json!({"success": true, "data": {}})),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/missing"))
+            .respond_with(
+                ResponseTemplate::new(404)
+                    .insert_header("X-Request-Id", "req_missing")
+                    .set_body_json(serde_json::json!({"error": "Not found"})),
             )
             .mount(&mock_server)
             .await;
 
-        let config = ClientConfig::new("test_key")
-            .base_url(mock_server.uri())
-            .max_retries(1);
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
         let client = HttpClient::new(config).unwrap();
+        assert!(client.last_response().is_none());
 
-        let result: std::result::Result<serde_json::Value, _> = client.get("/test").await;
+        let _: serde_json::Value = client.get("/ok").await.unwrap();
+        let meta = client.last_response().unwrap();
+        assert_eq!(meta.status, 200);
+        assert_eq!(meta.request_id.as_deref(), Some("req_ok"));
 
-        match result {
-            Err(Error::RateLimit { retry_after, .. }) => {
-                assert_eq!(retry_after, Some(30));
-            }
-            _ => panic!("Expected rate limit error"),
-        }
+        let error = client
+            .get::<serde_json::Value>("/missing")
+            .await
+            .unwrap_err();
+        assert_eq!(error.request_id(), Some("req_missing"));
+        assert_eq!(
+            client.last_response().unwrap().request_id.as_deref(),
+            Some("req_missing")
+        );
     }
 
     #[tokio::test]
-    async fn test_retry_on_server_error() {
+    async fn test_rate_limit_headers_are_exposed() {
         let mock_server = MockServer::start().await;
 
         Mock::given(method("GET"))
             .and(path("/api/v1/test"))
-            .respond_with(ResponseTemplate::new(500).set_body_json(serde_json::json!({
-                "error": "Server error"
-            })))
-            .expect(3)
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("X-RateLimit-Limit", "100")
+                    .insert_header("X-RateLimit-Remaining", "7")
+                    .insert_header("X-RateLimit-Reset", "30")
+                    .set_body_json(serde_json::json!({"success": true, "data": {}})),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+
+        let _: serde_json::Value = client.get("/test").await.unwrap();
+        assert_eq!(
+            client.last_rate_limit(),
+            Some(RateLimitInfo {
+                limit: 100,
+                remaining: 7,
+                reset: Some(30),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_default_headers_sent_and_overridable() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .and(header("X-Tenant", "acme"))
+            .and(header("X-Route", "eu"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .default_header("X-Tenant", "acme")
+            .default_header("X-Route", "us");
+        let client = HttpClient::new(config)
+            .unwrap()
+            .with_options(RequestOptions::new().header("X-Route", "eu"));
+
+        let _: serde_json::Value = client.get("/test").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_default_headers_cannot_replace_credential() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .and(header("X-API-Key", "test_key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .default_header("X-API-Key", "other_key")
+            .default_header("Authorization", "Bearer tenant-token");
+        assert!(!format!("{:?}", config).contains("tenant-token"));
+        let client = HttpClient::new(config)
+            .unwrap()
+            .with_options(RequestOptions::new().header("x-api-key", "per_request_key"));
+
+        let _: serde_json::Value = client.get("/test").await.unwrap();
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(!requests[0].headers.contains_key("Authorization"));
+    }
+
+    #[tokio::test]
+    async fn test_snake_case_responses_with_transform() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"page": 2, "limit": 10, "total": 30, "total_pages": 3, "has_next": true}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .response_case(FieldCase::Snake)
+            .transform_response(|body| body["data"]["hasPrev"] = serde_json::json!(true));
+        let client = HttpClient::new(config).unwrap();
+
+        let pagination: crate::types::Pagination = client.get("/test").await.unwrap();
+        assert_eq!(pagination.total_pages, 3);
+        assert!(pagination.has_next);
+        assert!(pagination.has_prev);
+    }
+
+    #[tokio::test]
+    async fn test_with_api_key_switches_key() {
+        let mock_server = MockServer::start().await;
+
+        for key in ["key_a", "key_b"] {
+            Mock::given(method("GET"))
+                .and(path("/api/v1/test"))
+                .and(header("X-API-Key", key))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .insert_header("X-Request-Id", key)
+                        .set_body_json(serde_json::json!({"success": true, "data": {}})),
+                )
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let config = ClientConfig::new("key_a").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+        let other = client.with_api_key("key_b");
+
+        let _: serde_json::Value = client.get("/test").await.unwrap();
+        let _: serde_json::Value = other.get("/test").await.unwrap();
+        assert_eq!(client.config().api_key.expose_secret(), "key_a");
+        assert_eq!(
+            client.last_response().unwrap().request_id.as_deref(),
+            Some("key_a")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_oauth2_token_is_fetched_once_and_reused() {
+        use crate::auth::OAuth2ClientCredentials;
+        use wiremock::matchers::body_string_contains;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .and(body_string_contains("grant_type=client_credentials"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "tok_123",
+                "token_type": "Bearer",
+                "expires_in": 3600
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .and(header("Authorization", "Bearer tok_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {}
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let provider = OAuth2ClientCredentials::new(
+            format!("{}/oauth/token", mock_server.uri()),
+            "client_id",
+            "client_secret",
+        );
+        let config = ClientConfig::new("")
+            .base_url(mock_server.uri())
+            .auth(provider);
+        let client = HttpClient::new(config).unwrap();
+
+        let _: serde_json::Value = client.get("/test").await.unwrap();
+        let _: serde_json::Value = client.get("/test").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_oauth2_token_is_replaced_after_a_401() {
+        use crate::auth::OAuth2ClientCredentials;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "tok_revoked",
+                "expires_in": 3600
+            })))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/oauth/token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "access_token": "tok_fresh",
+                "expires_in": 3600
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .and(header("Authorization", "Bearer tok_revoked"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "error": "Token revoked"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .and(header("Authorization", "Bearer tok_fresh"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {}
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let provider = OAuth2ClientCredentials::new(
+            format!("{}/oauth/token", mock_server.uri()),
+            "client_id",
+            "client_secret",
+        );
+        let config = ClientConfig::new("")
+            .base_url(mock_server.uri())
+            .max_retries(0)
+            .auth(provider);
+        let client = HttpClient::new(config).unwrap();
+
+        let _: serde_json::Value = client.get("/test").await.unwrap();
+        let _: serde_json::Value = client.get("/test").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_aborts_retry_loop() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(503).set_body_json(serde_json::json!({
+                "error": "Unavailable"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .max_retries(10)
+            .retry_policy(RetryPolicy::Fixed(Duration::from_secs(60)));
+        let client = HttpClient::new(config).unwrap();
+
+        let token = CancellationToken::new();
+        let options = RequestOptions::new().cancellation(token.clone());
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            token.cancel();
+        });
+
+        let started = Instant::now();
+        let result = client
+            .request_impl::<serde_json::Value, ()>(Method::GET, "/test", None, None, &options)
+            .await;
+        assert!(matches!(result, Err(Error::Cancelled)));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_max_concurrent_requests_limits_in_flight() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[derive(Debug, Default)]
+        struct SlowTransport {
+            in_flight: AtomicUsize,
+            peak: AtomicUsize,
+        }
+
+        #[async_trait::async_trait]
+        impl Transport for SlowTransport {
+            async fn send(&self, _request: TransportRequest) -> Result<TransportResponse> {
+                let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                self.peak.fetch_max(now, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(20)).await;
+                self.in_flight.fetch_sub(1, Ordering::SeqCst);
+                Ok(TransportResponse {
+                    status: StatusCode::OK,
+                    headers: HeaderMap::new(),
+                    body: br#"{"success": true, "data": {}}"#.to_vec(),
+                })
+            }
+        }
+
+        let transport = Arc::new(SlowTransport::default());
+        let mut config = ClientConfig::new("test_key").max_concurrent_requests(2);
+        config.transport = Some(transport.clone());
+        let client = HttpClient::new(config).unwrap();
+
+        let requests = (0..8).map(|_| client.get::<serde_json::Value>("/test"));
+        for result in futures_util::future::join_all(requests).await {
+            result.unwrap();
+        }
+        assert_eq!(transport.peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_hedged_get_takes_faster_response() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        /// First call stalls; later calls answer at once
+        #[derive(Debug, Default)]
+        struct StallingTransport {
+            calls: AtomicU32,
+        }
+
+        #[async_trait::async_trait]
+        impl Transport for StallingTransport {
+            async fn send(&self, _request: TransportRequest) -> Result<TransportResponse> {
+                let call = self.calls.fetch_add(1, Ordering::SeqCst);
+                if call == 0 {
+                    tokio::time::sleep(Duration::from_millis(500)).await;
+                }
+                Ok(TransportResponse {
+                    status: StatusCode::OK,
+                    headers: HeaderMap::new(),
+                    body: format!(r#"{{"success": true, "data": {{"call": {}}}}}"#, call)
+                        .into_bytes(),
+                })
+            }
+        }
+
+        let transport = Arc::new(StallingTransport::default());
+        let mut config = ClientConfig::new("test_key").hedge_after(Duration::from_millis(20));
+        config.transport = Some(transport.clone());
+        let client = HttpClient::new(config).unwrap();
+
+        let started = Instant::now();
+        let data: serde_json::Value = client.get("/test").await.unwrap();
+        assert_eq!(data["call"], 1);
+        assert!(started.elapsed() < Duration::from_millis(250));
+
+        // Writes are never duplicated
+        transport.calls.store(0, Ordering::SeqCst);
+        let started = Instant::now();
+        let _: serde_json::Value = client.post("/test", &serde_json::json!({})).await.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(500));
+        assert_eq!(transport.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_hedge_needs_a_free_concurrency_slot() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        #[derive(Debug, Default)]
+        struct SlowTransport {
+            calls: AtomicU32,
+        }
+
+        #[async_trait::async_trait]
+        impl Transport for SlowTransport {
+            async fn send(&self, _request: TransportRequest) -> Result<TransportResponse> {
+                self.calls.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                Ok(TransportResponse {
+                    status: StatusCode::OK,
+                    headers: HeaderMap::new(),
+                    body: br#"{"success": true, "data": {}}"#.to_vec(),
+                })
+            }
+        }
+
+        let transport = Arc::new(SlowTransport::default());
+        let mut config = ClientConfig::new("test_key")
+            .hedge_after(Duration::from_millis(10))
+            .max_concurrent_requests(1);
+        config.transport = Some(transport.clone());
+        let client = HttpClient::new(config).unwrap();
+
+        let _: serde_json::Value = client.get("/test").await.unwrap();
+        assert_eq!(transport.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_user_agent_includes_app_info() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .and(header(
+                "User-Agent",
+                format!(
+                    "mailbreeze-rust/{} billing/1.4.2",
+                    env!("CARGO_PKG_VERSION")
+                )
+                .as_str(),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .app_info("billing", "1.4.2");
+        let client = HttpClient::new(config).unwrap();
+
+        let _: serde_json::Value = client.get("/test").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_api_version_header() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .and(header("MailBreeze-Version", "2024-06-01"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .api_version("2024-06-01");
+        let client = HttpClient::new(config).unwrap();
+
+        let _: serde_json::Value = client.get("/test").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_schema_drift_reported_once_per_version() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .and(header(
+                "Accept",
+                format!("application/json; schema-version={}", SCHEMA_VERSION).as_str(),
+            ))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header(
+                        "MailBreeze-Schema-Version",
+                        (SCHEMA_VERSION + 1).to_string().as_str(),
+                    )
+                    .set_body_json(serde_json::json!({"success": true, "data": {}})),
+            )
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let seen = reports.clone();
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .on_schema_drift(move |drift| seen.lock().unwrap().push(drift.clone()));
+        let client = HttpClient::new(config).unwrap();
+
+        for _ in 0..3 {
+            let _: serde_json::Value = client.get("/test").await.unwrap();
+        }
+
+        let reports = reports.lock().unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].server_version, SCHEMA_VERSION + 1);
+        assert_eq!(reports[0].path, "/test");
+        assert_eq!(
+            client.last_response().unwrap().schema_version,
+            Some(SCHEMA_VERSION + 1)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_decode_error_keeps_raw_body() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<html>Gateway page</html>"))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+
+        let error = client.get::<serde_json::Value>("/test").await.unwrap_err();
+        match error {
+            Error::Decode {
+                raw_body, status, ..
+            } => {
+                assert_eq!(raw_body, "<html>Gateway page</html>");
+                assert_eq!(status, 200);
+            }
+            other => panic!("expected decode error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_decode_error_carries_request_id() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("X-Request-Id", "req_decode_1")
+                    .set_body_string("not json"),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+
+        let error = client.get::<serde_json::Value>("/test").await.unwrap_err();
+        assert!(matches!(error, Error::Decode { .. }));
+        assert_eq!(error.request_id(), Some("req_decode_1"));
+    }
+
+    #[tokio::test]
+    async fn test_post_body_keeps_field_order() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        #[derive(Serialize)]
+        struct Params {
+            zebra: u32,
+            apple: u32,
+        }
+
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+        let _: serde_json::Value = client
+            .post("/test", &Params { zebra: 1, apple: 2 })
+            .await
+            .unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(requests[0].body, br#"{"zebra":1,"apple":2}"#);
+    }
+
+    #[tokio::test]
+    async fn test_restricted_client_denies_before_sending() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_1"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config)
+            .unwrap()
+            .restricted(Capabilities::SEND_ONLY);
+
+        let _: serde_json::Value = client
+            .post("/emails", &serde_json::json!({}))
+            .await
+            .unwrap();
+
+        let error = client.delete("/webhooks/wh_1").await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::CapabilityDenied {
+                required: Capabilities::MANAGE,
+                ..
+            }
+        ));
+
+        // Neither deriving nor re-restricting widens the set
+        let widened = client
+            .with_api_key("other_key")
+            .restricted(Capabilities::ALL);
+        let error = widened
+            .get::<serde_json::Value>("/emails/e_1")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "GET /emails/e_1 needs the READ capability, which this client lacks"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_capability_is_checked_on_the_resolved_path() {
+        let client = HttpClient::new(ClientConfig::new("test_key"))
+            .unwrap()
+            .restricted(Capabilities::VERIFY);
+
+        let error = client
+            .get::<serde_json::Value>("/email-verification/../account")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "GET /account needs the READ capability, which this client lacks"
+        );
+
+        let error = client
+            .get::<serde_json::Value>("/../../admin")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidUrl(_)));
+    }
+
+    #[test]
+    fn test_path_segments_are_encoded_and_checked() {
+        assert_eq!(path_id("ver_1").unwrap(), "ver_1");
+        assert_eq!(path_id("a b?c#d").unwrap(), "a%20b%3Fc%23d");
+        for id in ["", ".", "..", "../account", "a/b"] {
+            assert!(matches!(path_id(id), Err(Error::InvalidUrl(_))), "{}", id);
+        }
+        assert_eq!(
+            path_segment("a/b+c@example.com").unwrap(),
+            "a%2Fb%2Bc@example.com"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_signer_signs_the_bytes_sent() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_1"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let signer = crate::HmacSigner::new("signing_secret").key_id("key_1");
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .signer(signer.clone());
+        let client = HttpClient::new(config).unwrap();
+        let _: serde_json::Value = client
+            .post("/emails", &serde_json::json!({"subject": "Hi"}))
+            .await
+            .unwrap();
+
+        let received = &mock_server.received_requests().await.unwrap()[0];
+        let header = |name: &str| received.headers.get(name).unwrap().to_str().unwrap();
+        let timestamp: u64 = header("x-mailbreeze-timestamp").parse().unwrap();
+        let expected = signer.signature("POST", "/api/v1/emails", &received.body, timestamp);
+        assert_eq!(header("x-mailbreeze-signature"), format!("v1={}", expected));
+        assert_eq!(header("x-mailbreeze-key-id"), "key_1");
+    }
+
+    #[tokio::test]
+    async fn test_not_found_error() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test/nonexistent"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "error": "Not found"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+
+        let result: std::result::Result<serde_json::Value, _> =
+            client.get("/test/nonexistent").await;
+        assert!(matches!(result, Err(Error::NotFound { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_validation_error() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(422).set_body_json(serde_json::json!({
+                "error": "Validation failed",
+                "errors": {
+                    "email": ["Required"]
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+
+        let body = serde_json::json!({});
+        let result: std::result::Result<serde_json::Value, _> = client.post("/test", &body).await;
+
+        match result {
+            Err(Error::Validation { errors, .. }) => {
+                assert!(errors.contains_key("email"));
+            }
+            _ => panic!("Expected validation error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_error() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "30")
+                    .set_body_json(serde_json::json!({
+                        "error": "Rate limit exceeded"
+                    })),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .max_retries(1);
+        let client = HttpClient::new(config).unwrap();
+
+        let result: std::result::Result<serde_json::Value, _> = client.get("/test").await;
+
+        match result {
+            Err(Error::RateLimit { retry_after, .. }) => {
+                assert_eq!(retry_after, Some(30));
+            }
+            _ => panic!("Expected rate limit error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_server_error() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(500).set_body_json(serde_json::json!({
+                "error": "Server error"
+            })))
+            .expect(3)
             .mount(&mock_server)
             .await;
 
@@ -579,6 +2727,374 @@ mod tests {
         assert!(matches!(result, Err(Error::Server { .. })));
     }
 
+    #[tokio::test]
+    async fn test_retry_policy_none_disables_retries() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(503).set_body_json(serde_json::json!({
+                "error": "Service unavailable"
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .max_retries(3)
+            .retry_policy(RetryPolicy::None);
+        let client = HttpClient::new(config).unwrap();
+
+        let result: std::result::Result<serde_json::Value, _> = client.get("/test").await;
+        assert!(matches!(
+            result,
+            Err(Error::Server {
+                status_code: 503,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_diagnostics_record_attempts_and_failures() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(503).set_body_json(serde_json::json!({
+                "error": "Service unavailable"
+            })))
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .max_retries(3)
+            .retry_policy(RetryPolicy::Fixed(Duration::ZERO))
+            .diagnostics(true);
+        let client = HttpClient::new(config).unwrap();
+
+        let result: Result<serde_json::Value> = client.get("/test").await;
+        assert!(result.is_err());
+
+        let diagnostics = client.diagnostics().unwrap();
+        let stats = diagnostics.endpoint("GET test").unwrap();
+        assert_eq!(stats.requests, 1);
+        assert_eq!(stats.attempts, 3);
+        assert_eq!(stats.failures, 1);
+        assert_eq!(stats.retry_ratio(), 2.0);
+
+        let disabled = HttpClient::new(ClientConfig::new("test_key")).unwrap();
+        assert!(disabled.diagnostics().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_large_bodies_are_gzipped() {
+        use std::io::Read;
+
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/test"))
+            .and(header("Content-Encoding", "gzip"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"ok": true}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .compress_requests_over(64);
+        let client = HttpClient::new(config).unwrap();
+
+        let emails: Vec<String> = (0..100).map(|i| format!("user{}@example.com", i)).collect();
+        let body = serde_json::json!({ "emails": emails });
+        let _: serde_json::Value = client.post("/test", &body).await.unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(&requests[0].body[..])
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&decoded).unwrap(),
+            body
+        );
+    }
+
+    #[tokio::test]
+    async fn test_oversized_body_is_rejected_before_sending() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .max_body_size(32);
+        let client = HttpClient::new(config).unwrap();
+
+        let body = serde_json::json!({ "html": "x".repeat(64) });
+        let result: Result<serde_json::Value> = client.post("/test", &body).await;
+        assert!(matches!(
+            result,
+            Err(Error::PayloadTooLarge { limit: 32, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_retry_rate_limits_honors_retry_after() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "0")
+                    .set_body_json(serde_json::json!({
+                        "error": "Rate limit exceeded"
+                    })),
+            )
+            .up_to_n_times(1)
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"id": "123"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .retry_rate_limits(true);
+        let client = HttpClient::new(config).unwrap();
+
+        let result: serde_json::Value = client.get("/test").await.unwrap();
+        assert_eq!(result["id"], "123");
+    }
+
+    #[tokio::test]
+    async fn test_retry_after_beyond_cap_is_returned() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(
+                ResponseTemplate::new(429)
+                    .insert_header("Retry-After", "120")
+                    .set_body_json(serde_json::json!({
+                        "error": "Rate limit exceeded"
+                    })),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .retry_rate_limits(true)
+            .max_retry_after(Duration::from_secs(10));
+        let client = HttpClient::new(config).unwrap();
+
+        let result: std::result::Result<serde_json::Value, _> = client.get("/test").await;
+        assert_eq!(result.unwrap_err().retry_after(), Some(120));
+    }
+
+    #[derive(Debug, Default)]
+    struct FlakyTransport {
+        calls: std::sync::atomic::AtomicU32,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for FlakyTransport {
+        async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if call == 0 {
+                return Err(Error::Transport {
+                    message: "connection reset".to_string(),
+                    retryable: true,
+                });
+            }
+
+            assert_eq!(request.url.path(), "/api/v1/test");
+            assert_eq!(request.headers["X-API-Key"], "test_key");
+            Ok(TransportResponse {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: br#"{"success": true, "data": {"id": "789"}}"#.to_vec(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_custom_transport() {
+        let transport = Arc::new(FlakyTransport::default());
+        let mut config = ClientConfig::new("test_key");
+        config.transport = Some(transport.clone());
+        let client = HttpClient::new(config).unwrap();
+
+        let result: serde_json::Value = client.get("/test").await.unwrap();
+        assert_eq!(result["id"], "789");
+        assert_eq!(transport.calls.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_etag_cache_serves_not_modified() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/contact-lists/list_1"))
+            .and(header("If-None-Match", "\"v1\""))
+            .respond_with(ResponseTemplate::new(304))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/contact-lists/list_1"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v1\"")
+                    .set_body_json(serde_json::json!({"success": true, "data": {"name": "VIP"}})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .etag_cache(16);
+        let client = HttpClient::new(config).unwrap();
+
+        for _ in 0..2 {
+            let list: serde_json::Value = client.get("/contact-lists/list_1").await.unwrap();
+            assert_eq!(list["name"], "VIP");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fails_over_after_connect_failures() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"region": "eu"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        // Nothing listens on port 1, so every attempt on the primary is refused
+        let config = ClientConfig::new("test_key")
+            .base_url("http://127.0.0.1:1")
+            .fallback_base_url(mock_server.uri())
+            .retry_policy(RetryPolicy::Fixed(Duration::from_millis(1)));
+        let client = HttpClient::new(config).unwrap();
+
+        let data: serde_json::Value = client
+            .get_with_params("/test", &serde_json::json!({"page": 2}))
+            .await
+            .unwrap();
+        assert_eq!(data["region"], "eu");
+    }
+
+    #[tokio::test]
+    async fn test_retry_classifier_overrides_defaults() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(
+                ResponseTemplate::new(408).set_body_json(serde_json::json!({"error": "Timeout"})),
+            )
+            .expect(3)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/test"))
+            .respond_with(
+                ResponseTemplate::new(500).set_body_json(serde_json::json!({"error": "Oops"})),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .max_retries(3)
+            .retry_policy(RetryPolicy::Fixed(Duration::from_millis(1)))
+            .retry_classifier(|method: &Method, error: &Error| match error.status_code() {
+                Some(408) => true,
+                Some(500) => method != Method::POST,
+                _ => error.is_retryable(),
+            });
+        let client = HttpClient::new(config).unwrap();
+
+        let error = client.get::<serde_json::Value>("/test").await.unwrap_err();
+        assert_eq!(error.status_code(), Some(408));
+        let error = client
+            .post::<serde_json::Value, _>("/test", &serde_json::json!({}))
+            .await
+            .unwrap_err();
+        assert_eq!(error.status_code(), Some(500));
+    }
+
+    #[tokio::test]
+    async fn test_retry_deadline_stops_retrying() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/test"))
+            .respond_with(
+                ResponseTemplate::new(503)
+                    .set_body_json(serde_json::json!({"error": "Unavailable"})),
+            )
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .max_retries(10)
+            .retry_policy(RetryPolicy::Fixed(Duration::from_millis(300)))
+            .retry_deadline(Duration::from_millis(500));
+        let client = HttpClient::new(config).unwrap();
+
+        let started = Instant::now();
+        let error = client.get::<serde_json::Value>("/test").await.unwrap_err();
+        assert_eq!(error.status_code(), Some(503));
+        assert!(started.elapsed() < Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_redact_path_masks_emails_and_query() {
+        assert_eq!(
+            redact_path("/suppressions/jane%40example.com"),
+            "/suppressions/[REDACTED]"
+        );
+        assert_eq!(
+            redact_path("/suppressions/jane@example.com?force=1"),
+            "/suppressions/[REDACTED]"
+        );
+        assert_eq!(redact_path("/emails/msg_1"), "/emails/msg_1");
+    }
+
     #[test]
     fn test_api_key_redacted_in_debug() {
         let config = ClientConfig::new("super_secret_api_key_12345");
@@ -0,0 +1,59 @@
+use reqwest::header::HeaderMap;
+use std::sync::Arc;
+
+/// Callback invoked when the API flags an endpoint as deprecated
+pub type DeprecationHandler = Arc<dyn Fn(&DeprecationNotice) + Send + Sync>;
+
+/// Deprecation details sent by the API via `Deprecation`/`Sunset` headers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeprecationNotice {
+    /// HTTP method of the request that triggered the notice
+    pub method: String,
+    /// API path of the request, without the version prefix
+    pub path: String,
+    /// Raw `Deprecation` header (a date, or `true`)
+    pub deprecation: Option<String>,
+    /// Raw `Sunset` header: the date the endpoint stops working
+    pub sunset: Option<String>,
+    /// Raw `Link` header, usually pointing at migration docs
+    pub link: Option<String>,
+}
+
+impl DeprecationNotice {
+    /// Build a notice from response headers, if any deprecation header is present
+    pub(crate) fn from_headers(method: &str, path: &str, headers: &HeaderMap) -> Option<Self> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.to_string())
+        };
+
+        let deprecation = header("Deprecation");
+        let sunset = header("Sunset");
+        if deprecation.is_none() && sunset.is_none() {
+            return None;
+        }
+
+        Some(Self {
+            method: method.to_string(),
+            path: path.to_string(),
+            deprecation,
+            sunset,
+            link: header("Link"),
+        })
+    }
+}
+
+impl std::fmt::Display for DeprecationNotice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} is deprecated", self.method, self.path)?;
+        if let Some(sunset) = &self.sunset {
+            write!(f, " and will be removed on {}", sunset)?;
+        }
+        if let Some(link) = &self.link {
+            write!(f, " (see {})", link)?;
+        }
+        Ok(())
+    }
+}
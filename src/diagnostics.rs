@@ -0,0 +1,150 @@
+//! In-process latency and retry statistics, enabled with `ClientConfig::diagnostics`
+
+use crate::telemetry::resource;
+use reqwest::Method;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Latency samples kept per endpoint; older samples are dropped first
+const MAX_SAMPLES: usize = 1024;
+
+#[derive(Debug, Default)]
+struct EndpointStats {
+    requests: u64,
+    attempts: u64,
+    failures: u64,
+    latencies: VecDeque<Duration>,
+}
+
+/// Collects per-endpoint statistics from the retry loop
+#[derive(Debug, Default)]
+pub(crate) struct Recorder {
+    endpoints: Mutex<BTreeMap<String, EndpointStats>>,
+}
+
+impl Recorder {
+    /// Record a single attempt and its latency
+    pub(crate) fn record_attempt(&self, method: &Method, path: &str, elapsed: Duration) {
+        let mut endpoints = self.endpoints.lock().unwrap_or_else(|e| e.into_inner());
+        let stats = endpoints.entry(endpoint(method, path)).or_default();
+        stats.attempts += 1;
+        if stats.latencies.len() == MAX_SAMPLES {
+            stats.latencies.pop_front();
+        }
+        stats.latencies.push_back(elapsed);
+    }
+
+    /// Record the outcome of a request once retries are exhausted or it succeeded
+    pub(crate) fn record_request(&self, method: &Method, path: &str, success: bool) {
+        let mut endpoints = self.endpoints.lock().unwrap_or_else(|e| e.into_inner());
+        let stats = endpoints.entry(endpoint(method, path)).or_default();
+        stats.requests += 1;
+        if !success {
+            stats.failures += 1;
+        }
+    }
+
+    /// Snapshot the current statistics
+    pub(crate) fn snapshot(&self) -> Diagnostics {
+        let endpoints = self.endpoints.lock().unwrap_or_else(|e| e.into_inner());
+        let endpoints = endpoints
+            .iter()
+            .map(|(name, stats)| {
+                let mut latencies: Vec<Duration> = stats.latencies.iter().copied().collect();
+                latencies.sort();
+                EndpointDiagnostics {
+                    endpoint: name.clone(),
+                    requests: stats.requests,
+                    attempts: stats.attempts,
+                    failures: stats.failures,
+                    p50: percentile(&latencies, 0.50),
+                    p90: percentile(&latencies, 0.90),
+                    p99: percentile(&latencies, 0.99),
+                }
+            })
+            .collect();
+
+        Diagnostics { endpoints }
+    }
+}
+
+/// Endpoint label, e.g. `GET emails`
+fn endpoint(method: &Method, path: &str) -> String {
+    format!("{} {}", method, resource(path))
+}
+
+/// Nearest-rank percentile of sorted samples
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Snapshot of the client's self-recorded request statistics
+#[derive(Debug, Clone, Default)]
+pub struct Diagnostics {
+    /// Statistics per endpoint, sorted by endpoint label
+    pub endpoints: Vec<EndpointDiagnostics>,
+}
+
+impl Diagnostics {
+    /// Look up statistics for an endpoint label such as `GET emails`
+    pub fn endpoint(&self, endpoint: &str) -> Option<&EndpointDiagnostics> {
+        self.endpoints.iter().find(|e| e.endpoint == endpoint)
+    }
+}
+
+/// Latency and retry statistics for one endpoint
+///
+/// Latencies are measured per attempt over the most recent samples.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndpointDiagnostics {
+    /// Method and resource, e.g. `POST emails`
+    pub endpoint: String,
+    /// Completed requests, including failed ones
+    pub requests: u64,
+    /// HTTP attempts, including retries
+    pub attempts: u64,
+    /// Requests that returned an error after all retries
+    pub failures: u64,
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+}
+
+impl EndpointDiagnostics {
+    /// Average number of retries per request
+    pub fn retry_ratio(&self) -> f64 {
+        if self.requests == 0 {
+            return 0.0;
+        }
+        self.attempts.saturating_sub(self.requests) as f64 / self.requests as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_percentiles_and_retry_ratio() {
+        let recorder = Recorder::default();
+        for ms in 1..=100 {
+            recorder.record_attempt(&Method::GET, "/emails", Duration::from_millis(ms));
+        }
+        for _ in 0..50 {
+            recorder.record_request(&Method::GET, "/emails", true);
+        }
+
+        let diagnostics = recorder.snapshot();
+        let emails = diagnostics.endpoint("GET emails").unwrap();
+        assert_eq!(emails.p50, Duration::from_millis(50));
+        assert_eq!(emails.p90, Duration::from_millis(90));
+        assert_eq!(emails.p99, Duration::from_millis(99));
+        assert_eq!(emails.retry_ratio(), 1.0);
+        assert!(diagnostics.endpoint("POST emails").is_none());
+    }
+}
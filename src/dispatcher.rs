@@ -0,0 +1,484 @@
+//! Quota-aware sending for large campaigns
+//!
+//! [`CampaignDispatcher`] sends one email per recipient from a stream, spaced
+//! evenly so an hourly or daily quota is never exceeded, and records its
+//! position and failed sends in a [`CheckpointStore`] after every send so a
+//! restarted process picks up where the last one stopped.
+
+use crate::error::{Error, Result};
+use crate::options::RequestOptions;
+use crate::resources::Emails;
+use crate::types::SendEmailParams;
+use async_trait::async_trait;
+use futures_util::{Stream, StreamExt};
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::Instant;
+
+const HOUR: Duration = Duration::from_secs(60 * 60);
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// How far a campaign has got, saved after every recipient
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Checkpoint {
+    /// Recipients consumed from the stream, whether the send succeeded or not
+    pub position: u64,
+    pub sent: u64,
+    pub failed: u64,
+}
+
+/// Persists campaign checkpoints and failed sends between runs
+///
+/// Implement this over a database row or a file so a campaign survives
+/// process restarts.
+#[async_trait]
+pub trait CheckpointStore: std::fmt::Debug + Send + Sync {
+    /// The last checkpoint saved for `campaign_id`, if any
+    async fn load(&self, campaign_id: &str) -> Result<Option<Checkpoint>>;
+
+    async fn save(&self, campaign_id: &str, checkpoint: &Checkpoint) -> Result<()>;
+
+    /// Record a send that failed for good, before the checkpoint moves past it
+    ///
+    /// The default discards the failure; override it (and
+    /// [`failures`](Self::failures)) to keep failures across restarts.
+    async fn record_failure(&self, _campaign_id: &str, _failure: &CampaignFailure) -> Result<()> {
+        Ok(())
+    }
+
+    /// Every failure recorded for `campaign_id`, across all runs
+    async fn failures(&self, _campaign_id: &str) -> Result<Vec<CampaignFailure>> {
+        Ok(Vec::new())
+    }
+}
+
+/// In-process [`CheckpointStore`]; progress is lost when the process exits
+#[derive(Debug, Default)]
+pub struct MemoryCheckpointStore {
+    checkpoints: Mutex<HashMap<String, Checkpoint>>,
+    failures: Mutex<HashMap<String, Vec<CampaignFailure>>>,
+}
+
+#[async_trait]
+impl CheckpointStore for MemoryCheckpointStore {
+    async fn load(&self, campaign_id: &str) -> Result<Option<Checkpoint>> {
+        let checkpoints = self.checkpoints.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(checkpoints.get(campaign_id).copied())
+    }
+
+    async fn save(&self, campaign_id: &str, checkpoint: &Checkpoint) -> Result<()> {
+        let mut checkpoints = self.checkpoints.lock().unwrap_or_else(|e| e.into_inner());
+        checkpoints.insert(campaign_id.to_string(), *checkpoint);
+        Ok(())
+    }
+
+    async fn record_failure(&self, campaign_id: &str, failure: &CampaignFailure) -> Result<()> {
+        let mut failures = self.failures.lock().unwrap_or_else(|e| e.into_inner());
+        failures
+            .entry(campaign_id.to_string())
+            .or_default()
+            .push(failure.clone());
+        Ok(())
+    }
+
+    async fn failures(&self, campaign_id: &str) -> Result<Vec<CampaignFailure>> {
+        let failures = self.failures.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(failures.get(campaign_id).cloned().unwrap_or_default())
+    }
+}
+
+/// A send that failed during a campaign run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CampaignFailure {
+    /// Zero-based position of the recipient in the stream
+    pub position: u64,
+    pub to: Vec<String>,
+    pub message: String,
+}
+
+/// Outcome of [`CampaignDispatcher::run`]
+#[derive(Debug, Clone, Default)]
+pub struct CampaignReport {
+    /// Totals across every run of the campaign, including earlier ones
+    pub checkpoint: Checkpoint,
+    /// Position the run started from; non-zero when resuming
+    pub resumed_from: u64,
+    /// Sends that failed in this run
+    pub failures: Vec<CampaignFailure>,
+}
+
+/// Sends a campaign within hourly and daily quotas, resuming after restarts
+///
+/// Sends are spaced at the interval the strictest quota allows (an hourly
+/// quota of 600 means one send every 6 seconds), so volume is spread across
+/// the window instead of bursting into the limit. A send the API rejects for
+/// that recipient (bad request, validation, suppression, size) is recorded in
+/// the [`CheckpointStore`] and the campaign moves on; any other error stops
+/// the run without consuming the recipient.
+///
+/// The recipient stream must yield the same recipients in the same order on
+/// every run: resuming skips as many items as the checkpoint has consumed.
+/// Each send carries the idempotency key `{campaign_id}-{position}`, so a
+/// crash between sending and saving the checkpoint never sends twice.
+///
+/// # Example
+/// ```rust,no_run
+/// use futures_util::{stream, StreamExt};
+/// use mailbreeze::{CampaignDispatcher, MailBreeze, MemoryCheckpointStore, SendEmailParams};
+///
+/// # async fn run(client: MailBreeze, subscribers: Vec<String>) -> mailbreeze::Result<()> {
+/// let recipients = stream::iter(subscribers).map(|to| SendEmailParams {
+///     from: "news@example.com".to_string(),
+///     to: vec![to],
+///     template_id: Some("tmpl_spring_sale".to_string()),
+///     ..Default::default()
+/// });
+///
+/// let report = CampaignDispatcher::new(client.emails.clone(), "spring-sale-2024")
+///     .hourly_quota(5_000)
+///     .daily_quota(50_000)
+///     .checkpoint_store(MemoryCheckpointStore::default())
+///     .run(recipients)
+///     .await?;
+/// println!("{} sent, {} failed", report.checkpoint.sent, report.checkpoint.failed);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CampaignDispatcher {
+    emails: Emails,
+    campaign_id: String,
+    hourly_quota: Option<u32>,
+    daily_quota: Option<u32>,
+    store: Arc<dyn CheckpointStore>,
+}
+
+impl CampaignDispatcher {
+    /// Dispatch the campaign `campaign_id`, which keys its checkpoints
+    pub fn new(emails: Emails, campaign_id: impl Into<String>) -> Self {
+        Self {
+            emails,
+            campaign_id: campaign_id.into(),
+            hourly_quota: None,
+            daily_quota: None,
+            store: Arc::new(MemoryCheckpointStore::default()),
+        }
+    }
+
+    /// Send at most `quota` emails per hour
+    pub fn hourly_quota(mut self, quota: u32) -> Self {
+        self.hourly_quota = Some(quota);
+        self
+    }
+
+    /// Send at most `quota` emails per day
+    pub fn daily_quota(mut self, quota: u32) -> Self {
+        self.daily_quota = Some(quota);
+        self
+    }
+
+    /// Persist progress in `store` instead of in memory
+    pub fn checkpoint_store(mut self, store: impl CheckpointStore + 'static) -> Self {
+        self.store = Arc::new(store);
+        self
+    }
+
+    /// Time between sends that keeps every quota; zero without quotas
+    pub fn send_interval(&self) -> Duration {
+        [(self.hourly_quota, HOUR), (self.daily_quota, DAY)]
+            .into_iter()
+            .filter_map(|(quota, window)| Some(window / quota?.max(1)))
+            .max()
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Send to every recipient in `recipients` not covered by the saved checkpoint
+    ///
+    /// Returns early with the error if the checkpoint store fails, or if a
+    /// send fails for a reason other than the recipient's email being
+    /// rejected (e.g. a revoked key, a rate limit or cancellation). The last
+    /// saved checkpoint is still valid for resuming, and the next run retries
+    /// that recipient under the same idempotency key.
+    pub async fn run<S>(&self, recipients: S) -> Result<CampaignReport>
+    where
+        S: Stream<Item = SendEmailParams>,
+    {
+        let mut checkpoint = self
+            .store
+            .load(&self.campaign_id)
+            .await?
+            .unwrap_or_default();
+        let mut report = CampaignReport {
+            resumed_from: checkpoint.position,
+            ..Default::default()
+        };
+        if checkpoint.position > 0 {
+            log::info!(
+                target: "mailbreeze",
+                "resuming campaign {} at recipient {}",
+                self.campaign_id,
+                checkpoint.position
+            );
+        }
+
+        let interval = self.send_interval();
+        let mut next_send = Instant::now();
+        let mut recipients = std::pin::pin!(recipients.skip(checkpoint.position as usize));
+
+        while let Some(params) = recipients.next().await {
+            tokio::time::sleep_until(next_send).await;
+            next_send = Instant::now() + interval;
+
+            let options = RequestOptions::new()
+                .idempotency_key(format!("{}-{}", self.campaign_id, checkpoint.position));
+            match self.emails.send_with_options(&params, &options).await {
+                Ok(_) => checkpoint.sent += 1,
+                Err(e) if !self.is_recipient_failure(&e) => {
+                    log::warn!(
+                        target: "mailbreeze",
+                        "stopping campaign {} at recipient {}: {}",
+                        self.campaign_id,
+                        checkpoint.position,
+                        e
+                    );
+                    return Err(e);
+                }
+                Err(e) => {
+                    let failure = CampaignFailure {
+                        position: checkpoint.position,
+                        to: params.to,
+                        message: e.to_string(),
+                    };
+                    self.store
+                        .record_failure(&self.campaign_id, &failure)
+                        .await?;
+                    checkpoint.failed += 1;
+                    report.failures.push(failure);
+                }
+            }
+            checkpoint.position += 1;
+            self.store.save(&self.campaign_id, &checkpoint).await?;
+        }
+
+        report.checkpoint = checkpoint;
+        Ok(report)
+    }
+
+    /// Whether `error` rejects this recipient's email rather than the run
+    ///
+    /// Anything else (authentication, permissions, cancellation, outages)
+    /// would fail every remaining recipient the same way.
+    fn is_recipient_failure(&self, error: &Error) -> bool {
+        let rejected = matches!(
+            error,
+            Error::BadRequest { .. }
+                | Error::Validation { .. }
+                | Error::RecipientSuppressed { .. }
+                | Error::PayloadTooLarge { .. }
+        );
+        rejected && !self.emails.client().is_retryable(&Method::POST, error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{ClientConfig, HttpClient};
+    use futures_util::stream;
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn setup() -> (MockServer, Emails) {
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .max_retries(0);
+        let emails = Emails::new(HttpClient::new(config).unwrap());
+        (mock_server, emails)
+    }
+
+    fn recipients(count: usize) -> impl Stream<Item = SendEmailParams> {
+        stream::iter(0..count).map(|n| SendEmailParams {
+            from: "news@example.com".to_string(),
+            to: vec![format!("user{}@example.com", n)],
+            subject: Some("Spring sale".to_string()),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_send_interval_uses_strictest_quota() {
+        let emails = Emails::new(HttpClient::new(ClientConfig::new("test_key")).unwrap());
+        let dispatcher = CampaignDispatcher::new(emails, "c1");
+        assert_eq!(dispatcher.send_interval(), Duration::ZERO);
+
+        let dispatcher = dispatcher.hourly_quota(600).daily_quota(24_000);
+        // 600/hour is one per 6s; 24,000/day would allow one per 3.6s
+        assert_eq!(dispatcher.send_interval(), Duration::from_secs(6));
+    }
+
+    #[tokio::test]
+    async fn test_resumes_from_checkpoint() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(body_partial_json(
+                serde_json::json!({"to": ["user3@example.com"]}),
+            ))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "success": false,
+                "error": "invalid recipient"
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(header("Idempotency-Key", "spring-2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_2"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        // A previous run got through the first two recipients
+        let store = Arc::new(MemoryCheckpointStore::default());
+        let previous = Checkpoint {
+            position: 2,
+            sent: 2,
+            failed: 0,
+        };
+        store.save("spring", &previous).await.unwrap();
+
+        let dispatcher = CampaignDispatcher {
+            store: store.clone(),
+            ..CampaignDispatcher::new(emails, "spring").hourly_quota(3_600_000)
+        };
+        let report = dispatcher.run(recipients(5)).await.unwrap();
+
+        assert_eq!(report.resumed_from, 2);
+        assert_eq!(
+            report.checkpoint,
+            Checkpoint {
+                position: 5,
+                sent: 4,
+                failed: 1
+            }
+        );
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].position, 3);
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 3);
+        assert_eq!(store.load("spring").await.unwrap(), Some(report.checkpoint));
+    }
+
+    #[tokio::test]
+    async fn test_transient_failure_stops_without_consuming_recipient() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(body_partial_json(
+                serde_json::json!({"to": ["user1@example.com"]}),
+            ))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "success": false,
+                "error": "invalid recipient"
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(body_partial_json(
+                serde_json::json!({"to": ["user2@example.com"]}),
+            ))
+            .respond_with(ResponseTemplate::new(503).set_body_json(serde_json::json!({
+                "success": false,
+                "error": "unavailable"
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let store = Arc::new(MemoryCheckpointStore::default());
+        let dispatcher = CampaignDispatcher {
+            store: store.clone(),
+            ..CampaignDispatcher::new(emails, "summer")
+        };
+        let error = dispatcher.run(recipients(4)).await.unwrap_err();
+
+        assert_eq!(error.status_code(), Some(503));
+        assert_eq!(
+            store.load("summer").await.unwrap(),
+            Some(Checkpoint {
+                position: 2,
+                sent: 1,
+                failed: 1
+            })
+        );
+        let failures = store.failures("summer").await.unwrap();
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].position, 1);
+        assert_eq!(failures[0].to, vec!["user1@example.com".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_authentication_failure_stops_the_run() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(body_partial_json(
+                serde_json::json!({"to": ["user1@example.com"]}),
+            ))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "success": false,
+                "error": "invalid api key"
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let store = Arc::new(MemoryCheckpointStore::default());
+        let dispatcher = CampaignDispatcher {
+            store: store.clone(),
+            ..CampaignDispatcher::new(emails, "autumn")
+        };
+        let error = dispatcher.run(recipients(4)).await.unwrap_err();
+
+        assert!(matches!(error, Error::Authentication { .. }));
+        let checkpoint = store.load("autumn").await.unwrap().unwrap();
+        assert_eq!(checkpoint.position, 1);
+        assert_eq!(checkpoint.failed, 0);
+        assert!(store.failures("autumn").await.unwrap().is_empty());
+        assert_eq!(mock_server.received_requests().await.unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,316 @@
+//! Downloads of large files such as verification results and exports
+//!
+//! [`HttpClient::download_to`] fetches files in `Range` chunks, so a connection
+//! that drops midway only costs the current chunk instead of restarting from
+//! byte zero. [`HttpClient::get_stream`] streams the body of a single request
+//! as it arrives.
+
+use crate::client::HttpClient;
+use crate::error::Result;
+use crate::options::RequestOptions;
+use bytes::Bytes;
+use futures_util::stream::{self, Stream, TryStreamExt};
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use serde::Serialize;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// Bytes requested per `Range` request
+const DOWNLOAD_CHUNK_SIZE: u64 = 8 * 1024 * 1024;
+
+impl HttpClient {
+    /// Download a file into `writer`, returning the number of bytes written
+    ///
+    /// Transient failures are retried per chunk, resuming from the last byte
+    /// written. Servers that ignore `Range` are handled by a single full download.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::HttpClient) -> mailbreeze::Result<()> {
+    /// let mut file = tokio::fs::File::create("results.csv").await?;
+    /// client.download_to("/email-verification/ver_123/download", &mut file).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_to<W>(&self, path: &str, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        self.download_from(path, 0, writer).await
+    }
+
+    /// Continue a download at byte `offset`, e.g. appending to a partially written file
+    ///
+    /// Returns the total file size written so far, including `offset`.
+    pub async fn download_from<W>(&self, path: &str, offset: u64, writer: &mut W) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        self.download_chunks(path, offset, DOWNLOAD_CHUNK_SIZE, writer)
+            .await
+    }
+
+    /// Stream a file's bytes as they arrive, without buffering it in memory
+    ///
+    /// The file is read over a single request. Failures before the response
+    /// starts are retried; a connection dropped mid-body ends the stream with
+    /// the error, so use [`download_to`](Self::download_to) when resuming
+    /// matters. The client's `timeout` covers the whole body with the default
+    /// transport.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use futures_util::TryStreamExt;
+    ///
+    /// # async fn run(client: mailbreeze::HttpClient) -> mailbreeze::Result<()> {
+    /// let mut stream = std::pin::pin!(client.get_stream("/email-verification/ver_123/download"));
+    /// while let Some(chunk) = stream.try_next().await? {
+    ///     println!("{} bytes", chunk.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_stream(&self, path: impl Into<String>) -> impl Stream<Item = Result<Bytes>> + '_ {
+        let path = path.into();
+        stream::once(async move {
+            self.execute_stream(&path, None, &RequestOptions::default())
+                .await
+        })
+        .try_flatten()
+    }
+
+    /// Like [`get_stream`](Self::get_stream), with `params` encoded as the query string
+    pub(crate) fn get_stream_with_params<Q: Serialize>(
+        &self,
+        path: impl Into<String>,
+        params: &Q,
+    ) -> impl Stream<Item = Result<Bytes>> + '_ {
+        let path = path.into();
+        let query = crate::query::to_pairs(params);
+        stream::once(async move {
+            let query = query?;
+            self.execute_stream(&path, Some(&query), &RequestOptions::default())
+                .await
+        })
+        .try_flatten()
+    }
+
+    pub(crate) async fn download_chunks<W>(
+        &self,
+        path: &str,
+        offset: u64,
+        chunk_size: u64,
+        writer: &mut W,
+    ) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let mut chunks =
+            std::pin::pin!(self.stream_chunks(path.to_string(), Vec::new(), offset, chunk_size));
+        let mut written = offset;
+        while let Some(chunk) = chunks.try_next().await? {
+            writer.write_all(&chunk).await?;
+            written += chunk.len() as u64;
+        }
+        writer.flush().await?;
+        Ok(written)
+    }
+
+    /// Chunks of the file from `offset` on, one `Range` request each
+    fn stream_chunks(
+        &self,
+        path: String,
+        query: Vec<(String, String)>,
+        offset: u64,
+        chunk_size: u64,
+    ) -> impl Stream<Item = Result<Bytes>> + '_ {
+        // `None` once the last chunk has been yielded
+        stream::try_unfold(Some(offset), move |offset| {
+            let path = path.clone();
+            let query = query.clone();
+            async move {
+                let Some(offset) = offset else {
+                    return Ok(None);
+                };
+                let range = format!("bytes={}-{}", offset, offset + chunk_size - 1);
+                let options = RequestOptions::new().header("Range", range);
+
+                let response = match self.fetch_chunk(&path, &query, &options).await {
+                    Ok(response) => response,
+                    // Asked for bytes past the end: the file is already complete
+                    Err(e) if e.status_code() == Some(416) => return Ok(None),
+                    Err(e) => return Err(e),
+                };
+
+                if response.status != StatusCode::PARTIAL_CONTENT {
+                    // Range not supported: the body is the whole file
+                    let start = (offset as usize).min(response.body.len());
+                    return Ok(Some((Bytes::from(response.body).slice(start..), None)));
+                }
+                if response.body.is_empty() {
+                    return Ok(None);
+                }
+
+                let received = response.body.len() as u64;
+                let next = offset + received;
+                let done = match content_range_total(&response.headers) {
+                    Some(total) => next >= total,
+                    None => received < chunk_size,
+                };
+                Ok(Some((Bytes::from(response.body), (!done).then_some(next))))
+            }
+        })
+    }
+
+    /// Fetch one chunk; transient failures are retried by `execute`'s retry policy
+    async fn fetch_chunk(
+        &self,
+        path: &str,
+        query: &[(String, String)],
+        options: &RequestOptions,
+    ) -> Result<crate::transport::TransportResponse> {
+        let query = (!query.is_empty()).then_some(query);
+        self.execute(Method::GET, path, None, query, options).await
+    }
+}
+
+/// Total size from a `Content-Range: bytes start-end/total` header
+fn content_range_total(headers: &HeaderMap) -> Option<u64> {
+    headers
+        .get("Content-Range")?
+        .to_str()
+        .ok()?
+        .rsplit('/')
+        .next()?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClientConfig;
+    use crate::error::Error;
+    use crate::retry::RetryPolicy;
+    use crate::transport::{Transport, TransportRequest, TransportResponse};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// Serves `data` by range and fails the second request with a retryable error
+    #[derive(Debug)]
+    struct RangeTransport {
+        data: Vec<u8>,
+        calls: AtomicU32,
+    }
+
+    #[async_trait::async_trait]
+    impl Transport for RangeTransport {
+        async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+            if self.calls.fetch_add(1, Ordering::SeqCst) == 1 {
+                return Err(Error::Transport {
+                    message: "connection reset".to_string(),
+                    retryable: true,
+                });
+            }
+
+            let range = request.headers["Range"].to_str().unwrap();
+            let (start, end) = range.trim_start_matches("bytes=").split_once('-').unwrap();
+            let start: usize = start.parse().unwrap();
+            let end: usize = end.parse::<usize>().unwrap().min(self.data.len() - 1);
+
+            let mut headers = HeaderMap::new();
+            headers.insert(
+                "Content-Range",
+                format!("bytes {}-{}/{}", start, end, self.data.len())
+                    .parse()
+                    .unwrap(),
+            );
+            Ok(TransportResponse {
+                status: StatusCode::PARTIAL_CONTENT,
+                headers,
+                body: self.data[start..=end].to_vec(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_download_resumes_after_failed_chunk() {
+        let transport = Arc::new(RangeTransport {
+            data: b"0123456789abcdefghij".to_vec(),
+            calls: AtomicU32::new(0),
+        });
+        let mut config =
+            ClientConfig::new("test_key").retry_policy(RetryPolicy::Fixed(Duration::ZERO));
+        config.transport = Some(transport.clone());
+        let client = HttpClient::new(config).unwrap();
+
+        let mut out = Vec::new();
+        let written = client
+            .download_chunks("/exports/1/download", 0, 8, &mut out)
+            .await
+            .unwrap();
+
+        assert_eq!(written, 20);
+        assert_eq!(out, b"0123456789abcdefghij");
+        // 3 chunks plus one retried after the dropped connection
+        assert_eq!(transport.calls.load(Ordering::SeqCst), 4);
+    }
+
+    #[tokio::test]
+    async fn test_stream_yields_chunks_in_order() {
+        let transport = Arc::new(RangeTransport {
+            data: b"0123456789abcdefghij".to_vec(),
+            calls: AtomicU32::new(0),
+        });
+        let mut config =
+            ClientConfig::new("test_key").retry_policy(RetryPolicy::Fixed(Duration::ZERO));
+        config.transport = Some(transport.clone());
+        let client = HttpClient::new(config).unwrap();
+
+        let chunks: Vec<Bytes> = client
+            .stream_chunks("/exports/1/download".to_string(), Vec::new(), 0, 8)
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.concat(), b"0123456789abcdefghij");
+    }
+
+    #[tokio::test]
+    async fn test_get_stream_reads_one_response() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/exports/1/download"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_bytes(b"0123456789abcdefghij".to_vec()),
+            )
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+
+        let chunks: Vec<Bytes> = client
+            .get_stream("/exports/1/download")
+            .try_collect()
+            .await
+            .unwrap();
+
+        assert_eq!(chunks.concat(), b"0123456789abcdefghij");
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(!requests[0].headers.contains_key("Range"));
+    }
+
+    #[test]
+    fn test_content_range_total() {
+        let mut headers = HeaderMap::new();
+        headers.insert("Content-Range", "bytes 0-7/20".parse().unwrap());
+        assert_eq!(content_range_total(&headers), Some(20));
+        assert_eq!(content_range_total(&HeaderMap::new()), None);
+    }
+}
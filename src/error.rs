@@ -1,3 +1,4 @@
+use crate::field_path::FieldPath;
 use std::collections::HashMap;
 use thiserror::Error;
 
@@ -9,6 +10,19 @@ pub enum Error {
     Authentication {
         message: String,
         code: Option<String>,
+        /// Value of the `X-Request-Id` response header, for support tickets
+        request_id: Option<String>,
+    },
+
+    /// The API key lacks a required scope (403)
+    #[error("Forbidden: {message}")]
+    Forbidden {
+        message: String,
+        /// Scope the key would need, when the API provides a hint
+        required_scope: Option<String>,
+        code: Option<String>,
+        /// Value of the `X-Request-Id` response header, for support tickets
+        request_id: Option<String>,
     },
 
     /// Bad request (400)
@@ -16,6 +30,21 @@ pub enum Error {
     BadRequest {
         message: String,
         code: Option<String>,
+        /// Value of the `X-Request-Id` response header, for support tickets
+        request_id: Option<String>,
+    },
+
+    /// A send was rejected because a recipient is suppressed (400)
+    #[error("Recipient {email} is suppressed ({reason})")]
+    RecipientSuppressed {
+        /// The suppressed address
+        email: String,
+        /// Why the address is suppressed, e.g. the recipient unsubscribed
+        reason: crate::types::SuppressReason,
+        message: String,
+        code: Option<String>,
+        /// Value of the `X-Request-Id` response header, for support tickets
+        request_id: Option<String>,
     },
 
     /// Resource not found (404)
@@ -23,6 +52,28 @@ pub enum Error {
     NotFound {
         message: String,
         code: Option<String>,
+        /// Value of the `X-Request-Id` response header, for support tickets
+        request_id: Option<String>,
+    },
+
+    /// Resource already exists (409)
+    #[error("Conflict: {message}")]
+    Conflict {
+        message: String,
+        /// ID of the existing resource, when the API returns it
+        existing_id: Option<String>,
+        code: Option<String>,
+        /// Value of the `X-Request-Id` response header, for support tickets
+        request_id: Option<String>,
+    },
+
+    /// The resource changed since the `If-Match` ETag was read (412)
+    #[error("Precondition failed: {message}")]
+    PreconditionFailed {
+        message: String,
+        code: Option<String>,
+        /// Value of the `X-Request-Id` response header, for support tickets
+        request_id: Option<String>,
     },
 
     /// Validation error (422)
@@ -31,6 +82,8 @@ pub enum Error {
         message: String,
         errors: HashMap<String, Vec<String>>,
         code: Option<String>,
+        /// Value of the `X-Request-Id` response header, for support tickets
+        request_id: Option<String>,
     },
 
     /// Rate limit exceeded (429)
@@ -39,6 +92,8 @@ pub enum Error {
         message: String,
         retry_after: Option<u64>,
         code: Option<String>,
+        /// Value of the `X-Request-Id` response header, for support tickets
+        request_id: Option<String>,
     },
 
     /// Server error (5xx)
@@ -47,6 +102,40 @@ pub enum Error {
         message: String,
         status_code: u16,
         code: Option<String>,
+        /// Value of the `X-Request-Id` response header, for support tickets
+        request_id: Option<String>,
+    },
+
+    /// Request exceeds a client-side size cap and was not sent
+    #[error("Payload too large: {message}")]
+    PayloadTooLarge {
+        message: String,
+        size: usize,
+        limit: usize,
+    },
+
+    /// The client was restricted with `MailBreeze::restricted` and may not make this request
+    #[error("{method} {path} needs the {required} capability, which this client lacks")]
+    CapabilityDenied {
+        required: crate::capabilities::Capabilities,
+        method: String,
+        /// Path with email addresses redacted
+        path: String,
+    },
+
+    /// A webhook event was passed to an operation that cannot use it
+    #[error("Unsupported event {event_type}: {message}")]
+    UnsupportedEvent { event_type: String, message: String },
+
+    /// A polled job did not finish within `PollOptions::max_wait`
+    #[error(
+        "Verification {} still {} after {:?} ({}/{} processed)",
+        last.verification_id, last.status, waited, last.processed, last.total_emails
+    )]
+    PollTimeout {
+        waited: std::time::Duration,
+        /// The last status seen, to decide whether to keep waiting
+        last: Box<crate::types::BatchVerificationResult>,
     },
 
     /// HTTP client error
@@ -57,6 +146,25 @@ pub enum Error {
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 
+    /// A response body did not match the expected shape
+    #[error("Failed to decode response (status {status}): {source}")]
+    Decode {
+        /// The body as received, for reporting API mismatches
+        raw_body: String,
+        source: serde_json::Error,
+        status: u16,
+        /// Value of the `X-Request-Id` response header, for support tickets
+        request_id: Option<String>,
+    },
+
+    /// I/O error while writing a download
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Query string encoding error
+    #[error("Query encoding error: {0}")]
+    Query(String),
+
     /// Invalid header value
     #[error("Invalid header: {0}")]
     InvalidHeader(String),
@@ -64,6 +172,18 @@ pub enum Error {
     /// Request timeout
     #[error("Request timeout")]
     Timeout,
+
+    /// The request's cancellation token fired before it completed
+    #[error("Request cancelled")]
+    Cancelled,
+
+    /// Invalid request URL (usually a malformed base URL)
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(String),
+
+    /// Failure reported by a custom transport
+    #[error("Transport error: {message}")]
+    Transport { message: String, retryable: bool },
 }
 
 impl Error {
@@ -71,8 +191,12 @@ impl Error {
     pub fn code(&self) -> Option<&str> {
         match self {
             Error::Authentication { code, .. } => code.as_deref(),
+            Error::Forbidden { code, .. } => code.as_deref(),
             Error::BadRequest { code, .. } => code.as_deref(),
+            Error::RecipientSuppressed { code, .. } => code.as_deref(),
             Error::NotFound { code, .. } => code.as_deref(),
+            Error::Conflict { code, .. } => code.as_deref(),
+            Error::PreconditionFailed { code, .. } => code.as_deref(),
             Error::Validation { code, .. } => code.as_deref(),
             Error::RateLimit { code, .. } => code.as_deref(),
             Error::Server { code, .. } => code.as_deref(),
@@ -80,12 +204,33 @@ impl Error {
         }
     }
 
+    /// Returns the API request ID (`X-Request-Id`) if the error came from an API response
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Error::Authentication { request_id, .. }
+            | Error::Forbidden { request_id, .. }
+            | Error::BadRequest { request_id, .. }
+            | Error::RecipientSuppressed { request_id, .. }
+            | Error::NotFound { request_id, .. }
+            | Error::Conflict { request_id, .. }
+            | Error::PreconditionFailed { request_id, .. }
+            | Error::Validation { request_id, .. }
+            | Error::RateLimit { request_id, .. }
+            | Error::Server { request_id, .. }
+            | Error::Decode { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
     /// Returns the HTTP status code if applicable
     pub fn status_code(&self) -> Option<u16> {
         match self {
             Error::Authentication { .. } => Some(401),
-            Error::BadRequest { .. } => Some(400),
+            Error::Forbidden { .. } => Some(403),
+            Error::BadRequest { .. } | Error::RecipientSuppressed { .. } => Some(400),
             Error::NotFound { .. } => Some(404),
+            Error::Conflict { .. } => Some(409),
+            Error::PreconditionFailed { .. } => Some(412),
             Error::Validation { .. } => Some(422),
             Error::RateLimit { .. } => Some(429),
             Error::Server { status_code, .. } => Some(*status_code),
@@ -101,6 +246,22 @@ impl Error {
         }
     }
 
+    /// Returns the scope the API key is missing for forbidden errors
+    pub fn required_scope(&self) -> Option<&str> {
+        match self {
+            Error::Forbidden { required_scope, .. } => required_scope.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the ID of the already-existing resource for conflict errors
+    pub fn existing_id(&self) -> Option<&str> {
+        match self {
+            Error::Conflict { existing_id, .. } => existing_id.as_deref(),
+            _ => None,
+        }
+    }
+
     /// Returns validation errors if this is a validation error
     pub fn validation_errors(&self) -> Option<&HashMap<String, Vec<String>>> {
         match self {
@@ -109,6 +270,61 @@ impl Error {
         }
     }
 
+    /// Validation messages for one field, e.g. `to[0]`
+    ///
+    /// Paths are compared after parsing, so `to[0]` also matches errors the
+    /// API reported under `to.0`.
+    pub fn field_errors(&self, path: &str) -> Vec<&str> {
+        let path = FieldPath::parse(path);
+        self.nested_field_errors_matching(|field| *field == path)
+            .into_iter()
+            .map(|(_, message)| message)
+            .collect()
+    }
+
+    /// Validation messages for a field and everything below it, sorted by path
+    ///
+    /// `nested_field_errors("to")` returns errors for `to`, `to[0]`, `to[1]`, and so on.
+    pub fn nested_field_errors(&self, prefix: &str) -> Vec<(FieldPath, &str)> {
+        let prefix = FieldPath::parse(prefix);
+        self.nested_field_errors_matching(|field| field.starts_with(&prefix))
+    }
+
+    /// Message plus one line per field error, suitable for showing to end users
+    ///
+    /// Returns `None` for errors other than [`Error::Validation`].
+    pub fn validation_summary(&self) -> Option<String> {
+        let Error::Validation { message, .. } = self else {
+            return None;
+        };
+        let mut summary = message.clone();
+        for (path, error) in self.nested_field_errors_matching(|_| true) {
+            summary.push_str(&format!("\n- {}: {}", path, error));
+        }
+        Some(summary)
+    }
+
+    fn nested_field_errors_matching(
+        &self,
+        matches: impl Fn(&FieldPath) -> bool,
+    ) -> Vec<(FieldPath, &str)> {
+        let Some(errors) = self.validation_errors() else {
+            return Vec::new();
+        };
+        let mut found: Vec<(FieldPath, &str)> = errors
+            .iter()
+            .map(|(field, messages)| (FieldPath::parse(field), messages))
+            .filter(|(path, _)| matches(path))
+            .flat_map(|(path, messages)| {
+                messages
+                    .iter()
+                    .map(move |message| (path.clone(), message.as_str()))
+            })
+            .collect();
+        found.sort();
+        found
+    }
+
     /// Check if this error is retryable
     pub fn is_retryable(&self) -> bool {
         match self {
@@ -117,6 +333,7 @@ impl Error {
             }
             Error::Timeout => true,
             Error::Http(e) => e.is_connect() || e.is_timeout(),
+            Error::Transport { retryable, .. } => *retryable,
             _ => false,
         }
     }
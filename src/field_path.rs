@@ -0,0 +1,148 @@
+//! Paths to fields in validation errors, e.g. `to[0]` or `customFields.plan`
+
+use std::fmt;
+
+/// One step of a [`FieldPath`]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PathSegment {
+    Field(String),
+    Index(usize),
+}
+
+/// A parsed field path from [`Error::Validation`](crate::Error::Validation)
+///
+/// Dotted and bracketed spellings are equivalent: `to.0`, `to[0]` and
+/// `to["0"]` all parse to the same path, which displays as `to[0]`.
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::{FieldPath, PathSegment};
+///
+/// let path = FieldPath::parse("recipients[2].email");
+/// assert_eq!(path.segments()[1], PathSegment::Index(2));
+/// assert!(path.starts_with(&FieldPath::parse("recipients.2")));
+/// assert_eq!(path.to_string(), "recipients[2].email");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FieldPath(Vec<PathSegment>);
+
+impl FieldPath {
+    pub fn parse(path: &str) -> Self {
+        let mut segments = Vec::new();
+        let mut current = String::new();
+        let mut chars = path.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '.' => push_segment(&mut segments, &mut current),
+                '[' => {
+                    push_segment(&mut segments, &mut current);
+                    let inner: String = chars.by_ref().take_while(|&c| c != ']').collect();
+                    current = inner.trim_matches(|c| c == '"' || c == '\'').to_string();
+                    push_segment(&mut segments, &mut current);
+                }
+                _ => current.push(c),
+            }
+        }
+        push_segment(&mut segments, &mut current);
+        Self(segments)
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    /// Whether `prefix` is this path or one of its ancestors
+    pub fn starts_with(&self, prefix: &FieldPath) -> bool {
+        self.0.starts_with(&prefix.0)
+    }
+}
+
+fn push_segment(segments: &mut Vec<PathSegment>, current: &mut String) {
+    if current.is_empty() {
+        return;
+    }
+    let segment = std::mem::take(current);
+    segments.push(match segment.parse() {
+        Ok(index) => PathSegment::Index(index),
+        Err(_) => PathSegment::Field(segment),
+    });
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{}", name)?,
+                PathSegment::Field(name) => write!(f, ".{}", name)?,
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&str> for FieldPath {
+    fn from(path: &str) -> Self {
+        Self::parse(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+    use std::collections::HashMap;
+
+    fn validation_error() -> Error {
+        let errors = HashMap::from([
+            (
+                "to.0".to_string(),
+                vec!["must be a valid email address".to_string()],
+            ),
+            ("to[1]".to_string(), vec!["is required".to_string()]),
+            ("subject".to_string(), vec!["is too long".to_string()]),
+        ]);
+        Error::Validation {
+            message: "Invalid email".to_string(),
+            errors,
+            code: None,
+            request_id: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_equivalent_spellings() {
+        let expected = FieldPath(vec![
+            PathSegment::Field("customFields".to_string()),
+            PathSegment::Field("plan".to_string()),
+        ]);
+        assert_eq!(FieldPath::parse("customFields.plan"), expected);
+        assert_eq!(FieldPath::parse("customFields[\"plan\"]"), expected);
+        assert_eq!(FieldPath::parse("to.0").to_string(), "to[0]");
+        assert_eq!(FieldPath::parse("a[0][1].b").to_string(), "a[0][1].b");
+    }
+
+    #[test]
+    fn test_field_error_accessors() {
+        let error = validation_error();
+        assert_eq!(
+            error.field_errors("to[0]"),
+            ["must be a valid email address"]
+        );
+        assert_eq!(error.field_errors("to.1"), ["is required"]);
+        assert!(error.field_errors("from").is_empty());
+
+        let nested = error.nested_field_errors("to");
+        assert_eq!(nested.len(), 2);
+        assert_eq!(nested[0].0.to_string(), "to[0]");
+
+        assert_eq!(
+            error.validation_summary().unwrap(),
+            "Invalid email\n\
+             - subject: is too long\n\
+             - to[0]: must be a valid email address\n\
+             - to[1]: is required"
+        );
+        assert_eq!(Error::Timeout.validation_summary(), None);
+    }
+}
@@ -0,0 +1,351 @@
+//! Custom MIME headers for outgoing email
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Headers the API sets itself and drops from `headers` without an error
+///
+/// Most have a dedicated [`SendEmailParams`](crate::SendEmailParams) field instead.
+const FORBIDDEN: &[&str] = &[
+    "bcc",
+    "cc",
+    "content-transfer-encoding",
+    "content-type",
+    "date",
+    "dkim-signature",
+    "from",
+    "message-id",
+    "mime-version",
+    "received",
+    "reply-to",
+    "return-path",
+    "sender",
+    "subject",
+    "to",
+];
+
+/// Value of the `Auto-Submitted` header (RFC 3834)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AutoSubmitted {
+    /// Sent by a process without a human author, e.g. a receipt or alert
+    AutoGenerated,
+    /// An automatic reply to another message, e.g. an out-of-office notice
+    AutoReplied,
+    /// Written by a person
+    No,
+}
+
+impl fmt::Display for AutoSubmitted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            AutoSubmitted::AutoGenerated => "auto-generated",
+            AutoSubmitted::AutoReplied => "auto-replied",
+            AutoSubmitted::No => "no",
+        })
+    }
+}
+
+/// Custom headers for [`SendEmailParams::headers`](crate::SendEmailParams::headers)
+///
+/// Names are case-insensitive: setting `x-campaign` replaces `X-Campaign`.
+/// Headers the API controls (`From`, `Subject`, `Message-ID`, ...) are
+/// rejected by [`validate`](Self::validate), which every send runs before
+/// making a request, instead of being stripped by the server.
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::{AutoSubmitted, Headers};
+///
+/// let headers = Headers::new()
+///     .references(["<order-1042@shop.example>"])
+///     .auto_submitted(AutoSubmitted::AutoGenerated)
+///     .entity_ref_id("order-1042-shipped")
+///     .header("X-Campaign", "spring");
+/// assert!(headers.validate().is_ok());
+/// assert_eq!(headers.get("x-campaign"), Some("spring"));
+///
+/// assert!(Headers::new().header("Subject", "Hi").validate().is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Headers(BTreeMap<String, String>);
+
+impl Headers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set a header, replacing any existing value under the same name
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Set a header in place, replacing any existing value under the same name
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+        self.remove(&name);
+        self.0.insert(name, value.into());
+    }
+
+    /// `References`: message IDs of the thread this email belongs to, oldest first
+    ///
+    /// Angle brackets are added to IDs that lack them.
+    pub fn references<I, S>(self, message_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let value = message_ids
+            .into_iter()
+            .map(|id| bracketed(id.as_ref()))
+            .collect::<Vec<_>>()
+            .join(" ");
+        self.header("References", value)
+    }
+
+    /// `In-Reply-To`: the message ID this email answers
+    ///
+    /// Angle brackets are added when missing.
+    pub fn in_reply_to(self, message_id: impl AsRef<str>) -> Self {
+        let value = bracketed(message_id.as_ref());
+        self.header("In-Reply-To", value)
+    }
+
+    /// `Auto-Submitted`: mark the email as automated so auto-responders ignore it
+    pub fn auto_submitted(self, value: AutoSubmitted) -> Self {
+        self.header("Auto-Submitted", value.to_string())
+    }
+
+    /// `X-Entity-Ref-ID`: a unique value that stops Gmail threading unrelated emails
+    /// with the same subject
+    pub fn entity_ref_id(self, id: impl Into<String>) -> Self {
+        self.header("X-Entity-Ref-ID", id)
+    }
+
+    /// Value of the header `name`, compared case-insensitively
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Remove the header `name`, compared case-insensitively
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        let key = self
+            .0
+            .keys()
+            .find(|key| key.eq_ignore_ascii_case(name))?
+            .clone();
+        self.0.remove(&key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Reject headers the API would strip and names or values that are not valid MIME
+    ///
+    /// Returns [`Error::InvalidHeader`] naming the first offending header.
+    pub fn validate(&self) -> Result<()> {
+        for (name, value) in &self.0 {
+            if FORBIDDEN.iter().any(|f| f.eq_ignore_ascii_case(name)) {
+                return Err(Error::InvalidHeader(format!(
+                    "{} is set by MailBreeze and cannot be overridden",
+                    name
+                )));
+            }
+            let valid_name =
+                !name.is_empty() && name.bytes().all(|b| b.is_ascii_graphic() && b != b':');
+            if !valid_name {
+                return Err(Error::InvalidHeader(format!(
+                    "{:?} is not a valid header name",
+                    name
+                )));
+            }
+            if value.contains(['\r', '\n']) {
+                return Err(Error::InvalidHeader(format!(
+                    "{} value contains a line break",
+                    name
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A message ID in `<...>` form
+fn bracketed(message_id: &str) -> String {
+    let message_id = message_id.trim();
+    if message_id.starts_with('<') {
+        message_id.to_string()
+    } else {
+        format!("<{}>", message_id)
+    }
+}
+
+impl<K: Into<String>, V: Into<String>> FromIterator<(K, V)> for Headers {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut headers = Self::new();
+        for (name, value) in iter {
+            headers.insert(name, value);
+        }
+        headers
+    }
+}
+
+impl From<std::collections::HashMap<String, String>> for Headers {
+    fn from(map: std::collections::HashMap<String, String>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+/// How recipients unsubscribe, sent as `List-Unsubscribe` headers
+///
+/// Set on [`SendEmailParams::unsubscribe`](crate::SendEmailParams::unsubscribe);
+/// the SDK writes `List-Unsubscribe` and, for one-click, the RFC 8058
+/// `List-Unsubscribe-Post` header, replacing any hand-written ones. Gmail and
+/// Yahoo require one-click unsubscribe from bulk senders.
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::UnsubscribeConfig;
+///
+/// let unsubscribe = UnsubscribeConfig::url("https://news.example.com/unsubscribe?u=42")
+///     .mailto("unsubscribe@news.example.com")
+///     .one_click(true);
+/// let headers = unsubscribe.to_headers();
+/// assert_eq!(
+///     headers.get("List-Unsubscribe"),
+///     Some("<mailto:unsubscribe@news.example.com>, <https://news.example.com/unsubscribe?u=42>")
+/// );
+/// assert_eq!(headers.get("List-Unsubscribe-Post"), Some("List-Unsubscribe=One-Click"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsubscribeConfig {
+    /// Address that unsubscribes the sender of any email it receives
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mailto: Option<String>,
+    /// HTTPS endpoint that unsubscribes the recipient
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Whether `url` accepts an RFC 8058 one-click `POST`
+    #[serde(default)]
+    pub one_click: bool,
+}
+
+impl UnsubscribeConfig {
+    /// Unsubscribe through `url`
+    pub fn url(url: impl Into<String>) -> Self {
+        Self {
+            url: Some(url.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Also, or only, unsubscribe by email to `address`
+    pub fn mailto(mut self, address: impl Into<String>) -> Self {
+        self.mailto = Some(address.into());
+        self
+    }
+
+    /// Advertise that `url` handles one-click unsubscribe
+    pub fn one_click(mut self, one_click: bool) -> Self {
+        self.one_click = one_click;
+        self
+    }
+
+    /// Problems that would make mailbox providers ignore the headers
+    pub(crate) fn problems(&self) -> Vec<&'static str> {
+        let mut problems = Vec::new();
+        if self.mailto.is_none() && self.url.is_none() {
+            problems.push("needs a mailto address or a url");
+        }
+        if self.one_click
+            && !self
+                .url
+                .as_deref()
+                .is_some_and(|u| u.starts_with("https://"))
+        {
+            problems.push("oneClick requires an https url");
+        }
+        problems
+    }
+
+    /// The `List-Unsubscribe` headers this configuration produces
+    pub fn to_headers(&self) -> Headers {
+        let targets: Vec<String> = self
+            .mailto
+            .iter()
+            .map(|address| format!("<mailto:{}>", address))
+            .chain(self.url.iter().map(|url| format!("<{}>", url)))
+            .collect();
+        let mut headers = Headers::new().header("List-Unsubscribe", targets.join(", "));
+        if self.one_click {
+            headers.insert("List-Unsubscribe-Post", "List-Unsubscribe=One-Click");
+        }
+        headers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_are_case_insensitive() {
+        let mut headers = Headers::new().header("X-Campaign", "spring");
+        headers.insert("x-campaign", "summer");
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers.get("X-CAMPAIGN"), Some("summer"));
+        assert_eq!(headers.remove("X-Campaign").as_deref(), Some("summer"));
+        assert!(headers.is_empty());
+    }
+
+    #[test]
+    fn test_typed_setters_serialize_as_a_map() {
+        let headers = Headers::new()
+            .references(["a@x.example", "<b@x.example>"])
+            .auto_submitted(AutoSubmitted::AutoReplied)
+            .entity_ref_id("ref-1");
+        assert_eq!(
+            serde_json::to_value(&headers).unwrap(),
+            serde_json::json!({
+                "Auto-Submitted": "auto-replied",
+                "References": "<a@x.example> <b@x.example>",
+                "X-Entity-Ref-ID": "ref-1"
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_forbidden_and_malformed_headers() {
+        for (name, value) in [
+            ("message-id", "<x@y>"),
+            ("Bcc", "spy@example.com"),
+            ("X Bad", "v"),
+            ("X-Injected", "a\r\nBcc: spy@example.com"),
+        ] {
+            let err = Headers::new().header(name, value).validate().unwrap_err();
+            assert!(matches!(err, Error::InvalidHeader(_)), "{}", name);
+        }
+        assert!(Headers::new()
+            .header("List-Id", "<news.example.com>")
+            .validate()
+            .is_ok());
+    }
+}
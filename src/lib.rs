@@ -30,16 +30,93 @@
 //! }
 //! ```
 
+mod address;
+mod auth;
+mod cache;
+mod capabilities;
 mod client;
+mod deprecation;
+mod diagnostics;
+mod dispatcher;
+mod download;
 mod error;
+mod field_path;
+mod headers;
+mod list_directory;
+mod mjml;
+mod observer;
+mod options;
+mod paginator;
+mod preheader;
+mod progress;
+mod query;
 mod resources;
+mod response;
+mod retry;
+mod schema;
+mod secret;
+mod signing;
+mod telemetry;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod throttle;
+mod transform;
+mod transport;
 mod types;
+mod typo;
+mod webhook_router;
+mod webhooks;
 
+pub use auth::{AuthProvider, Credential, OAuth2ClientCredentials};
+pub use capabilities::Capabilities;
 pub use client::{ClientConfig, HttpClient};
+pub use deprecation::{DeprecationHandler, DeprecationNotice};
+pub use diagnostics::{Diagnostics, EndpointDiagnostics};
+pub use dispatcher::{
+    CampaignDispatcher, CampaignFailure, CampaignReport, Checkpoint, CheckpointStore,
+    MemoryCheckpointStore,
+};
 pub use error::{Error, Result};
-pub use resources::{Attachments, Contacts, Emails, Lists, Verification};
+pub use field_path::{FieldPath, PathSegment};
+pub use headers::{AutoSubmitted, Headers, UnsubscribeConfig};
+pub use observer::ContactObserver;
+pub use options::{PollOptions, RequestOptions};
+pub use paginator::{Page, Paginator};
+pub use progress::{LogProgress, NoProgress, ProgressReporter};
+pub use resources::{
+    Account, Alerts, Attachments, Automations, Branding, Contacts, Emails, Lists, ReplyRoutes,
+    Suppressions, Templates, Verification, Webhooks,
+};
+pub use response::{RateLimitInfo, Response, ResponseMeta};
+pub use retry::{RetryClassifier, RetryPolicy};
+pub use schema::{SchemaDrift, SchemaDriftHandler, SCHEMA_VERSION};
+pub use secret::SecretString;
+pub use signing::{HmacSigner, RequestSigner};
+pub use transform::{FieldCase, ResponseTransform};
+pub use transport::{
+    ReqwestTransport, StreamingResponse, Transport, TransportRequest, TransportResponse,
+};
 pub use types::*;
+pub use webhook_router::{DedupeStore, Dispatch, HandlerError, MemoryDedupeStore, WebhookRouter};
+pub use webhooks::{
+    ApiKeyCreatedEventData, BounceEventData, CreditLowEventData, DomainVerificationEventData,
+    EmailEventData, ReplyEventData, WebhookEvent, WebhookPayload,
+};
 
+/// Re-exported so custom [`Transport`] implementations don't need their own dependency
+pub use async_trait::async_trait;
+
+/// Re-exported for [`ClientConfig::root_certificates`] and [`ClientConfig::client_identity`]
+pub use reqwest::{Certificate, Identity};
+
+/// Re-exported for [`RetryClassifier`] implementations
+pub use reqwest::Method;
+
+/// Re-exported for [`RequestOptions::cancellation`]
+pub use tokio_util::sync::CancellationToken;
+
+use list_directory::ListDirectory;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Main MailBreeze client
@@ -53,8 +130,26 @@ pub struct MailBreeze {
     pub verification: Verification,
     /// Attachments API resource
     pub attachments: Attachments,
+    /// Automations API resource
+    pub automations: Automations,
+    /// Templates API resource
+    pub templates: Templates,
+    /// Account API resource
+    pub account: Account,
+    /// Webhook endpoints API resource
+    pub webhooks: Webhooks,
+    /// Account-wide suppression list API resource
+    pub suppressions: Suppressions,
+    /// Compliance footer branding API resource
+    pub branding: Branding,
+    /// Reply routing rules API resource
+    pub reply_routes: ReplyRoutes,
+    /// Deliverability alerts API resource
+    pub alerts: Alerts,
     /// HTTP client for creating list-scoped resources
     http_client: HttpClient,
+    /// List names cached by `preload_lists`
+    list_directory: Arc<ListDirectory>,
 }
 
 impl MailBreeze {
@@ -65,15 +160,67 @@ impl MailBreeze {
 
     /// Create a new MailBreeze client with custom configuration
     pub fn with_config(config: ClientConfig) -> Result<Self> {
-        let http_client = HttpClient::new(config)?;
+        Ok(Self::from_http_client(HttpClient::new(config)?))
+    }
 
-        Ok(Self {
+    fn from_http_client(http_client: HttpClient) -> Self {
+        Self {
             emails: Emails::new(http_client.clone()),
             lists: Lists::new(http_client.clone()),
             verification: Verification::new(http_client.clone()),
             attachments: Attachments::new(http_client.clone()),
+            automations: Automations::new(http_client.clone()),
+            templates: Templates::new(http_client.clone()),
+            account: Account::new(http_client.clone()),
+            webhooks: Webhooks::new(http_client.clone()),
+            suppressions: Suppressions::new(http_client.clone()),
+            branding: Branding::new(http_client.clone()),
+            reply_routes: ReplyRoutes::new(http_client.clone()),
+            alerts: Alerts::new(http_client.clone()),
             http_client,
-        })
+            list_directory: Arc::default(),
+        }
+    }
+
+    /// Derive a client that sends with a different API key, e.g. another workspace
+    ///
+    /// The connection pool and all other configuration are shared with this client;
+    /// rate-limit and response metadata are tracked separately per key.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// // One client per tenant, all reusing the same connections
+    /// let tenant = client.with_api_key("tenant_api_key");
+    /// tenant.emails.send(&Default::default()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_api_key(&self, api_key: impl Into<String>) -> Self {
+        Self::from_http_client(self.http_client.with_api_key(api_key))
+    }
+
+    /// Derive a client that can only perform the operations in `capabilities`
+    ///
+    /// Hand the result to less-trusted code, such as plugins. Requests outside
+    /// the set fail with [`Error::CapabilityDenied`] before anything is sent,
+    /// and clients derived from it (including via `with_api_key`) stay restricted.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use mailbreeze::{Capabilities, Error, MailBreeze};
+    ///
+    /// # async fn run(client: MailBreeze) -> mailbreeze::Result<()> {
+    /// let plugin_client = client.restricted(Capabilities::SEND_ONLY);
+    /// plugin_client.emails.send(&Default::default()).await?;
+    ///
+    /// let denied = plugin_client.webhooks.delete("wh_123").await;
+    /// assert!(matches!(denied, Err(Error::CapabilityDenied { .. })));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn restricted(&self, capabilities: Capabilities) -> Self {
+        Self::from_http_client(self.http_client.restricted(capabilities))
     }
 
     /// Get a contacts resource for a specific list
@@ -110,6 +257,105 @@ impl MailBreeze {
         Contacts::new(self.http_client.clone(), list_id)
     }
 
+    /// Cache list names so lists can be referenced with [`MailBreeze::contacts_by_name`]
+    ///
+    /// With `refresh_every`, names are reloaded in the background for as long as
+    /// this client (or a clone of it) is alive. Returns the number of names cached.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    ///
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// client.preload_lists(Some(Duration::from_secs(300))).await?;
+    ///
+    /// let newsletter = client.contacts_by_name("Newsletter").await?;
+    /// newsletter.list(&Default::default()).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn preload_lists(&self, refresh_every: Option<Duration>) -> Result<usize> {
+        let count = self.list_directory.load(&self.lists).await?;
+        if let Some(interval) = refresh_every {
+            self.list_directory
+                .refresh_every(self.lists.clone(), interval);
+        }
+        Ok(count)
+    }
+
+    /// Get a contacts resource for the list with this name
+    ///
+    /// Names come from [`MailBreeze::preload_lists`]. If they haven't been
+    /// loaded, or `name` is unknown, the lists are fetched once more before
+    /// failing with [`Error::NotFound`].
+    pub async fn contacts_by_name(&self, name: &str) -> Result<Contacts> {
+        let list_id = match self.list_directory.lookup(name) {
+            Some(id) => Some(id),
+            None => {
+                self.list_directory.load(&self.lists).await?;
+                self.list_directory.lookup(name)
+            }
+        };
+        match list_id {
+            Some(id) => Ok(self.contacts(id)),
+            None => Err(Error::NotFound {
+                message: format!("no contact list named {:?}", name),
+                code: None,
+                request_id: None,
+            }),
+        }
+    }
+
+    /// Describe the API key in use: account, scopes, rate limits and sandbox status
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// // Refuse to start with an over-privileged key
+    /// let key = client.whoami().await?;
+    /// assert_eq!(key.scopes, vec!["emails:send".to_string()]);
+    /// assert!(!key.sandbox);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn whoami(&self) -> Result<KeyInfo> {
+        self.account.whoami().await
+    }
+
+    /// Latency percentiles and retry ratios per endpoint, if diagnostics are enabled
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use mailbreeze::MailBreeze;
+    ///
+    /// # fn main() -> mailbreeze::Result<()> {
+    /// let client = MailBreeze::builder("your_api_key").diagnostics(true).build()?;
+    ///
+    /// if let Some(diagnostics) = client.diagnostics() {
+    ///     for endpoint in &diagnostics.endpoints {
+    ///         println!(
+    ///             "{}: p50={:?} p99={:?} retries/request={:.2}",
+    ///             endpoint.endpoint, endpoint.p50, endpoint.p99, endpoint.retry_ratio()
+    ///         );
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn diagnostics(&self) -> Option<Diagnostics> {
+        self.http_client.diagnostics()
+    }
+
+    /// Status and `X-Request-Id` of the most recent API response
+    pub fn last_response(&self) -> Option<ResponseMeta> {
+        self.http_client.last_response()
+    }
+
+    /// Rate-limit state from the most recent response's `X-RateLimit-*` headers
+    pub fn last_rate_limit(&self) -> Option<RateLimitInfo> {
+        self.http_client.last_rate_limit()
+    }
+
     /// Create a builder for configuring the client
     pub fn builder(api_key: impl Into<String>) -> MailBreezeBuilder {
         MailBreezeBuilder::new(api_key)
@@ -135,6 +381,15 @@ impl MailBreezeBuilder {
         self
     }
 
+    /// Fail over to a secondary endpoint, e.g. another region, when the primary is unreachable
+    ///
+    /// After repeated connection failures within a request, its remaining
+    /// retries go to this base URL. Each new request starts on the primary again.
+    pub fn fallback_base_url(mut self, url: impl Into<String>) -> Self {
+        self.config = self.config.fallback_base_url(url);
+        self
+    }
+
     /// Set the request timeout
     pub fn timeout(mut self, timeout: Duration) -> Self {
         self.config = self.config.timeout(timeout);
@@ -147,6 +402,238 @@ impl MailBreezeBuilder {
         self
     }
 
+    /// Set the backoff strategy used between retries
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.config = self.config.retry_policy(policy);
+        self
+    }
+
+    /// Decide which failures are retried instead of the built-in status list
+    pub fn retry_classifier(mut self, classifier: impl RetryClassifier + 'static) -> Self {
+        self.config = self.config.retry_classifier(classifier);
+        self
+    }
+
+    /// Automatically retry rate-limited (429) requests after the `Retry-After` delay
+    pub fn retry_rate_limits(mut self, enabled: bool) -> Self {
+        self.config = self.config.retry_rate_limits(enabled);
+        self
+    }
+
+    /// Set the longest `Retry-After` delay that will be waited out automatically
+    pub fn max_retry_after(mut self, max: Duration) -> Self {
+        self.config = self.config.max_retry_after(max);
+        self
+    }
+
+    /// Cap the total time a request may take across all attempts and backoff sleeps
+    ///
+    /// Retries that would end after the budget are skipped, and an in-flight
+    /// attempt is cut short with [`Error::Timeout`] when it runs out.
+    pub fn retry_deadline(mut self, budget: Duration) -> Self {
+        self.config = self.config.retry_deadline(budget);
+        self
+    }
+
+    /// Record per-endpoint latency and retry statistics, retrievable via [`MailBreeze::diagnostics`]
+    pub fn diagnostics(mut self, enabled: bool) -> Self {
+        self.config = self.config.diagnostics(enabled);
+        self
+    }
+
+    /// Log a summary of each request (method, path, status, duration, attempts) at debug level
+    ///
+    /// The API key, query strings, bodies and email addresses in paths are never logged.
+    pub fn debug_logging(mut self, enabled: bool) -> Self {
+        self.config = self.config.debug_logging(enabled);
+        self
+    }
+
+    /// Accept gzip/brotli-encoded responses (enabled by default)
+    pub fn decompress_responses(mut self, enabled: bool) -> Self {
+        self.config = self.config.decompress_responses(enabled);
+        self
+    }
+
+    /// Gzip request bodies larger than `bytes`, e.g. big batch verification payloads
+    pub fn compress_requests_over(mut self, bytes: usize) -> Self {
+        self.config = self.config.compress_requests_over(bytes);
+        self
+    }
+
+    /// Limit how many requests are in flight at once; the rest queue fairly
+    ///
+    /// Useful when firing thousands of calls in parallel, so they share a
+    /// bounded number of sockets instead of exhausting them.
+    pub fn max_concurrent_requests(mut self, requests: usize) -> Self {
+        self.config = self.config.max_concurrent_requests(requests);
+        self
+    }
+
+    /// Fire a second copy of a GET still unanswered after `threshold`, taking whichever returns first
+    ///
+    /// Trims tail latency on reads in synchronous request paths at the cost
+    /// of occasional duplicate GETs. Writes are never hedged.
+    pub fn hedge_after(mut self, threshold: Duration) -> Self {
+        self.config = self.config.hedge_after(threshold);
+        self
+    }
+
+    /// Cache up to `entries` GET responses that carry an `ETag`, revalidating with `If-None-Match`
+    ///
+    /// Saves bandwidth on frequently polled endpoints such as `lists.get` or
+    /// `emails.stats`: unchanged resources come back as a bodiless `304`.
+    pub fn etag_cache(mut self, entries: usize) -> Self {
+        self.config = self.config.etag_cache(entries);
+        self
+    }
+
+    /// Reject request bodies larger than `bytes` without sending them
+    pub fn max_body_size(mut self, bytes: usize) -> Self {
+        self.config = self.config.max_body_size(bytes);
+        self
+    }
+
+    /// Reject sends with more than `recipients` to/cc/bcc addresses without sending them
+    pub fn max_recipients(mut self, recipients: usize) -> Self {
+        self.config = self.config.max_recipients(recipients);
+        self
+    }
+
+    /// Route every send through the sandbox, for staging environments
+    ///
+    /// Sends run the full request path and return real message IDs but are
+    /// never delivered. Sets `test_mode` on sends that leave it unset.
+    pub fn sandbox(mut self, sandbox: bool) -> Self {
+        self.config = self.config.sandbox(sandbox);
+        self
+    }
+
+    /// Space out sends so each `from` domain sends at most `per_minute` messages a minute
+    ///
+    /// Sends over the rate wait their turn rather than fail, which smooths
+    /// bursts that receiving ISPs would otherwise defer.
+    pub fn send_rate_per_domain(mut self, per_minute: u32) -> Self {
+        self.config = self.config.send_rate_per_domain(per_minute);
+        self
+    }
+
+    /// Cap sends from `domain` at `per_minute`, overriding `send_rate_per_domain`
+    pub fn domain_send_rate(mut self, domain: impl Into<String>, per_minute: u32) -> Self {
+        self.config = self.config.domain_send_rate(domain, per_minute);
+        self
+    }
+
+    /// Identify your application in the User-Agent, e.g. `mailbreeze-rust/0.2.5 billing/1.4.2`
+    pub fn app_info(mut self, name: impl Into<String>, version: impl Into<String>) -> Self {
+        self.config = self.config.app_info(name, version);
+        self
+    }
+
+    /// Pin responses to a dated API version, e.g. `2024-06-01`
+    ///
+    /// Sent as the `MailBreeze-Version` header, so response shapes stay the
+    /// same until you change it. Without it the account's default version is used.
+    pub fn api_version(mut self, version: impl Into<String>) -> Self {
+        self.config = self.config.api_version(version);
+        self
+    }
+
+    /// Add a header to every request, e.g. a tenant ID or routing hint
+    ///
+    /// Per-request headers from [`RequestOptions`] with the same name take precedence.
+    /// `X-API-Key` and `Authorization` always come from the configured
+    /// credential, and values are left out of `Debug` output.
+    pub fn default_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.config = self.config.default_header(name, value);
+        self
+    }
+
+    /// Field naming used by the API's responses, for self-hosted compatible gateways
+    pub fn response_case(mut self, case: FieldCase) -> Self {
+        self.config = self.config.response_case(case);
+        self
+    }
+
+    /// Rewrite every JSON response body before it is decoded
+    ///
+    /// Runs after the [`FieldCase`] conversion, so it can patch up fields the
+    /// conversion does not cover.
+    pub fn transform_response(
+        mut self,
+        transform: impl Fn(&mut serde_json::Value) + Send + Sync + 'static,
+    ) -> Self {
+        self.config = self.config.transform_response(transform);
+        self
+    }
+
+    /// Handle `Deprecation`/`Sunset` notices from the API (logged as warnings by default)
+    pub fn on_deprecation(
+        mut self,
+        handler: impl Fn(&DeprecationNotice) + Send + Sync + 'static,
+    ) -> Self {
+        self.config = self.config.on_deprecation(handler);
+        self
+    }
+
+    /// Handle responses whose schema is newer than this SDK (logged as a warning by default)
+    pub fn on_schema_drift(
+        mut self,
+        handler: impl Fn(&SchemaDrift) + Send + Sync + 'static,
+    ) -> Self {
+        self.config = self.config.on_schema_drift(handler);
+        self
+    }
+
+    /// Authenticate with a custom credential source instead of the API key
+    pub fn auth(mut self, provider: impl AuthProvider + 'static) -> Self {
+        self.config = self.config.auth(provider);
+        self
+    }
+
+    /// Notify `observer` after contacts are created, updated, deleted, restored or suppressed
+    pub fn contact_observer(mut self, observer: impl ContactObserver + 'static) -> Self {
+        self.config = self.config.contact_observer(observer);
+        self
+    }
+
+    /// Trust an additional root certificate, e.g. from a TLS-inspecting egress proxy
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # fn main() -> mailbreeze::Result<()> {
+    /// let pem = std::fs::read("corp-proxy-ca.pem")?;
+    /// let client = mailbreeze::MailBreeze::builder("your_api_key")
+    ///     .add_root_certificate(mailbreeze::Certificate::from_pem(&pem)?)
+    ///     .build()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.config = self.config.add_root_certificate(certificate);
+        self
+    }
+
+    /// Present a client certificate for mutual TLS, e.g. to a private gateway
+    ///
+    /// Build the identity with `Identity::from_pkcs12_der` or `Identity::from_pkcs8_pem`.
+    pub fn client_identity(mut self, identity: Identity) -> Self {
+        self.config = self.config.client_identity(identity);
+        self
+    }
+
+    /// Use a custom HTTP transport instead of the default reqwest client
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.config = self.config.transport(transport);
+        self
+    }
+
+    /// Sign every request, e.g. with an [`HmacSigner`]
+    pub fn signer(mut self, signer: impl RequestSigner + 'static) -> Self {
+        self.config = self.config.signer(signer);
+        self
+    }
+
     /// Build the MailBreeze client
     pub fn build(self) -> Result<MailBreeze> {
         MailBreeze::with_config(self.config)
@@ -235,4 +722,88 @@ mod tests {
         let result = contacts.list(&ListContactsParams::default()).await.unwrap();
         assert_eq!(result.contacts.len(), 1);
     }
+
+    #[tokio::test]
+    async fn test_contacts_by_name() {
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/contact-lists"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": [
+                    {"id": "list_1", "name": "Newsletter"},
+                    {"id": "list_2", "name": "Customers"}
+                ]
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/contact-lists/list_1/contacts"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "contacts": [],
+                    "pagination": {"page": 1, "limit": 10, "total": 0, "totalPages": 0}
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let client = MailBreeze::builder("test_api_key")
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+        assert_eq!(client.preload_lists(None).await.unwrap(), 2);
+
+        let newsletter = client.contacts_by_name("Newsletter").await.unwrap();
+        newsletter.list(&Default::default()).await.unwrap();
+
+        // Unknown names trigger one more fetch before failing
+        let error = client.contacts_by_name("Archive").await.unwrap_err();
+        assert!(matches!(error, Error::NotFound { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_preload_lists_reads_every_page() {
+        use wiremock::matchers::query_param;
+
+        let mock_server = MockServer::start().await;
+
+        let first_page: Vec<_> = (0..50)
+            .map(
+                |n| serde_json::json!({"id": format!("list_{}", n), "name": format!("List {}", n)}),
+            )
+            .collect();
+        Mock::given(method("GET"))
+            .and(path("/api/v1/contact-lists"))
+            .and(query_param("page", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": first_page
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/contact-lists"))
+            .and(query_param("page", "2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": [{"id": "list_archive", "name": "Archive"}]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let client = MailBreeze::builder("test_api_key")
+            .base_url(mock_server.uri())
+            .build()
+            .unwrap();
+        assert_eq!(client.preload_lists(None).await.unwrap(), 51);
+        assert_eq!(
+            client.list_directory.lookup("Archive").as_deref(),
+            Some("list_archive")
+        );
+    }
 }
@@ -0,0 +1,82 @@
+//! Name-to-ID lookup for contact lists, backing `MailBreeze::contacts_by_name`
+
+use crate::error::Result;
+use crate::resources::Lists;
+use crate::types::ListListsParams;
+use futures_util::TryStreamExt;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, RwLock, Weak};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// Cached list IDs keyed by list name
+#[derive(Debug, Default)]
+pub(crate) struct ListDirectory {
+    ids: RwLock<HashMap<String, String>>,
+    refresher: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl ListDirectory {
+    /// Fetch every page of lists and replace the cached names
+    ///
+    /// If two lists share a name, the first one returned by the API wins.
+    pub(crate) async fn load(&self, lists: &Lists) -> Result<usize> {
+        let mut pages = lists.list_all(&ListListsParams::default());
+        let mut ids = HashMap::new();
+        let mut seen = HashSet::new();
+        while let Some(list) = pages.try_next().await? {
+            // A server that ignores `page` repeats the first page; stop there
+            if !seen.insert(list.id.clone()) {
+                break;
+            }
+            ids.entry(list.name).or_insert(list.id);
+        }
+        let count = ids.len();
+        *self.ids.write().unwrap_or_else(|e| e.into_inner()) = ids;
+        Ok(count)
+    }
+
+    pub(crate) fn lookup(&self, name: &str) -> Option<String> {
+        self.ids
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(name)
+            .cloned()
+    }
+
+    /// Reload in the background every `interval`, replacing any earlier schedule
+    ///
+    /// The task stops once the directory is dropped. Failed refreshes are
+    /// logged and the previous names are kept.
+    pub(crate) fn refresh_every(self: &Arc<Self>, lists: Lists, interval: Duration) {
+        let directory: Weak<Self> = Arc::downgrade(self);
+        let task = tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                let Some(directory) = directory.upgrade() else {
+                    return;
+                };
+                if let Err(e) = directory.load(&lists).await {
+                    log::warn!(target: "mailbreeze", "list name refresh failed: {}", e);
+                }
+            }
+        });
+        let previous = self
+            .refresher
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .replace(task);
+        if let Some(previous) = previous {
+            previous.abort();
+        }
+    }
+}
+
+impl Drop for ListDirectory {
+    fn drop(&mut self) {
+        let refresher = self.refresher.get_mut().unwrap_or_else(|e| e.into_inner());
+        if let Some(task) = refresher.take() {
+            task.abort();
+        }
+    }
+}
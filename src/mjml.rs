@@ -0,0 +1,49 @@
+//! Client-side MJML compilation; markup is refused without the `mjml` feature
+
+use crate::error::{Error, Result};
+use std::collections::HashMap;
+
+/// Compile MJML markup to responsive HTML
+///
+/// Markup that fails to parse or render is reported as an
+/// [`Error::Validation`] on the `mjml` field, without a request.
+#[cfg(feature = "mjml")]
+pub(crate) fn compile(markup: &str) -> Result<String> {
+    let parsed = mrml::parse(markup).map_err(|e| invalid(e.to_string()))?;
+    parsed
+        .element
+        .render(&mrml::prelude::render::RenderOptions::default())
+        .map_err(|e| invalid(e.to_string()))
+}
+
+/// Without the `mjml` feature, every markup is refused
+#[cfg(not(feature = "mjml"))]
+pub(crate) fn compile(_markup: &str) -> Result<String> {
+    Err(invalid("requires the `mjml` feature".to_string()))
+}
+
+/// [`Error::Validation`] on the `mjml` field
+fn invalid(message: String) -> Error {
+    Error::Validation {
+        message: format!("invalid mjml: {}", message),
+        errors: HashMap::from([("mjml".to_string(), vec![message])]),
+        code: None,
+        request_id: None,
+    }
+}
+
+#[cfg(all(test, feature = "mjml"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compile() {
+        let html = compile("<mjml><mj-body><mj-text>Your order shipped</mj-text></mj-body></mjml>")
+            .unwrap();
+        assert!(html.starts_with("<!doctype html>"));
+        assert!(html.contains("Your order shipped"));
+
+        let error = compile("<mjml><mj-body>").unwrap_err();
+        assert_eq!(error.field_errors("mjml").len(), 1);
+    }
+}
@@ -0,0 +1,44 @@
+//! Hooks for keeping application state in sync with contact mutations
+
+use crate::types::{Contact, SuppressReason};
+
+/// Notified by [`Contacts`](crate::Contacts) after each successful mutation
+///
+/// All methods default to no-ops; implement the ones you need. Hooks run
+/// inline on the calling task, so hand slow work (e.g. reindexing) off to a
+/// queue or spawned task.
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::{Contact, ContactObserver, MailBreeze};
+///
+/// #[derive(Debug)]
+/// struct SearchIndex;
+///
+/// impl ContactObserver for SearchIndex {
+///     fn on_contact_created(&self, list_id: &str, contact: &Contact) {
+///         println!("index {} in {}", contact.email, list_id);
+///     }
+///     fn on_contact_deleted(&self, list_id: &str, contact_id: &str) {
+///         println!("drop {} from {}", contact_id, list_id);
+///     }
+/// }
+///
+/// # fn main() -> mailbreeze::Result<()> {
+/// let client = MailBreeze::builder("your_api_key")
+///     .contact_observer(SearchIndex)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait ContactObserver: std::fmt::Debug + Send + Sync {
+    fn on_contact_created(&self, _list_id: &str, _contact: &Contact) {}
+
+    fn on_contact_updated(&self, _list_id: &str, _contact: &Contact) {}
+
+    fn on_contact_deleted(&self, _list_id: &str, _contact_id: &str) {}
+
+    fn on_contact_restored(&self, _list_id: &str, _contact: &Contact) {}
+
+    fn on_contact_suppressed(&self, _list_id: &str, _contact_id: &str, _reason: &SuppressReason) {}
+}
@@ -0,0 +1,188 @@
+use crate::retry::RetryPolicy;
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+/// Per-request overrides layered on top of the client configuration
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::{MailBreeze, RequestOptions, SendEmailParams};
+///
+/// #[tokio::main]
+/// async fn main() -> mailbreeze::Result<()> {
+///     let client = MailBreeze::new("your_api_key")?;
+///
+///     // Retrying with the same key never produces a duplicate send
+///     let options = RequestOptions::new().idempotency_key("order-1234-receipt");
+///     let result = client.emails.send_with_options(&SendEmailParams {
+///         from: "sender@yourdomain.com".to_string(),
+///         to: vec!["customer@example.com".to_string()],
+///         subject: Some("Your receipt".to_string()),
+///         ..Default::default()
+///     }, &options).await?;
+///
+///     // Apply options to every call made through a resource handle
+///     let slow = client.verification.with_options(
+///         RequestOptions::new().timeout(std::time::Duration::from_secs(300)),
+///     );
+///     let batch = slow.batch(vec!["a@example.com".to_string()]).await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    /// Sent as the `Idempotency-Key` header so the API can deduplicate retried requests
+    pub idempotency_key: Option<String>,
+    /// Timeout for each attempt, overriding `ClientConfig.timeout`
+    pub timeout: Option<Duration>,
+    /// Sent as `If-Match`: only apply the change if the resource still has this ETag
+    pub if_match: Option<String>,
+    /// Extra headers added to the request
+    pub headers: Vec<(String, String)>,
+    /// Raw query pairs appended after the typed params, e.g. `("tags[]", "a")`
+    pub query: Vec<(String, String)>,
+    /// Abort the request, including any pending retries, once this token is cancelled
+    pub cancellation: Option<CancellationToken>,
+}
+
+impl RequestOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Some(key.into());
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    pub fn if_match(mut self, etag: impl Into<String>) -> Self {
+        self.if_match = Some(etag.into());
+        self
+    }
+
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Append a raw query pair; repeat the name to send multiple values
+    pub fn query(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.query.push((name.into(), value.into()));
+        self
+    }
+
+    /// Abort the request with [`Error::Cancelled`](crate::Error::Cancelled) when `token` is cancelled
+    ///
+    /// Cancellation takes effect immediately, even mid-attempt or during a backoff sleep.
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Layer `overrides` on top of these options
+    ///
+    /// Scalar settings from `overrides` win; headers and query pairs from both are kept.
+    pub(crate) fn merge(&self, overrides: &RequestOptions) -> RequestOptions {
+        RequestOptions {
+            idempotency_key: overrides
+                .idempotency_key
+                .clone()
+                .or_else(|| self.idempotency_key.clone()),
+            timeout: overrides.timeout.or(self.timeout),
+            if_match: overrides.if_match.clone().or_else(|| self.if_match.clone()),
+            headers: self
+                .headers
+                .iter()
+                .chain(&overrides.headers)
+                .cloned()
+                .collect(),
+            query: self.query.iter().chain(&overrides.query).cloned().collect(),
+            cancellation: overrides
+                .cancellation
+                .clone()
+                .or_else(|| self.cancellation.clone()),
+        }
+    }
+}
+
+const DEFAULT_POLL_MAX_WAIT_SECS: u64 = 600;
+
+/// How long and how often to poll a long-running job, e.g. a verification batch
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::{PollOptions, RetryPolicy};
+/// use std::time::Duration;
+///
+/// let options = PollOptions::new()
+///     .max_wait(Duration::from_secs(120))
+///     .poll_timeout(Duration::from_secs(10))
+///     .backoff(RetryPolicy::Fixed(Duration::from_secs(5)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct PollOptions {
+    /// Give up after this much wall-clock time, returning `Error::PollTimeout`
+    pub max_wait: Duration,
+    /// Timeout for each status request, overriding `ClientConfig.timeout`
+    pub poll_timeout: Option<Duration>,
+    /// Delay between polls by poll number; `RetryPolicy::None` polls only once
+    pub backoff: RetryPolicy,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            max_wait: Duration::from_secs(DEFAULT_POLL_MAX_WAIT_SECS),
+            poll_timeout: None,
+            backoff: RetryPolicy::exponential(Duration::from_secs(1), Duration::from_secs(30)),
+        }
+    }
+}
+
+impl PollOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+
+    pub fn poll_timeout(mut self, timeout: Duration) -> Self {
+        self.poll_timeout = Some(timeout);
+        self
+    }
+
+    pub fn backoff(mut self, backoff: RetryPolicy) -> Self {
+        self.backoff = backoff;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_prefers_overrides() {
+        let base = RequestOptions::new()
+            .timeout(Duration::from_secs(5))
+            .idempotency_key("base")
+            .header("X-Tenant", "acme");
+        let overrides = RequestOptions::new()
+            .timeout(Duration::from_secs(120))
+            .header("X-Trace", "abc");
+
+        let merged = base.merge(&overrides);
+        assert_eq!(merged.timeout, Some(Duration::from_secs(120)));
+        assert_eq!(merged.idempotency_key, Some("base".to_string()));
+        assert_eq!(merged.headers.len(), 2);
+    }
+}
@@ -0,0 +1,196 @@
+//! Auto-pagination over list endpoints
+
+use crate::error::{Error, Result};
+use crate::types::{ContactsResponse, EmailList, ListsResponse, Pagination};
+use futures_util::stream::{self, BoxStream, Stream, StreamExt, TryStreamExt};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// One page of a list endpoint's response
+pub trait Page {
+    type Item;
+
+    fn pagination(&self) -> &Pagination;
+
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+impl Page for EmailList {
+    type Item = crate::types::Email;
+
+    fn pagination(&self) -> &Pagination {
+        &self.pagination
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.emails
+    }
+}
+
+impl Page for ContactsResponse {
+    type Item = crate::types::Contact;
+
+    fn pagination(&self) -> &Pagination {
+        &self.pagination
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.contacts
+    }
+}
+
+impl Page for ListsResponse {
+    type Item = crate::types::List;
+
+    fn pagination(&self) -> &Pagination {
+        &self.pagination
+    }
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.lists
+    }
+}
+
+/// Stream of every item across the pages of a list endpoint
+///
+/// Pages are fetched one at a time, starting at page 1, as the stream is
+/// polled. Another page is requested while the last one reports `has_next`
+/// (or a page number below `total_pages`) and was not empty. An error ends
+/// the stream after it is yielded.
+///
+/// # Example
+/// ```rust,no_run
+/// use futures_util::TryStreamExt;
+/// use mailbreeze::{ListContactsParams, Paginator};
+///
+/// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+/// let contacts = client.contacts("list_123");
+/// let pages = Paginator::new(move |page| {
+///     let contacts = contacts.clone();
+///     async move {
+///         let params = ListContactsParams { page: Some(page), ..Default::default() };
+///         contacts.list(&params).await
+///     }
+/// });
+/// let all: Vec<_> = pages.try_collect().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Paginator<T> {
+    items: BoxStream<'static, Result<T>>,
+}
+
+impl<T: Send + 'static> Paginator<T> {
+    /// Page through `fetch`, which is called with 1-based page numbers
+    pub fn new<F, Fut, P>(mut fetch: F) -> Self
+    where
+        F: FnMut(i32) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<P>> + Send + 'static,
+        P: Page<Item = T>,
+    {
+        let pages = stream::try_unfold(Some(1), move |page: Option<i32>| {
+            let request = page.map(|page| (page, fetch(page)));
+            async move {
+                let Some((page, request)) = request else {
+                    return Ok::<_, Error>(None);
+                };
+                let response = request.await?;
+                let pagination = response.pagination();
+                let more = pagination.has_next || page < pagination.total_pages;
+                let items = response.into_items();
+                let next = (more && !items.is_empty()).then_some(page + 1);
+                Ok(Some((items, next)))
+            }
+        });
+        Self {
+            items: pages
+                .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+                .try_flatten()
+                .boxed(),
+        }
+    }
+}
+
+impl<T> Stream for Paginator<T> {
+    type Item = Result<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.items.poll_next_unpin(cx)
+    }
+}
+
+impl<T> std::fmt::Debug for Paginator<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Paginator").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicI32, Ordering};
+    use std::sync::Arc;
+
+    struct NumberPage {
+        pagination: Pagination,
+        items: Vec<i32>,
+    }
+
+    impl Page for NumberPage {
+        type Item = i32;
+
+        fn pagination(&self) -> &Pagination {
+            &self.pagination
+        }
+
+        fn into_items(self) -> Vec<i32> {
+            self.items
+        }
+    }
+
+    fn page(page: i32, has_next: bool, items: Vec<i32>) -> NumberPage {
+        NumberPage {
+            pagination: Pagination {
+                page,
+                limit: 2,
+                total: 0,
+                total_pages: 0,
+                has_next,
+                has_prev: page > 1,
+            },
+            items,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_follows_has_next() {
+        let calls = Arc::new(AtomicI32::new(0));
+        let counter = calls.clone();
+        let paginator = Paginator::new(move |n| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async move { Ok(page(n, n < 3, vec![n * 10, n * 10 + 1])) }
+        });
+
+        let items: Vec<i32> = paginator.try_collect().await.unwrap();
+        assert_eq!(items, [10, 11, 20, 21, 30, 31]);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_stops_on_error_and_empty_page() {
+        let paginator = Paginator::new(|n| async move {
+            match n {
+                1 => Ok(page(1, true, vec![1])),
+                _ => Err(Error::Timeout),
+            }
+        });
+        let results: Vec<Result<i32>> = paginator.collect().await;
+        assert_eq!(results.len(), 2);
+        assert!(matches!(results[1], Err(Error::Timeout)));
+
+        // An empty page ends the stream even if the API claims there is more
+        let paginator = Paginator::new(|n| async move { Ok(page(n, true, vec![])) });
+        assert_eq!(paginator.count().await, 0);
+    }
+}
@@ -0,0 +1,60 @@
+//! Preview text shown next to the subject in inbox listings
+
+/// Hidden filler after the preheader so clients don't pad the preview with body text
+const FILLER: &str = "&#847;&zwnj;&nbsp;";
+const FILLER_REPEAT: usize = 40;
+
+/// `html` with `preheader` inserted as a hidden element at the start of the body
+///
+/// The element goes right after the `<body>` tag, or at the very start when
+/// the HTML is a fragment without one.
+pub(crate) fn inject(html: &str, preheader: &str) -> String {
+    let hidden = format!(
+        "<div style=\"display:none;font-size:1px;line-height:1px;max-height:0;max-width:0;opacity:0;overflow:hidden;mso-hide:all\">{}{}</div>",
+        escape(preheader),
+        FILLER.repeat(FILLER_REPEAT)
+    );
+    let lower = html.to_ascii_lowercase();
+    let at = lower
+        .find("<body")
+        .and_then(|start| lower[start..].find('>').map(|end| start + end + 1))
+        .unwrap_or(0);
+    let mut out = String::with_capacity(html.len() + hidden.len());
+    out.push_str(&html[..at]);
+    out.push_str(&hidden);
+    out.push_str(&html[at..]);
+    out
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inject_after_body_tag() {
+        let html = inject(
+            "<html><BODY class=\"x\"><p>Hi</p></BODY></html>",
+            "Save 20% <today>",
+        );
+        let body = html.find("<BODY class=\"x\">").unwrap();
+        let hidden = html.find("<div style=\"display:none").unwrap();
+        assert!(hidden > body && hidden < html.find("<p>Hi</p>").unwrap());
+        assert!(html.contains("Save 20% &lt;today&gt;"));
+
+        assert!(inject("<p>Hi</p>", "Preview").starts_with("<div style=\"display:none"));
+    }
+}
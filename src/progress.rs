@@ -0,0 +1,209 @@
+//! Progress reporting for long-running bulk operations
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Receives progress updates from bulk helpers such as
+/// [`Contacts::export_csv_with_progress`](crate::Contacts::export_csv_with_progress),
+/// [`Contacts::wait_for_import`](crate::Contacts::wait_for_import),
+/// [`Emails::send_bulk`](crate::Emails::send_bulk) and
+/// [`Verification::batch_chunked_with_progress`](crate::Verification::batch_chunked_with_progress)
+///
+/// Every operation calls `start` once, then `inc` or `set_position` as work
+/// completes, then `finish` whether it succeeded or not. `total` is `None`
+/// when the amount of work is not known up front.
+///
+/// With the `indicatif` feature, `indicatif::ProgressBar` implements this
+/// trait, so a bar can be passed straight in. [`LogProgress`] writes
+/// periodic lines through the `log` facade for jobs without a terminal.
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::ProgressReporter;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// #[derive(Default)]
+/// struct Counter(AtomicU64);
+///
+/// impl ProgressReporter for Counter {
+///     fn inc(&self, delta: u64) {
+///         self.0.fetch_add(delta, Ordering::Relaxed);
+///     }
+///
+///     fn set_position(&self, position: u64) {
+///         self.0.store(position, Ordering::Relaxed);
+///     }
+/// }
+/// ```
+pub trait ProgressReporter: Send + Sync {
+    /// An operation named `operation` started with `total` units of work
+    fn start(&self, operation: &str, total: Option<u64>) {
+        let _ = (operation, total);
+    }
+
+    /// `delta` more units of work completed
+    fn inc(&self, delta: u64);
+
+    /// `position` units of work have completed in total
+    fn set_position(&self, position: u64);
+
+    /// The operation ended, successfully or not
+    fn finish(&self) {}
+}
+
+/// Discards every update; used by the helpers that take no reporter
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoProgress;
+
+impl ProgressReporter for NoProgress {
+    fn inc(&self, _delta: u64) {}
+
+    fn set_position(&self, _position: u64) {}
+}
+
+/// Logs progress at `info` level under the `mailbreeze::progress` target
+///
+/// A line is written when the operation starts and finishes, and at most
+/// once per `interval` in between.
+///
+/// # Example
+/// ```rust,no_run
+/// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+/// use mailbreeze::{CsvExportOptions, LogProgress};
+/// use std::time::Duration;
+///
+/// let mut file = tokio::fs::File::create("newsletter.csv").await?;
+/// let progress = LogProgress::new(Duration::from_secs(10));
+/// client
+///     .contacts("list_123")
+///     .export_csv_with_progress(&mut file, &CsvExportOptions::default(), &progress)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct LogProgress {
+    interval: Duration,
+    state: Mutex<LogState>,
+}
+
+#[derive(Debug)]
+struct LogState {
+    operation: String,
+    total: Option<u64>,
+    position: u64,
+    last_logged: Instant,
+}
+
+impl LogProgress {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            state: Mutex::new(LogState {
+                operation: String::new(),
+                total: None,
+                position: 0,
+                last_logged: Instant::now(),
+            }),
+        }
+    }
+
+    fn update(&self, apply: impl FnOnce(&mut LogState)) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        apply(&mut state);
+        if state.last_logged.elapsed() >= self.interval {
+            state.last_logged = Instant::now();
+            log::info!(target: "mailbreeze::progress", "{}", state.describe());
+        }
+    }
+}
+
+impl Default for LogProgress {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(5))
+    }
+}
+
+impl LogState {
+    fn describe(&self) -> String {
+        match self.total {
+            Some(total) if total > 0 => format!(
+                "{}: {}/{} ({:.0}%)",
+                self.operation,
+                self.position,
+                total,
+                self.position as f64 * 100.0 / total as f64
+            ),
+            Some(total) => format!("{}: {}/{}", self.operation, self.position, total),
+            None => format!("{}: {}", self.operation, self.position),
+        }
+    }
+}
+
+impl ProgressReporter for LogProgress {
+    fn start(&self, operation: &str, total: Option<u64>) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        state.operation = operation.to_string();
+        state.total = total;
+        state.position = 0;
+        state.last_logged = Instant::now();
+        log::info!(target: "mailbreeze::progress", "{} started", operation);
+    }
+
+    fn inc(&self, delta: u64) {
+        self.update(|state| state.position += delta);
+    }
+
+    fn set_position(&self, position: u64) {
+        self.update(|state| state.position = position);
+    }
+
+    fn finish(&self) {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        log::info!(target: "mailbreeze::progress", "{} finished", state.describe());
+    }
+}
+
+#[cfg(feature = "indicatif")]
+impl ProgressReporter for indicatif::ProgressBar {
+    fn start(&self, operation: &str, total: Option<u64>) {
+        self.set_message(operation.to_string());
+        self.set_position(0);
+        if let Some(total) = total {
+            self.set_length(total);
+        }
+    }
+
+    fn inc(&self, delta: u64) {
+        indicatif::ProgressBar::inc(self, delta);
+    }
+
+    fn set_position(&self, position: u64) {
+        indicatif::ProgressBar::set_position(self, position);
+    }
+
+    fn finish(&self) {
+        indicatif::ProgressBar::finish(self);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_progress_tracks_position() {
+        let progress = LogProgress::new(Duration::from_secs(3600));
+        progress.start("export", Some(200));
+        progress.inc(40);
+        progress.inc(10);
+        assert_eq!(
+            progress.state.lock().unwrap().describe(),
+            "export: 50/200 (25%)"
+        );
+
+        progress.start("import", None);
+        progress.set_position(7);
+        assert_eq!(progress.state.lock().unwrap().describe(), "import: 7");
+    }
+}
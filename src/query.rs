@@ -0,0 +1,123 @@
+//! Query string encoding for list params
+//!
+//! Params are encoded with `serde_qs`. Flat params encode as `key=value`,
+//! sequences repeat the key (`tags=a&tags=b`) and nested structs or maps use
+//! bracketed keys (`created_at[gte]=2024-01-01`); `serde_urlencoded` supports
+//! neither of the last two.
+
+use percent_encoding::percent_decode_str;
+use serde::Serialize;
+use serde_qs::{ArrayFormat, Config};
+
+const CONFIG: Config = Config::new().array_format(ArrayFormat::Unindexed);
+
+/// Encode params into `(key, value)` pairs, ready to be percent-encoded
+pub(crate) fn to_pairs<T: Serialize>(params: &T) -> crate::Result<Vec<(String, String)>> {
+    let query = CONFIG
+        .serialize_string(params)
+        .map_err(|e| crate::Error::Query(e.to_string()))?;
+    query
+        .split('&')
+        // `None` fields are written as a bare key
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(key, value)| {
+            if key.is_empty() {
+                return Err(crate::Error::Query(
+                    "top-level query params must be a struct or map".to_string(),
+                ));
+            }
+            Ok((decode(key)?, decode(value)?))
+        })
+        .collect()
+}
+
+/// Undo `serde_qs`'s form encoding, where `+` stands for a space
+fn decode(encoded: &str) -> crate::Result<String> {
+    percent_decode_str(&encoded.replace('+', " "))
+        .decode_utf8()
+        .map(|decoded| decoded.into_owned())
+        .map_err(|e| crate::Error::Query(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{EmailStatus, RangeFilter};
+    use serde::Serialize;
+    use std::collections::BTreeMap;
+
+    fn pairs(list: &[(&str, &str)]) -> Vec<(String, String)> {
+        list.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[derive(Serialize)]
+    struct Params {
+        status: Option<EmailStatus>,
+        page: Option<i32>,
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+        created_at: Option<RangeFilter<String>>,
+        #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+        meta: BTreeMap<String, bool>,
+    }
+
+    #[test]
+    fn test_flat_and_skipped_values() {
+        let params = Params {
+            status: Some(EmailStatus::Delivered),
+            page: None,
+            tags: vec![],
+            created_at: None,
+            meta: BTreeMap::new(),
+        };
+        assert_eq!(
+            to_pairs(&params).unwrap(),
+            pairs(&[("status", "delivered")])
+        );
+    }
+
+    #[test]
+    fn test_sequences_repeat_keys_and_nested_use_brackets() {
+        let mut meta = BTreeMap::new();
+        meta.insert("vip".to_string(), true);
+        let params = Params {
+            status: None,
+            page: Some(3),
+            tags: vec!["a".to_string(), "b".to_string()],
+            created_at: Some(RangeFilter::between("2024-01-01", "2024-02-01")),
+            meta,
+        };
+
+        assert_eq!(
+            to_pairs(&params).unwrap(),
+            pairs(&[
+                ("page", "3"),
+                ("tags", "a"),
+                ("tags", "b"),
+                ("created_at[gte]", "2024-01-01"),
+                ("created_at[lte]", "2024-02-01"),
+                ("meta[vip]", "true"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_reserved_characters_round_trip() {
+        #[derive(Serialize)]
+        struct Search {
+            q: String,
+        }
+        let params = Search {
+            q: "a+b c&d=e".to_string(),
+        };
+        assert_eq!(to_pairs(&params).unwrap(), pairs(&[("q", "a+b c&d=e")]));
+    }
+
+    #[test]
+    fn test_top_level_scalar_is_rejected() {
+        assert!(to_pairs(&42).is_err());
+        assert!(to_pairs(&vec!["a"]).is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::client::HttpClient;
+use crate::error::Result;
+use crate::options::RequestOptions;
+use crate::types::{AccountSummary, KeyInfo};
+
+/// Account API resource
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::MailBreeze;
+///
+/// #[tokio::main]
+/// async fn main() -> mailbreeze::Result<()> {
+///     let client = MailBreeze::new("your_api_key")?;
+///
+///     let summary = client.account.summary().await?;
+///     println!(
+///         "{}: {} sent, {} delivered, {} bounced, {} new contacts, {} unsubscribed",
+///         summary.date,
+///         summary.sent,
+///         summary.delivered,
+///         summary.bounced,
+///         summary.new_contacts,
+///         summary.unsubscribed
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Account {
+    client: HttpClient,
+}
+
+impl Account {
+    pub fn new(client: HttpClient) -> Self {
+        Self { client }
+    }
+
+    /// Return a handle that applies `options` to every request it makes
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self::new(self.client.with_options(options))
+    }
+
+    /// Get today's sends, deliveries, bounces, new contacts and unsubscribes
+    pub async fn summary(&self) -> Result<AccountSummary> {
+        self.client.get("/account/summary").await
+    }
+
+    /// Describe the API key in use: account, scopes, rate limits and sandbox status
+    pub async fn whoami(&self) -> Result<KeyInfo> {
+        self.client.get("/account/whoami").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClientConfig;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn setup() -> (MockServer, Account) {
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+        let account = Account::new(client);
+        (mock_server, account)
+    }
+
+    #[tokio::test]
+    async fn test_summary() {
+        let (mock_server, account) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/account/summary"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "date": "2024-01-15",
+                    "sent": 1200,
+                    "delivered": 1180,
+                    "bounced": 20,
+                    "newContacts": 35,
+                    "unsubscribed": 4
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let summary = account.summary().await.unwrap();
+        assert_eq!(summary.date, "2024-01-15");
+        assert_eq!(summary.sent, 1200);
+        assert_eq!(summary.new_contacts, 35);
+        assert_eq!(summary.unsubscribed, 4);
+    }
+
+    #[tokio::test]
+    async fn test_whoami() {
+        let (mock_server, account) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/account/whoami"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "keyId": "key_123",
+                    "keyName": "billing-service",
+                    "accountId": "acct_1",
+                    "scopes": ["emails:send"],
+                    "rateLimit": {"requestsPerSecond": 10},
+                    "sandbox": true
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let key = account.whoami().await.unwrap();
+        assert_eq!(key.account_id, "acct_1");
+        assert!(key.sandbox);
+        assert!(key.has_scope("emails:send"));
+        assert!(!key.has_scope("contacts:write"));
+        assert_eq!(key.rate_limit.unwrap().requests_per_second, Some(10));
+    }
+}
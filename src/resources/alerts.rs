@@ -0,0 +1,192 @@
+use crate::client::{path_id, HttpClient};
+use crate::error::Result;
+use crate::options::RequestOptions;
+use crate::types::{Alert, AlertRule, CreateAlertRuleParams, UpdateAlertRuleParams};
+
+/// Deliverability alerts API resource
+///
+/// Alert rules watch account health (bounce rate, complaint rate, blocklist
+/// listings) and notify webhooks or mailboxes when a threshold is crossed.
+/// Triggered alerts stay active until acknowledged or the metric recovers.
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::{AlertChannel, CreateAlertRuleParams, MailBreeze};
+///
+/// #[tokio::main]
+/// async fn main() -> mailbreeze::Result<()> {
+///     let client = MailBreeze::new("your_api_key")?;
+///
+///     // Page on-call through a PagerDuty Events webhook
+///     let pagerduty = AlertChannel::Webhook {
+///         url: "https://events.pagerduty.com/integration/abc123/enqueue".to_string(),
+///     };
+///     client.alerts.create_rule(&CreateAlertRuleParams::bounce_rate_above(5.0, pagerduty)).await?;
+///
+///     for alert in client.alerts.list().await? {
+///         println!("{}: {}", alert.id, alert.message);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Alerts {
+    client: HttpClient,
+}
+
+impl Alerts {
+    pub fn new(client: HttpClient) -> Self {
+        Self { client }
+    }
+
+    /// Return a handle that applies `options` to every request it makes
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self::new(self.client.with_options(options))
+    }
+
+    /// Create an alert rule
+    pub async fn create_rule(&self, params: &CreateAlertRuleParams) -> Result<AlertRule> {
+        self.client.post("/alerts/rules", params).await
+    }
+
+    /// List all alert rules
+    pub async fn list_rules(&self) -> Result<Vec<AlertRule>> {
+        self.client.get("/alerts/rules").await
+    }
+
+    /// Update an alert rule
+    pub async fn update_rule(&self, id: &str, params: &UpdateAlertRuleParams) -> Result<AlertRule> {
+        self.client
+            .put(&format!("/alerts/rules/{}", path_id(id)?), params)
+            .await
+    }
+
+    /// Delete an alert rule
+    pub async fn delete_rule(&self, id: &str) -> Result<()> {
+        self.client
+            .delete(&format!("/alerts/rules/{}", path_id(id)?))
+            .await
+    }
+
+    /// List alerts that are currently active
+    pub async fn list(&self) -> Result<Vec<Alert>> {
+        self.client.get("/alerts").await
+    }
+
+    /// Acknowledge an active alert so it stops re-notifying
+    pub async fn acknowledge(&self, id: &str) -> Result<Alert> {
+        self.client
+            .post_empty(&format!("/alerts/{}/acknowledge", path_id(id)?))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClientConfig;
+    use crate::types::{AlertChannel, AlertMetric, AlertState};
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn setup() -> (MockServer, Alerts) {
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+        let alerts = Alerts::new(client);
+        (mock_server, alerts)
+    }
+
+    #[tokio::test]
+    async fn test_create_complaint_rate_rule() {
+        let (mock_server, alerts) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/alerts/rules"))
+            .and(body_json(serde_json::json!({
+                "metric": "complaint_rate",
+                "threshold": 0.1,
+                "channels": [
+                    {"type": "webhook", "url": "https://hooks.example.com/alerts"},
+                    {"type": "email", "to": "ops@example.com"}
+                ]
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "_id": "ar_1",
+                    "metric": "complaint_rate",
+                    "threshold": 0.1,
+                    "channels": [
+                        {"type": "webhook", "url": "https://hooks.example.com/alerts"},
+                        {"type": "email", "to": "ops@example.com"}
+                    ],
+                    "enabled": true,
+                    "createdAt": "2024-01-01T00:00:00Z"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let mut params = CreateAlertRuleParams::complaint_rate_above(
+            0.1,
+            AlertChannel::Webhook {
+                url: "https://hooks.example.com/alerts".to_string(),
+            },
+        );
+        params.channels.push(AlertChannel::Email {
+            to: "ops@example.com".to_string(),
+        });
+        let rule = alerts.create_rule(&params).await.unwrap();
+
+        assert_eq!(rule.id, "ar_1");
+        assert_eq!(rule.metric, AlertMetric::ComplaintRate);
+        assert_eq!(rule.channels.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_and_acknowledge_alerts() {
+        let (mock_server, alerts) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/alerts"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": [{
+                    "id": "al_9",
+                    "ruleId": "ar_2",
+                    "metric": "blocklist",
+                    "message": "203.0.113.7 listed on Spamhaus ZEN",
+                    "status": "active",
+                    "triggeredAt": "2024-01-02T03:04:05Z"
+                }]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/alerts/al_9/acknowledge"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "id": "al_9",
+                    "ruleId": "ar_2",
+                    "metric": "blocklist",
+                    "message": "203.0.113.7 listed on Spamhaus ZEN",
+                    "status": "acknowledged",
+                    "triggeredAt": "2024-01-02T03:04:05Z",
+                    "acknowledgedAt": "2024-01-02T03:10:00Z"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let active = alerts.list().await.unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].metric, AlertMetric::Blocklist);
+        assert_eq!(active[0].value, None);
+
+        let alert = alerts.acknowledge("al_9").await.unwrap();
+        assert_eq!(alert.status, AlertState::Acknowledged);
+        assert!(alert.acknowledged_at.is_some());
+    }
+}
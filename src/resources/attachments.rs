@@ -1,6 +1,9 @@
-use crate::client::HttpClient;
+use crate::client::{path_id, HttpClient};
 use crate::error::Result;
+use crate::options::RequestOptions;
 use crate::types::{Attachment, CreateUploadParams, UploadUrl};
+use serde::Serialize;
+use std::time::Duration;
 
 /// Attachments API resource
 #[derive(Debug, Clone)]
@@ -8,11 +11,22 @@ pub struct Attachments {
     client: HttpClient,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ExtendExpiryRequest {
+    ttl_seconds: u64,
+}
+
 impl Attachments {
     pub fn new(client: HttpClient) -> Self {
         Self { client }
     }
 
+    /// Return a handle that applies `options` to every request it makes
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self::new(self.client.with_options(options))
+    }
+
     /// Create a pre-signed upload URL
     pub async fn create_upload_url(&self, params: &CreateUploadParams) -> Result<UploadUrl> {
         self.client.post("/attachments/presigned-url", params).await
@@ -25,7 +39,7 @@ impl Attachments {
     pub async fn confirm(&self, attachment_id: &str) -> Result<()> {
         self.client
             .post_no_response(
-                &format!("/attachments/{}/confirm", attachment_id),
+                &format!("/attachments/{}/confirm", path_id(attachment_id)?),
                 &serde_json::json!({}),
             )
             .await
@@ -33,12 +47,31 @@ impl Attachments {
 
     /// Get an attachment by ID
     pub async fn get(&self, id: &str) -> Result<Attachment> {
-        self.client.get(&format!("/attachments/{}", id)).await
+        self.client
+            .get(&format!("/attachments/{}", path_id(id)?))
+            .await
+    }
+
+    /// Push an attachment's expiry back to `ttl` from now
+    ///
+    /// Extend before resending an email whose attachment is close to lapsing;
+    /// an attachment that has already expired cannot be extended.
+    pub async fn extend_expiry(&self, id: &str, ttl: Duration) -> Result<Attachment> {
+        self.client
+            .post(
+                &format!("/attachments/{}/extend", path_id(id)?),
+                &ExtendExpiryRequest {
+                    ttl_seconds: ttl.as_secs(),
+                },
+            )
+            .await
     }
 
     /// Delete an attachment
     pub async fn delete(&self, id: &str) -> Result<()> {
-        self.client.delete(&format!("/attachments/{}", id)).await
+        self.client
+            .delete(&format!("/attachments/{}", path_id(id)?))
+            .await
     }
 }
 
@@ -46,7 +79,8 @@ impl Attachments {
 mod tests {
     use super::*;
     use crate::client::ClientConfig;
-    use wiremock::matchers::{method, path};
+    use crate::types::AttachmentStatus;
+    use wiremock::matchers::{body_json, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     async fn setup() -> (MockServer, Attachments) {
@@ -108,7 +142,8 @@ mod tests {
         let attachment = attachments.get("attach_123").await.unwrap();
         assert_eq!(attachment.id, "attach_123");
         assert_eq!(attachment.filename, "document.pdf");
-        assert_eq!(attachment.status, "ready");
+        assert_eq!(attachment.status, AttachmentStatus::Ready);
+        assert!(!attachment.is_expired());
     }
 
     #[tokio::test]
@@ -172,6 +207,65 @@ mod tests {
             .await;
 
         let attachment = attachments.get("attach_789").await.unwrap();
-        assert_eq!(attachment.status, "pending");
+        assert_eq!(attachment.status, AttachmentStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_expired_attachment() {
+        let (mock_server, attachments) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/attachments/attach_old"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "id": "attach_old",
+                    "filename": "invoice.pdf",
+                    "contentType": "application/pdf",
+                    "size": 2048,
+                    "status": "ready",
+                    "createdAt": "2024-01-01T00:00:00Z",
+                    "expiresAt": "2024-01-08T00:00:00Z"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        // Lapsed by date even before the API flips the status
+        let attachment = attachments.get("attach_old").await.unwrap();
+        assert!(attachment.is_expired());
+    }
+
+    #[tokio::test]
+    async fn test_extend_expiry() {
+        let (mock_server, attachments) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/attachments/attach_123/extend"))
+            .and(body_json(serde_json::json!({"ttlSeconds": 604800})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "id": "attach_123",
+                    "filename": "document.pdf",
+                    "contentType": "application/pdf",
+                    "size": 1024000,
+                    "status": "ready",
+                    "createdAt": "2024-01-01T00:00:00Z",
+                    "expiresAt": "2999-01-01T00:00:00Z"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let attachment = attachments
+            .extend_expiry("attach_123", Duration::from_secs(7 * 24 * 60 * 60))
+            .await
+            .unwrap();
+        assert_eq!(
+            attachment.expires_at.as_deref(),
+            Some("2999-01-01T00:00:00Z")
+        );
+        assert!(!attachment.is_expired());
     }
 }
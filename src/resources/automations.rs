@@ -0,0 +1,258 @@
+use crate::client::{path_id, HttpClient};
+use crate::error::Result;
+use crate::options::RequestOptions;
+use crate::types::{
+    ContactEnrollmentsResponse, EnrollParams, Enrollment, EnrollmentsResponse,
+    ListEnrollmentsParams,
+};
+
+/// Automations API resource
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::MailBreeze;
+///
+/// #[tokio::main]
+/// async fn main() -> mailbreeze::Result<()> {
+///     let client = MailBreeze::new("your_api_key")?;
+///
+///     // Show where a contact currently is in every automation
+///     let enrollments = client.automations.enrollments_for_contact("contact_123").await?;
+///     for enrollment in enrollments {
+///         println!(
+///             "{} - step {:?} ({:?})",
+///             enrollment.automation_name.unwrap_or(enrollment.automation_id),
+///             enrollment.current_step,
+///             enrollment.status
+///         );
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Automations {
+    client: HttpClient,
+}
+
+impl Automations {
+    pub fn new(client: HttpClient) -> Self {
+        Self { client }
+    }
+
+    /// Return a handle that applies `options` to every request it makes
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self::new(self.client.with_options(options))
+    }
+
+    /// Enroll a contact in an automation
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// #[derive(serde::Serialize, serde::Deserialize)]
+    /// struct Onboarding {
+    ///     plan: String,
+    ///     trial_days: u32,
+    /// }
+    ///
+    /// let params = mailbreeze::EnrollParams {
+    ///     contact_id: "contact_123".to_string(),
+    ///     ..Default::default()
+    /// }
+    /// .typed_variables(&Onboarding { plan: "pro".to_string(), trial_days: 14 })?;
+    ///
+    /// let enrollment = client.automations.enroll("auto_welcome", &params).await?;
+    /// let vars: Onboarding = enrollment.variables_as()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn enroll(&self, automation_id: &str, params: &EnrollParams) -> Result<Enrollment> {
+        self.client
+            .post(
+                &format!("/automations/{}/enrollments", path_id(automation_id)?),
+                params,
+            )
+            .await
+    }
+
+    /// List enrollments across all automations
+    pub async fn list_enrollments(
+        &self,
+        params: &ListEnrollmentsParams,
+    ) -> Result<EnrollmentsResponse> {
+        self.client
+            .get_with_params("/automations/enrollments", params)
+            .await
+    }
+
+    /// List a contact's enrollments across all automations
+    pub async fn enrollments_for_contact(&self, contact_id: &str) -> Result<Vec<Enrollment>> {
+        let response: ContactEnrollmentsResponse = self
+            .client
+            .get(&format!(
+                "/automations/contacts/{}/enrollments",
+                path_id(contact_id)?
+            ))
+            .await?;
+        Ok(response.enrollments)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClientConfig;
+    use crate::types::{EnrollmentSort, EnrollmentStatus, SortOrder};
+    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn setup() -> (MockServer, Automations) {
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+        let automations = Automations::new(client);
+        (mock_server, automations)
+    }
+
+    #[tokio::test]
+    async fn test_list_enrollments() {
+        let (mock_server, automations) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/automations/enrollments"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "enrollments": [
+                        {"id": "enr_1", "automationId": "auto_1", "contactId": "contact_1", "status": "active", "currentStep": 2, "enrolledAt": "2024-01-01T00:00:00Z"},
+                        {"id": "enr_2", "automationId": "auto_2", "contactId": "contact_2", "status": "completed", "enrolledAt": "2024-01-01T00:00:00Z", "completedAt": "2024-01-08T00:00:00Z"}
+                    ],
+                    "pagination": {"page": 1, "limit": 10, "total": 2, "totalPages": 1, "hasNext": false, "hasPrev": false}
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let result = automations
+            .list_enrollments(&ListEnrollmentsParams::default())
+            .await
+            .unwrap();
+        assert_eq!(result.enrollments.len(), 2);
+        assert_eq!(result.enrollments[1].status, EnrollmentStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_list_enrollments_with_filters_and_cursor() {
+        let (mock_server, automations) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/automations/enrollments"))
+            .and(query_param("automation_id", "auto_1"))
+            .and(query_param("status", "active"))
+            .and(query_param("enrolled_after", "2024-06-01T00:00:00Z"))
+            .and(query_param("sort_by", "enrolled_at"))
+            .and(query_param("sort_order", "desc"))
+            .and(query_param("cursor", "c_abc"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "enrollments": [
+                        {"id": "enr_3", "automationId": "auto_1", "contactId": "contact_3", "status": "active", "enrolledAt": "2024-06-02T00:00:00Z"}
+                    ],
+                    "nextCursor": "c_def"
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let params = ListEnrollmentsParams {
+            automation_id: Some("auto_1".to_string()),
+            status: Some(EnrollmentStatus::Active),
+            enrolled_after: Some("2024-06-01T00:00:00Z".to_string()),
+            sort_by: Some(EnrollmentSort::EnrolledAt),
+            sort_order: Some(SortOrder::Desc),
+            cursor: Some("c_abc".to_string()),
+            ..Default::default()
+        };
+        let result = automations.list_enrollments(&params).await.unwrap();
+        assert_eq!(result.enrollments[0].id, "enr_3");
+        assert_eq!(result.next_cursor.as_deref(), Some("c_def"));
+    }
+
+    #[tokio::test]
+    async fn test_enrollments_for_contact() {
+        let (mock_server, automations) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/automations/contacts/contact_123/enrollments"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "enrollments": [
+                        {"id": "enr_1", "automationId": "auto_welcome", "automationName": "Welcome Series", "contactId": "contact_123", "status": "active", "currentStep": 3, "currentStepName": "Day 3 tips", "enrolledAt": "2024-01-01T00:00:00Z"},
+                        {"id": "enr_2", "automationId": "auto_winback", "automationName": "Win-back", "contactId": "contact_123", "status": "active", "currentStep": 1, "enrolledAt": "2024-01-05T00:00:00Z"}
+                    ]
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let enrollments = automations
+            .enrollments_for_contact("contact_123")
+            .await
+            .unwrap();
+        assert_eq!(enrollments.len(), 2);
+        assert_eq!(
+            enrollments[0].automation_name,
+            Some("Welcome Series".to_string())
+        );
+        assert_eq!(enrollments[0].current_step, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_enroll_with_typed_variables() {
+        #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+        #[serde(rename_all = "camelCase")]
+        struct Onboarding {
+            plan: String,
+            trial_days: u32,
+        }
+
+        let (mock_server, automations) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/automations/auto_1/enrollments"))
+            .and(body_json(serde_json::json!({
+                "contactId": "contact_1",
+                "variables": {"plan": "pro", "trialDays": 14}
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "id": "enr_1",
+                    "automationId": "auto_1",
+                    "contactId": "contact_1",
+                    "status": "active",
+                    "variables": {"plan": "pro", "trialDays": 14}
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let vars = Onboarding {
+            plan: "pro".to_string(),
+            trial_days: 14,
+        };
+        let params = EnrollParams {
+            contact_id: "contact_1".to_string(),
+            ..Default::default()
+        }
+        .typed_variables(&vars)
+        .unwrap();
+
+        let enrollment = automations.enroll("auto_1", &params).await.unwrap();
+        assert_eq!(enrollment.variables_as::<Onboarding>().unwrap(), vars);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::client::{path_id, HttpClient};
+use crate::error::Result;
+use crate::options::RequestOptions;
+use crate::types::{BrandingSettings, UpdateBrandingParams};
+
+/// Branding API resource: footer, physical address and logo used in compliance footers
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::{MailBreeze, UpdateBrandingParams};
+///
+/// #[tokio::main]
+/// async fn main() -> mailbreeze::Result<()> {
+///     let client = MailBreeze::new("your_api_key")?;
+///
+///     // Give a second brand its own footer
+///     client.branding.update_for_domain("news.otherbrand.com", &UpdateBrandingParams {
+///         footer: Some("<p>Other Brand Ltd.</p>".to_string()),
+///         physical_address: Some("1 Market St, Lagos".to_string()),
+///         ..Default::default()
+///     }).await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Branding {
+    client: HttpClient,
+}
+
+impl Branding {
+    pub fn new(client: HttpClient) -> Self {
+        Self { client }
+    }
+
+    /// Return a handle that applies `options` to every request it makes
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self::new(self.client.with_options(options))
+    }
+
+    /// Get the account's default branding
+    pub async fn get(&self) -> Result<BrandingSettings> {
+        self.client.get("/branding").await
+    }
+
+    /// Update the account's default branding
+    pub async fn update(&self, params: &UpdateBrandingParams) -> Result<BrandingSettings> {
+        self.client.patch("/branding", params).await
+    }
+
+    /// Get the branding used for a sending domain, falling back to the account default
+    pub async fn get_for_domain(&self, domain: &str) -> Result<BrandingSettings> {
+        self.client
+            .get(&format!("/branding/domains/{}", path_id(domain)?))
+            .await
+    }
+
+    /// Set branding for a sending domain, overriding the account default
+    pub async fn update_for_domain(
+        &self,
+        domain: &str,
+        params: &UpdateBrandingParams,
+    ) -> Result<BrandingSettings> {
+        self.client
+            .patch(&format!("/branding/domains/{}", path_id(domain)?), params)
+            .await
+    }
+
+    /// Remove a sending domain's branding so it uses the account default again
+    pub async fn reset_domain(&self, domain: &str) -> Result<()> {
+        self.client
+            .delete(&format!("/branding/domains/{}", path_id(domain)?))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClientConfig;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn setup() -> (MockServer, Branding) {
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+        let branding = Branding::new(client);
+        (mock_server, branding)
+    }
+
+    #[tokio::test]
+    async fn test_get_default() {
+        let (mock_server, branding) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/branding"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "footer": "<p>Acme Inc.</p>",
+                    "physicalAddress": "1 Main St, Springfield",
+                    "logoUrl": "https://cdn.example.com/acme.png"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let settings = branding.get().await.unwrap();
+        assert_eq!(settings.domain, None);
+        assert_eq!(
+            settings.physical_address.as_deref(),
+            Some("1 Main St, Springfield")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_for_domain() {
+        let (mock_server, branding) = setup().await;
+
+        Mock::given(method("PATCH"))
+            .and(path("/api/v1/branding/domains/news.example.com"))
+            .and(body_json(
+                serde_json::json!({"logoUrl": "https://cdn.example.com/news.png"}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "domain": "news.example.com",
+                    "logoUrl": "https://cdn.example.com/news.png"
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let settings = branding
+            .update_for_domain(
+                "news.example.com",
+                &UpdateBrandingParams {
+                    logo_url: Some("https://cdn.example.com/news.png".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(settings.domain.as_deref(), Some("news.example.com"));
+    }
+}
@@ -1,10 +1,37 @@
-use crate::client::HttpClient;
-use crate::error::Result;
+use crate::client::{path_id, HttpClient};
+use crate::error::{Error, Result};
+use crate::observer::ContactObserver;
+use crate::options::{PollOptions, RequestOptions};
+use crate::paginator::Paginator;
+use crate::progress::{NoProgress, ProgressReporter};
+use futures_util::stream::{self, Stream, StreamExt, TryStreamExt};
+use std::borrow::Cow;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::resources::Verification;
 use crate::types::{
-    Contact, ContactsResponse, CreateContactParams, ListContactsParams, SuppressParams,
-    SuppressReason, UpdateContactParams,
+    Contact, ContactImport, ContactsResponse, CreateContactParams, CreateVerifiedOptions,
+    CsvExportOptions, CustomFieldDefinition, ImportFromUrlParams, ImportMapping,
+    ListContactsParams, SuppressParams, SuppressReason, UpdateContactParams, VerificationCategory,
+    VerifiedContact,
 };
 
+/// Columns written before any custom fields in a CSV export
+const CSV_COLUMNS: [&str; 11] = [
+    "id",
+    "email",
+    "firstName",
+    "lastName",
+    "phoneNumber",
+    "status",
+    "source",
+    "createdAt",
+    "updatedAt",
+    "subscribedAt",
+    "unsubscribedAt",
+];
+
 /// Contacts API resource - scoped to a specific contact list
 ///
 /// All contact operations are performed within the context of a specific list.
@@ -48,36 +75,410 @@ impl Contacts {
         }
     }
 
+    /// Return a handle that applies `options` to every request it makes
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self::new(self.client.with_options(options), self.list_id.clone())
+    }
+
     /// Build the path for contact operations within this list
-    fn path(&self, suffix: &str) -> String {
-        format!("/contact-lists/{}/contacts{}", self.list_id, suffix)
+    fn path(&self, suffix: &str) -> Result<String> {
+        Ok(format!(
+            "/contact-lists/{}/contacts{}",
+            path_id(&self.list_id)?,
+            suffix
+        ))
+    }
+
+    /// The configured lifecycle observer, if any
+    fn observer(&self) -> Option<&dyn ContactObserver> {
+        self.client.config().contact_observer.as_deref()
     }
 
     /// Create a new contact in the list
     pub async fn create(&self, params: &CreateContactParams) -> Result<Contact> {
-        self.client.post(&self.path(""), params).await
+        let contact: Contact = self.client.post(&self.path("")?, params).await?;
+        if let Some(observer) = self.observer() {
+            observer.on_contact_created(&self.list_id, &contact);
+        }
+        Ok(contact)
+    }
+
+    /// Verify the address first, then create the contact unless verification rejects it
+    ///
+    /// With `auto_correct`, an address with a suggested correction (from the
+    /// API or a built-in table of typo domains like `gmial.com`) is replaced
+    /// when the corrected address verifies clean. Addresses the policy
+    /// categorizes as dirty are not created; `contact` is `None` in that case.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use mailbreeze::{CreateContactParams, CreateVerifiedOptions};
+    ///
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// let options = CreateVerifiedOptions { auto_correct: true, ..Default::default() };
+    /// let outcome = client
+    ///     .contacts("list_123")
+    ///     .create_verified(
+    ///         &CreateContactParams { email: "jane@gmial.com".to_string(), ..Default::default() },
+    ///         &options,
+    ///     )
+    ///     .await?;
+    /// if let Some(original) = outcome.corrected_from {
+    ///     println!("signed up {} (typed {})", outcome.verification.email, original);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_verified(
+        &self,
+        params: &CreateContactParams,
+        options: &CreateVerifiedOptions,
+    ) -> Result<VerifiedContact> {
+        let verification = Verification::new(self.client.clone());
+        let mut result = verification.verify(&params.email).await?;
+        let mut corrected_from = None;
+
+        if options.auto_correct {
+            if let Some(suggested) = result.suggested_email() {
+                let corrected = verification.verify(&suggested).await?;
+                if options.policy.categorize(&corrected) == VerificationCategory::Clean {
+                    corrected_from = Some(params.email.clone());
+                    result = corrected;
+                }
+            }
+        }
+
+        if options.policy.categorize(&result) == VerificationCategory::Dirty {
+            return Ok(VerifiedContact {
+                contact: None,
+                verification: result,
+                corrected_from,
+            });
+        }
+        let params = CreateContactParams {
+            email: result.email.clone(),
+            ..params.clone()
+        };
+        Ok(VerifiedContact {
+            contact: Some(self.create(&params).await?),
+            verification: result,
+            corrected_from,
+        })
     }
 
     /// Get a contact by ID
     pub async fn get(&self, id: &str) -> Result<Contact> {
-        self.client.get(&self.path(&format!("/{}", id))).await
+        self.client
+            .get(&self.path(&format!("/{}", path_id(id)?))?)
+            .await
     }
 
-    /// Update a contact
-    pub async fn update(&self, id: &str, params: &UpdateContactParams) -> Result<Contact> {
+    /// Get a contact with its `ETag`, for use with [`Contacts::delete_if_match`]
+    pub async fn get_with_etag(&self, id: &str) -> Result<(Contact, Option<String>)> {
         self.client
-            .put(&self.path(&format!("/{}", id)), params)
+            .get_with_etag(&self.path(&format!("/{}", path_id(id)?))?)
             .await
     }
 
+    /// Update a contact
+    pub async fn update(&self, id: &str, params: &UpdateContactParams) -> Result<Contact> {
+        let contact: Contact = self
+            .client
+            .put(&self.path(&format!("/{}", path_id(id)?))?, params)
+            .await?;
+        if let Some(observer) = self.observer() {
+            observer.on_contact_updated(&self.list_id, &contact);
+        }
+        Ok(contact)
+    }
+
     /// Delete a contact
     pub async fn delete(&self, id: &str) -> Result<()> {
-        self.client.delete(&self.path(&format!("/{}", id))).await
+        self.client
+            .delete(&self.path(&format!("/{}", path_id(id)?))?)
+            .await?;
+        if let Some(observer) = self.observer() {
+            observer.on_contact_deleted(&self.list_id, id);
+        }
+        Ok(())
+    }
+
+    /// Delete a contact only if it still has `etag`
+    ///
+    /// Fails with [`Error::PreconditionFailed`](crate::Error::PreconditionFailed)
+    /// if the contact was modified or recreated since the ETag was read.
+    pub async fn delete_if_match(&self, id: &str, etag: &str) -> Result<()> {
+        self.with_options(RequestOptions::new().if_match(etag))
+            .delete(id)
+            .await
+    }
+
+    /// Restore a contact deleted within the platform's grace window
+    pub async fn restore(&self, id: &str) -> Result<Contact> {
+        let contact: Contact = self
+            .client
+            .post_empty(&self.path(&format!("/{}/restore", path_id(id)?))?)
+            .await?;
+        if let Some(observer) = self.observer() {
+            observer.on_contact_restored(&self.list_id, &contact);
+        }
+        Ok(contact)
+    }
+
+    /// Import contacts from a CSV the API fetches from `url`
+    ///
+    /// The file is streamed server-side into the import pipeline and never
+    /// passes through this process, so it can be arbitrarily large. Use a
+    /// signed storage URL or a spreadsheet export link such as
+    /// `https://docs.google.com/spreadsheets/d/<id>/export?format=csv`.
+    /// Poll [`Contacts::get_import`] or [`Contacts::wait_for_import`] to follow progress.
+    pub async fn import_from_url(
+        &self,
+        url: &str,
+        mapping: &ImportMapping,
+    ) -> Result<ContactImport> {
+        self.import_from_url_with(&ImportFromUrlParams {
+            source_url: url.to_string(),
+            mapping: mapping.clone(),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Import contacts from a remote CSV with full control over the import settings
+    pub async fn import_from_url_with(
+        &self,
+        params: &ImportFromUrlParams,
+    ) -> Result<ContactImport> {
+        self.client.post(&self.path("/imports")?, params).await
+    }
+
+    /// Get the progress of an import
+    pub async fn get_import(&self, import_id: &str) -> Result<ContactImport> {
+        self.client
+            .get(&self.path(&format!("/imports/{}", path_id(import_id)?))?)
+            .await
+    }
+
+    /// Poll an import until it completes or fails, reporting processed rows to `progress`
+    ///
+    /// Rows count as processed once imported, skipped or failed. Returns
+    /// [`Error::Timeout`] if the import is still running after
+    /// `options.max_wait`; call [`Contacts::get_import`] for its latest state.
+    pub async fn wait_for_import(
+        &self,
+        import_id: &str,
+        options: &PollOptions,
+        progress: &dyn ProgressReporter,
+    ) -> Result<ContactImport> {
+        let result = self.poll_import(import_id, options, progress).await;
+        progress.finish();
+        result
+    }
+
+    async fn poll_import(
+        &self,
+        import_id: &str,
+        options: &PollOptions,
+        progress: &dyn ProgressReporter,
+    ) -> Result<ContactImport> {
+        let poller = match options.poll_timeout {
+            Some(timeout) => self.with_options(RequestOptions::new().timeout(timeout)),
+            None => self.clone(),
+        };
+        let started = Instant::now();
+        let mut polls = 0;
+        let mut total = None;
+
+        loop {
+            polls += 1;
+            let status = poller.get_import(import_id).await?;
+            // The row count is only known once the API has read the file
+            let rows = u64::try_from(status.total_rows)
+                .ok()
+                .filter(|&rows| rows > 0);
+            if polls == 1 || rows != total {
+                total = rows;
+                progress.start("import contacts", total);
+            }
+            let processed = status.imported + status.skipped + status.failed;
+            progress.set_position(u64::try_from(processed).unwrap_or(0));
+            if status.is_complete() {
+                return Ok(status);
+            }
+
+            let remaining = options.max_wait.saturating_sub(started.elapsed());
+            let delay = match options.backoff.delay(polls) {
+                Some(delay) if remaining > Duration::ZERO => delay.min(remaining),
+                _ => return Err(Error::Timeout),
+            };
+            tokio::time::sleep(delay).await;
+        }
     }
 
     /// List contacts in the list with optional filters
     pub async fn list(&self, params: &ListContactsParams) -> Result<ContactsResponse> {
-        self.client.get_with_params(&self.path(""), params).await
+        self.client.get_with_params(&self.path("")?, params).await
+    }
+
+    /// Stream every contact matching `params`, one page at a time, in order
+    ///
+    /// `params.page` is ignored; paging starts from the first page.
+    pub fn list_all(&self, params: &ListContactsParams) -> Paginator<Contact> {
+        let contacts = self.clone();
+        let params = params.clone();
+        Paginator::new(move |page| {
+            let contacts = contacts.clone();
+            let params = ListContactsParams {
+                page: Some(page),
+                ..params.clone()
+            };
+            async move { contacts.list(&params).await }
+        })
+    }
+
+    /// Stream every contact in the list, fetching up to `pages_in_flight` pages at once
+    ///
+    /// Contacts arrive in no particular order. The first page is fetched alone to
+    /// learn the page count; each page request goes through the normal retry loop,
+    /// so pair this with `retry_rate_limits` to ride out 429s on large exports.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use futures_util::TryStreamExt;
+    ///
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// let contacts = client.contacts("list_123");
+    /// let all: Vec<_> = contacts
+    ///     .list_stream_parallel(&Default::default(), 8)
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_stream_parallel<'a>(
+        &'a self,
+        params: &ListContactsParams,
+        pages_in_flight: usize,
+    ) -> impl Stream<Item = Result<Contact>> + 'a {
+        let params = params.clone();
+        let first = ListContactsParams {
+            page: Some(1),
+            ..params.clone()
+        };
+
+        stream::once(async move { self.list(&first).await })
+            .map_ok(move |first| {
+                let params = params.clone();
+                let rest = stream::iter(2..=first.pagination.total_pages)
+                    .map(move |page| {
+                        let params = ListContactsParams {
+                            page: Some(page),
+                            ..params.clone()
+                        };
+                        async move { self.list(&params).await }
+                    })
+                    .buffer_unordered(pages_in_flight.max(1))
+                    .map_ok(|page| stream::iter(page.contacts.into_iter().map(Ok)))
+                    .try_flatten();
+
+                stream::iter(first.contacts.into_iter().map(Ok)).chain(rest)
+            })
+            .try_flatten()
+    }
+
+    /// Custom fields defined on this list
+    pub async fn custom_fields(&self) -> Result<Vec<CustomFieldDefinition>> {
+        self.client
+            .get(&format!(
+                "/contact-lists/{}/custom-fields",
+                path_id(&self.list_id)?
+            ))
+            .await
+    }
+
+    /// Write the list's contacts to `writer` as CSV, returning the number of rows
+    ///
+    /// Pages are fetched in order and written as they arrive, so memory use
+    /// stays flat for large lists. With `flatten_custom_fields`, the columns
+    /// come from [`Contacts::custom_fields`]; values for keys outside that
+    /// schema are left out. Otherwise custom fields go in one JSON column.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// use mailbreeze::CsvExportOptions;
+    ///
+    /// let mut file = tokio::fs::File::create("newsletter.csv").await?;
+    /// let options = CsvExportOptions {
+    ///     flatten_custom_fields: true,
+    ///     ..Default::default()
+    /// };
+    /// client.contacts("list_123").export_csv(&mut file, &options).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn export_csv<W>(&self, writer: &mut W, options: &CsvExportOptions) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        self.export_csv_with_progress(writer, options, &NoProgress)
+            .await
+    }
+
+    /// Like [`export_csv`](Self::export_csv), reporting each row written to `progress`
+    ///
+    /// Pages are streamed, so no total is reported up front.
+    pub async fn export_csv_with_progress<W>(
+        &self,
+        writer: &mut W,
+        options: &CsvExportOptions,
+        progress: &dyn ProgressReporter,
+    ) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let result = self.write_csv(writer, options, progress).await;
+        progress.finish();
+        result
+    }
+
+    async fn write_csv<W>(
+        &self,
+        writer: &mut W,
+        options: &CsvExportOptions,
+        progress: &dyn ProgressReporter,
+    ) -> Result<u64>
+    where
+        W: AsyncWrite + Unpin,
+    {
+        let custom_keys: Option<Vec<String>> = if options.flatten_custom_fields {
+            let fields = self.custom_fields().await?;
+            Some(fields.into_iter().map(|field| field.key).collect())
+        } else {
+            None
+        };
+
+        let mut header: Vec<Cow<str>> = CSV_COLUMNS.iter().map(|&c| Cow::Borrowed(c)).collect();
+        match &custom_keys {
+            Some(keys) => header.extend(
+                keys.iter()
+                    .map(|key| Cow::Owned(format!("customFields.{}", key))),
+            ),
+            None => header.push(Cow::Borrowed("customFields")),
+        }
+        write_csv_row(writer, &header).await?;
+
+        let mut rows = 0;
+        let mut contacts = self.list_all(&options.filter);
+        progress.start("export contacts", None);
+        while let Some(contact) = contacts.try_next().await? {
+            write_csv_row(writer, &csv_record(&contact, custom_keys.as_deref())).await?;
+            rows += 1;
+            progress.inc(1);
+        }
+        writer.flush().await?;
+        Ok(rows)
     }
 
     /// Suppress a contact
@@ -88,9 +489,79 @@ impl Contacts {
     pub async fn suppress(&self, id: &str, reason: SuppressReason) -> Result<()> {
         let params = SuppressParams { reason };
         self.client
-            .post_no_response(&self.path(&format!("/{}/suppress", id)), &params)
-            .await
+            .post_no_response(&self.path(&format!("/{}/suppress", path_id(id)?))?, &params)
+            .await?;
+        if let Some(observer) = self.observer() {
+            observer.on_contact_suppressed(&self.list_id, id, &params.reason);
+        }
+        Ok(())
+    }
+}
+
+/// Values for one contact, in the same order as the export header
+fn csv_record<'a>(contact: &'a Contact, custom_keys: Option<&[String]>) -> Vec<Cow<'a, str>> {
+    let optional = |value: &'a Option<String>| Cow::Borrowed(value.as_deref().unwrap_or(""));
+    let status = serde_json::to_value(&contact.status)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default();
+    let mut record = vec![
+        Cow::Borrowed(contact.id.as_str()),
+        Cow::Borrowed(contact.email.as_str()),
+        optional(&contact.first_name),
+        optional(&contact.last_name),
+        optional(&contact.phone_number),
+        Cow::Owned(status),
+        optional(&contact.source),
+        Cow::Borrowed(contact.created_at.as_str()),
+        optional(&contact.updated_at),
+        optional(&contact.subscribed_at),
+        optional(&contact.unsubscribed_at),
+    ];
+
+    let fields = contact.custom_fields.as_ref();
+    match custom_keys {
+        Some(keys) => {
+            record.extend(
+                keys.iter()
+                    .map(|key| match fields.and_then(|fields| fields.get(key)) {
+                        None | Some(serde_json::Value::Null) => Cow::Borrowed(""),
+                        Some(serde_json::Value::String(s)) => Cow::Borrowed(s.as_str()),
+                        Some(other) => Cow::Owned(other.to_string()),
+                    }),
+            )
+        }
+        None => record.push(match fields {
+            Some(fields) if !fields.is_empty() => {
+                Cow::Owned(serde_json::to_string(fields).unwrap_or_default())
+            }
+            _ => Cow::Borrowed(""),
+        }),
+    }
+    record
+}
+
+/// Write one CSV line, quoting fields that contain separators, quotes or newlines
+async fn write_csv_row<W>(writer: &mut W, fields: &[Cow<'_, str>]) -> Result<()>
+where
+    W: AsyncWrite + Unpin,
+{
+    let mut line = String::new();
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            line.push(',');
+        }
+        if field.contains([',', '"', '\n', '\r']) {
+            line.push('"');
+            line.push_str(&field.replace('"', "\"\""));
+            line.push('"');
+        } else {
+            line.push_str(field);
+        }
     }
+    line.push_str("\r\n");
+    writer.write_all(line.as_bytes()).await?;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -98,7 +569,7 @@ mod tests {
     use super::*;
     use crate::client::ClientConfig;
     use crate::types::ContactStatus;
-    use wiremock::matchers::{method, path};
+    use wiremock::matchers::{body_json, body_partial_json, method, path};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     async fn setup() -> (MockServer, Contacts) {
@@ -143,6 +614,123 @@ mod tests {
         assert_eq!(contact.status, ContactStatus::Active);
     }
 
+    #[tokio::test]
+    async fn test_create_verified_auto_corrects_typo() {
+        let (mock_server, contacts) = setup().await;
+
+        let verify_response = |email: &str, status: &str, suggestion: Option<&str>| {
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"email": email, "status": status, "suggestion": suggestion}
+            }))
+        };
+        Mock::given(method("POST"))
+            .and(path("/api/v1/email-verification/single"))
+            .and(body_json(serde_json::json!({"email": "jane@gmial.com"})))
+            .respond_with(verify_response(
+                "jane@gmial.com",
+                "invalid",
+                Some("jane@gmail.com"),
+            ))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/email-verification/single"))
+            .and(body_json(serde_json::json!({"email": "jane@gmail.com"})))
+            .respond_with(verify_response("jane@gmail.com", "valid", None))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/contact-lists/list_123/contacts"))
+            .and(body_partial_json(
+                serde_json::json!({"email": "jane@gmail.com"}),
+            ))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"id": "contact_1", "email": "jane@gmail.com", "status": "active"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let params = CreateContactParams {
+            email: "jane@gmial.com".to_string(),
+            ..Default::default()
+        };
+
+        // Without auto-correct the invalid address is rejected, not created
+        let outcome = contacts
+            .create_verified(&params, &CreateVerifiedOptions::default())
+            .await
+            .unwrap();
+        assert!(outcome.contact.is_none());
+        assert_eq!(outcome.verification.apply_suggestion(), "jane@gmail.com");
+
+        let options = CreateVerifiedOptions {
+            auto_correct: true,
+            ..Default::default()
+        };
+        let outcome = contacts.create_verified(&params, &options).await.unwrap();
+        assert_eq!(outcome.contact.unwrap().id, "contact_1");
+        assert_eq!(outcome.corrected_from.as_deref(), Some("jane@gmial.com"));
+    }
+
+    #[tokio::test]
+    async fn test_create_contact_with_idempotency_token() {
+        let (mock_server, contacts) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/contact-lists/list_123/contacts"))
+            .and(body_partial_json(serde_json::json!({
+                "idempotencyToken": "webhook-evt-991"
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "id": "contact_456",
+                    "email": "jane@example.com",
+                    "status": "active",
+                    "createdAt": "2024-01-01T00:00:00Z"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let params = CreateContactParams {
+            email: "jane@example.com".to_string(),
+            idempotency_token: Some("webhook-evt-991".to_string()),
+            ..Default::default()
+        };
+
+        let contact = contacts.create(&params).await.unwrap();
+        assert_eq!(contact.id, "contact_456");
+    }
+
+    #[tokio::test]
+    async fn test_create_existing_contact_returns_conflict() {
+        let (mock_server, contacts) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/contact-lists/list_123/contacts"))
+            .respond_with(ResponseTemplate::new(409).set_body_json(serde_json::json!({
+                "error": "Contact already exists",
+                "code": "contact_exists",
+                "existingId": "contact_123"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let params = CreateContactParams {
+            email: "john@example.com".to_string(),
+            ..Default::default()
+        };
+
+        let error = contacts.create(&params).await.unwrap_err();
+        assert!(matches!(error, crate::Error::Conflict { .. }));
+        assert_eq!(error.existing_id(), Some("contact_123"));
+        assert_eq!(error.status_code(), Some(409));
+    }
+
     #[tokio::test]
     async fn test_get_contact() {
         let (mock_server, contacts) = setup().await;
@@ -210,6 +798,33 @@ mod tests {
         contacts.delete("contact_123").await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_restore_contact() {
+        let (mock_server, contacts) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path(
+                "/api/v1/contact-lists/list_123/contacts/contact_123/restore",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "id": "contact_123",
+                    "email": "john@example.com",
+                    "status": "active",
+                    "source": "api",
+                    "createdAt": "2024-01-01T00:00:00Z",
+                    "updatedAt": "2024-01-02T00:00:00Z"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let contact = contacts.restore("contact_123").await.unwrap();
+        assert_eq!(contact.id, "contact_123");
+        assert_eq!(contact.status, ContactStatus::Active);
+    }
+
     #[tokio::test]
     async fn test_list_contacts() {
         let (mock_server, contacts) = setup().await;
@@ -250,4 +865,295 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_list_stream_parallel_fetches_all_pages() {
+        use wiremock::matchers::query_param;
+
+        let (mock_server, contacts) = setup().await;
+
+        for page in 1..=3 {
+            Mock::given(method("GET"))
+                .and(path("/api/v1/contact-lists/list_123/contacts"))
+                .and(query_param("page", page.to_string()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "success": true,
+                    "data": {
+                        "contacts": [
+                            {"id": format!("c{}a", page), "email": "a@example.com", "status": "active"},
+                            {"id": format!("c{}b", page), "email": "b@example.com", "status": "active"}
+                        ],
+                        "pagination": {"page": page, "limit": 2, "total": 6, "totalPages": 3}
+                    }
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let mut ids: Vec<String> = contacts
+            .list_stream_parallel(&ListContactsParams::default(), 2)
+            .map_ok(|contact| contact.id)
+            .try_collect()
+            .await
+            .unwrap();
+        ids.sort();
+        assert_eq!(ids, vec!["c1a", "c1b", "c2a", "c2b", "c3a", "c3b"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_all_follows_has_next_in_order() {
+        use wiremock::matchers::query_param;
+
+        let (mock_server, contacts) = setup().await;
+
+        for page in 1..=2 {
+            Mock::given(method("GET"))
+                .and(path("/api/v1/contact-lists/list_123/contacts"))
+                .and(query_param("page", page.to_string()))
+                .and(query_param("status", "active"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "success": true,
+                    "data": {
+                        "contacts": [
+                            {"id": format!("c{}", page), "email": "a@example.com", "status": "active"}
+                        ],
+                        "pagination": {"page": page, "limit": 1, "total": 2, "totalPages": 0, "hasNext": page < 2}
+                    }
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let params = ListContactsParams {
+            status: Some(crate::types::ContactStatus::Active),
+            ..Default::default()
+        };
+        let ids: Vec<String> = contacts
+            .list_all(&params)
+            .map_ok(|contact| contact.id)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(ids, vec!["c1", "c2"]);
+    }
+
+    #[tokio::test]
+    async fn test_observer_notified_after_mutations() {
+        use std::sync::{Arc, Mutex};
+
+        #[derive(Debug, Default)]
+        struct Recorder(Mutex<Vec<String>>);
+
+        impl ContactObserver for Arc<Recorder> {
+            fn on_contact_created(&self, list_id: &str, contact: &Contact) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(format!("created {} {}", list_id, contact.id));
+            }
+            fn on_contact_deleted(&self, list_id: &str, contact_id: &str) {
+                self.0
+                    .lock()
+                    .unwrap()
+                    .push(format!("deleted {} {}", list_id, contact_id));
+            }
+        }
+
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/contact-lists/list_123/contacts"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"id": "contact_1", "email": "a@example.com", "status": "active"}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/api/v1/contact-lists/list_123/contacts/contact_1"))
+            .respond_with(ResponseTemplate::new(204))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/api/v1/contact-lists/list_123/contacts/missing"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "error": "Contact not found"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let recorder = Arc::new(Recorder::default());
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .contact_observer(recorder.clone());
+        let contacts = Contacts::new(HttpClient::new(config).unwrap(), "list_123");
+
+        contacts
+            .create(&CreateContactParams {
+                email: "a@example.com".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        contacts.delete("contact_1").await.unwrap();
+        assert!(contacts.delete("missing").await.is_err());
+
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            vec!["created list_123 contact_1", "deleted list_123 contact_1"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_import_from_url() {
+        let (mock_server, contacts) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/contact-lists/list_123/contacts/imports"))
+            .and(body_json(serde_json::json!({
+                "sourceUrl": "https://docs.google.com/spreadsheets/d/abc/export?format=csv",
+                "mapping": {"Email Address": "email", "Plan": "customFields.plan"}
+            })))
+            .respond_with(ResponseTemplate::new(202).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"id": "imp_1", "status": "pending", "createdAt": "2024-01-15T10:00:00Z"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mapping = ImportMapping::new()
+            .email("Email Address")
+            .custom_field("plan", "Plan");
+        let import = contacts
+            .import_from_url(
+                "https://docs.google.com/spreadsheets/d/abc/export?format=csv",
+                &mapping,
+            )
+            .await
+            .unwrap();
+        assert_eq!(import.id, "imp_1");
+        assert!(!import.is_complete());
+    }
+
+    #[derive(Default)]
+    struct RecordingProgress(std::sync::Mutex<Vec<String>>);
+
+    impl ProgressReporter for RecordingProgress {
+        fn start(&self, operation: &str, total: Option<u64>) {
+            let event = format!("start {} {:?}", operation, total);
+            self.0.lock().unwrap().push(event);
+        }
+
+        fn inc(&self, delta: u64) {
+            self.0.lock().unwrap().push(format!("inc {}", delta));
+        }
+
+        fn set_position(&self, position: u64) {
+            self.0.lock().unwrap().push(format!("at {}", position));
+        }
+
+        fn finish(&self) {
+            self.0.lock().unwrap().push("finish".to_string());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_import_reports_progress() {
+        let (mock_server, contacts) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path(
+                "/api/v1/contact-lists/list_123/contacts/imports/imp_1",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"id": "imp_1", "status": "processing", "totalRows": 100,
+                         "imported": 30, "skipped": 5, "failed": 5}
+            })))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path(
+                "/api/v1/contact-lists/list_123/contacts/imports/imp_1",
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"id": "imp_1", "status": "completed", "totalRows": 100,
+                         "imported": 90, "skipped": 6, "failed": 4}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let progress = RecordingProgress::default();
+        let options = PollOptions::new().backoff(crate::RetryPolicy::Fixed(
+            std::time::Duration::from_millis(10),
+        ));
+        let import = contacts
+            .wait_for_import("imp_1", &options, &progress)
+            .await
+            .unwrap();
+
+        assert_eq!(import.imported, 90);
+        assert_eq!(
+            *progress.0.lock().unwrap(),
+            [
+                "start import contacts Some(100)",
+                "at 40",
+                "at 100",
+                "finish"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_csv_flattens_custom_fields() {
+        let (mock_server, contacts) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/contact-lists/list_123/custom-fields"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": [{"key": "plan", "type": "text"}, {"key": "seats", "type": "number"}]
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/contact-lists/list_123/contacts"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "contacts": [{
+                        "id": "c_1",
+                        "email": "a@example.com",
+                        "firstName": "Smith, Jo",
+                        "status": "active",
+                        "createdAt": "2024-01-01",
+                        "customFields": {"plan": "pro", "seats": 5, "legacy": "x"}
+                    }],
+                    "pagination": {"page": 1, "limit": 50, "total": 1, "totalPages": 1}
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut out = Vec::new();
+        let options = CsvExportOptions {
+            flatten_custom_fields: true,
+            ..Default::default()
+        };
+        let rows = contacts.export_csv(&mut out, &options).await.unwrap();
+        assert_eq!(rows, 1);
+
+        let csv = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert!(lines[0].ends_with("unsubscribedAt,customFields.plan,customFields.seats"));
+        assert_eq!(
+            lines[1],
+            "c_1,a@example.com,\"Smith, Jo\",,,active,,2024-01-01,,,,pro,5"
+        );
+    }
 }
@@ -1,9 +1,18 @@
-use crate::client::HttpClient;
-use crate::error::Result;
+use crate::client::{path_id, HttpClient};
+use crate::error::{Error, Result};
+use crate::options::RequestOptions;
+use crate::paginator::Paginator;
+use crate::progress::ProgressReporter;
+use crate::response::Response;
 use crate::types::{
-    CancelEmailResult, Email, EmailList, EmailStats, EmailStatsResponse, ListEmailsParams,
-    SendEmailParams, SendEmailResult,
+    BroadcastParams, BroadcastResult, CancelEmailResult, Email, EmailDraft, EmailDraftParams,
+    EmailExportParams, EmailList, EmailStats, EmailStatsParams, EmailStatsResponse,
+    ListEmailsParams, SendEmailParams, SendEmailResult, SendValidation,
 };
+use bytes::Bytes;
+use futures_util::Stream;
+use serde::Serialize;
+use std::borrow::Cow;
 
 /// Emails API resource
 #[derive(Debug, Clone)]
@@ -11,67 +20,1360 @@ pub struct Emails {
     client: HttpClient,
 }
 
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BroadcastRequest<'a> {
+    list_id: &'a str,
+    #[serde(flatten)]
+    params: &'a BroadcastParams,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SendDraftRequest {
+    test_mode: bool,
+}
+
 impl Emails {
     pub fn new(client: HttpClient) -> Self {
         Self { client }
     }
 
-    /// Send an email
-    ///
-    /// Returns the message ID of the sent email.
-    pub async fn send(&self, params: &SendEmailParams) -> Result<SendEmailResult> {
-        self.client.post("/emails", params).await
+    pub(crate) fn client(&self) -> &HttpClient {
+        &self.client
+    }
+
+    /// Return a handle that applies `options` to every request it makes
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self::new(self.client.with_options(options))
+    }
+
+    /// Send an email
+    ///
+    /// Returns the message ID of the sent email.
+    pub async fn send(&self, params: &SendEmailParams) -> Result<SendEmailResult> {
+        let params = &self.prepare(params)?;
+        self.client.throttle_send(&params.from).await;
+        self.client.post("/emails", params).await
+    }
+
+    /// Send an email with per-request options
+    ///
+    /// Pass an idempotency key so that retrying after a network failure
+    /// never results in a duplicate send.
+    pub async fn send_with_options(
+        &self,
+        params: &SendEmailParams,
+        options: &RequestOptions,
+    ) -> Result<SendEmailResult> {
+        let params = &self.prepare(params)?;
+        self.client.throttle_send(&params.from).await;
+        self.client
+            .post_with_options("/emails", params, options)
+            .await
+    }
+
+    /// Send each email in `emails` in turn, reporting every attempt to `progress`
+    ///
+    /// A failed send does not stop the run; results line up with `emails`.
+    /// Sends go through the same validation and per-domain pacing as
+    /// [`send`](Self::send).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze, batch: Vec<mailbreeze::SendEmailParams>) {
+    /// use mailbreeze::LogProgress;
+    ///
+    /// let results = client.emails.send_bulk(&batch, &LogProgress::default()).await;
+    /// let failed = results.iter().filter(|result| result.is_err()).count();
+    /// println!("{} of {} sends failed", failed, batch.len());
+    /// # }
+    /// ```
+    pub async fn send_bulk(
+        &self,
+        emails: &[SendEmailParams],
+        progress: &dyn ProgressReporter,
+    ) -> Vec<Result<SendEmailResult>> {
+        progress.start("send emails", Some(emails.len() as u64));
+        let mut results = Vec::with_capacity(emails.len());
+        for params in emails {
+            results.push(self.send(params).await);
+            progress.inc(1);
+        }
+        progress.finish();
+        results
+    }
+
+    /// Send an email, keeping the response envelope's warnings and request ID
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze, params: mailbreeze::SendEmailParams) -> mailbreeze::Result<()> {
+    /// let response = client.emails.send_with_meta(&params).await?;
+    /// for warning in &response.warnings {
+    ///     eprintln!("send {}: {}", response.data.message_id, warning);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_with_meta(
+        &self,
+        params: &SendEmailParams,
+    ) -> Result<Response<SendEmailResult>> {
+        let params = &self.prepare(params)?;
+        self.client.throttle_send(&params.from).await;
+        self.client
+            .post_with_meta("/emails", params, &RequestOptions::default())
+            .await
+    }
+
+    /// Send one email to every active contact in a list, or in one of its segments
+    ///
+    /// The API expands the list and sends in the background; the result
+    /// reports how many contacts the broadcast targets. The sender and
+    /// headers are checked with [`BroadcastParams::validate`] first, and
+    /// `ClientConfig.sandbox` applies as it does to [`send`](Self::send).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// use mailbreeze::BroadcastParams;
+    ///
+    /// let broadcast = client
+    ///     .emails
+    ///     .send_to_list(
+    ///         "list_newsletter",
+    ///         &BroadcastParams {
+    ///             from: "news@example.com".to_string(),
+    ///             template_id: Some("tmpl_june_digest".to_string()),
+    ///             ..Default::default()
+    ///         },
+    ///     )
+    ///     .await?;
+    /// println!("broadcast {} to {} contacts", broadcast.id, broadcast.recipient_count);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_to_list(
+        &self,
+        list_id: &str,
+        params: &BroadcastParams,
+    ) -> Result<BroadcastResult> {
+        params.validate()?;
+        let mut params = Cow::Borrowed(params);
+        if self.client.config().sandbox && params.test_mode.is_none() {
+            params.to_mut().test_mode = Some(true);
+        }
+        self.client.throttle_send(&params.from).await;
+        self.client
+            .post(
+                "/emails/broadcast",
+                &BroadcastRequest {
+                    list_id,
+                    params: &params,
+                },
+            )
+            .await
+    }
+
+    /// Check `params` against the server and render it without sending
+    ///
+    /// Reports template variable errors and spam indicators that local
+    /// validation cannot see. The payload goes through the same local checks
+    /// as [`send`](Self::send) first; no email is sent and no credits are used.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze, params: mailbreeze::SendEmailParams) -> mailbreeze::Result<()> {
+    /// let check = client.emails.validate(&params).await?;
+    /// if !check.missing_variables.is_empty() {
+    ///     eprintln!("template needs: {}", check.missing_variables.join(", "));
+    /// }
+    /// for indicator in &check.spam_indicators {
+    ///     eprintln!("spam +{}: {}", indicator.score, indicator.description);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn validate(&self, params: &SendEmailParams) -> Result<SendValidation> {
+        let params = &self.prepare(params)?;
+        self.client.post("/emails/validate", params).await
+    }
+
+    /// Validate the parameters and custom headers, enforce
+    /// `ClientConfig.max_recipients`, apply `ClientConfig.sandbox`, compile
+    /// `mjml`, insert the preheader and write threading and `unsubscribe`
+    /// headers before anything is sent
+    fn prepare<'a>(&self, params: &'a SendEmailParams) -> Result<Cow<'a, SendEmailParams>> {
+        params.validate()?;
+        self.check_recipient_limit(params.recipient_count())?;
+        let sandbox = self.client.config().sandbox && params.test_mode.is_none();
+        let html = params
+            .mjml
+            .as_deref()
+            .map(crate::mjml::compile)
+            .transpose()?;
+        let inject_preheader =
+            params.preheader.is_some() && (html.is_some() || params.html.is_some());
+        let extra_headers = params.unsubscribe.is_some()
+            || params.in_reply_to.is_some()
+            || params.references.is_some();
+        if !sandbox && html.is_none() && !inject_preheader && !extra_headers {
+            if let Some(headers) = &params.headers {
+                headers.validate()?;
+            }
+            return Ok(Cow::Borrowed(params));
+        }
+        let mut params = params.clone();
+        if html.is_some() {
+            params.html = html;
+            params.mjml = None;
+        }
+        if inject_preheader {
+            let preheader = params.preheader.take().unwrap_or_default();
+            params.html = params
+                .html
+                .as_deref()
+                .map(|html| crate::preheader::inject(html, &preheader));
+        }
+        if sandbox {
+            params.test_mode = Some(true);
+        }
+        if extra_headers {
+            let mut headers = params.headers.take().unwrap_or_default();
+            if let Some(message_id) = params.in_reply_to.take() {
+                headers = headers.in_reply_to(&message_id);
+            }
+            if let Some(references) = params.references.take() {
+                headers = headers.references(&references);
+            }
+            if let Some(unsubscribe) = params.unsubscribe.take() {
+                for (name, value) in unsubscribe.to_headers().iter() {
+                    headers.insert(name, value);
+                }
+            }
+            params.headers = Some(headers);
+        }
+        if let Some(headers) = &params.headers {
+            headers.validate()?;
+        }
+        Ok(Cow::Owned(params))
+    }
+
+    /// Enforce `ClientConfig.max_recipients` on an email with `count` recipients
+    fn check_recipient_limit(&self, count: usize) -> Result<()> {
+        match self.client.config().max_recipients {
+            Some(limit) if count > limit => Err(Error::PayloadTooLarge {
+                message: format!("email has {} recipients, limit is {}", count, limit),
+                size: count,
+                limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Get an email by ID
+    pub async fn get(&self, id: &str) -> Result<Email> {
+        self.client.get(&format!("/emails/{}", path_id(id)?)).await
+    }
+
+    /// Get an email by ID, keeping the response envelope metadata
+    pub async fn get_with_meta(&self, id: &str) -> Result<Response<Email>> {
+        self.client
+            .get_with_meta(&format!("/emails/{}", path_id(id)?))
+            .await
+    }
+
+    /// List emails with optional filters
+    pub async fn list(&self, params: &ListEmailsParams) -> Result<EmailList> {
+        self.client.get_with_params("/emails", params).await
+    }
+
+    /// Stream every email matching `params`, one page at a time, in order
+    ///
+    /// `params.page` is ignored; paging starts from the first page.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use futures_util::TryStreamExt;
+    /// use mailbreeze::{EmailStatus, ListEmailsParams};
+    ///
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// let mut emails = client.emails.list_all(&ListEmailsParams::default());
+    /// let mut bounced = 0;
+    /// while let Some(email) = emails.try_next().await? {
+    ///     if email.status == EmailStatus::Bounced {
+    ///         bounced += 1;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_all(&self, params: &ListEmailsParams) -> Paginator<Email> {
+        let emails = self.clone();
+        let params = params.clone();
+        Paginator::new(move |page| {
+            let emails = emails.clone();
+            let params = ListEmailsParams {
+                page: Some(page),
+                ..params.clone()
+            };
+            async move { emails.list(&params).await }
+        })
+    }
+
+    /// Stream the full send log matching `params` as CSV or NDJSON
+    ///
+    /// Not subject to list pagination limits. The export is read over a
+    /// single request and chunks are handed over as they arrive, so the file
+    /// is never held in memory. The client's `timeout` covers the whole body;
+    /// raise it for large exports with
+    /// `client.emails.with_options(RequestOptions::new().timeout(..))`.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// use futures_util::TryStreamExt;
+    /// use mailbreeze::{EmailExportParams, ExportFormat, RangeFilter};
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// let params = EmailExportParams {
+    ///     format: ExportFormat::Ndjson,
+    ///     created_at: Some(RangeFilter::between("2024-01-01", "2024-03-31")),
+    ///     ..Default::default()
+    /// };
+    /// let mut file = tokio::fs::File::create("emails-q1.ndjson").await?;
+    /// let mut export = std::pin::pin!(client.emails.export(&params));
+    /// while let Some(chunk) = export.try_next().await? {
+    ///     file.write_all(&chunk).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn export(&self, params: &EmailExportParams) -> impl Stream<Item = Result<Bytes>> + '_ {
+        self.client.get_stream_with_params("/emails/export", params)
+    }
+
+    /// Get email statistics
+    pub async fn stats(&self) -> Result<EmailStats> {
+        let response: EmailStatsResponse = self.client.get("/emails/stats").await?;
+        Ok(response.stats)
+    }
+
+    /// Get email statistics for a date range, optionally as a time series
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// use mailbreeze::{EmailStatsGrouping, EmailStatsParams};
+    ///
+    /// let stats = client
+    ///     .emails
+    ///     .stats_with_params(&EmailStatsParams {
+    ///         from: Some("2024-06-01".to_string()),
+    ///         to: Some("2024-06-30".to_string()),
+    ///         group_by: Some(EmailStatsGrouping::Week),
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    /// for week in &stats.series {
+    ///     println!("{}: {} sent, {:.1}% success", week.period, week.sent, week.success_rate);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stats_with_params(&self, params: &EmailStatsParams) -> Result<EmailStats> {
+        let response: EmailStatsResponse =
+            self.client.get_with_params("/emails/stats", params).await?;
+        Ok(response.stats)
+    }
+
+    /// Cancel a pending email
+    pub async fn cancel(&self, id: &str) -> Result<CancelEmailResult> {
+        self.client
+            .post_empty(&format!("/emails/{}/cancel", path_id(id)?))
+            .await
+    }
+
+    /// Start a draft that can be filled in over several calls before sending
+    pub async fn create_draft(&self, params: &EmailDraftParams) -> Result<EmailDraft> {
+        self.client.post("/emails/drafts", params).await
+    }
+
+    /// Get a draft by ID
+    pub async fn get_draft(&self, id: &str) -> Result<EmailDraft> {
+        self.client
+            .get(&format!("/emails/drafts/{}", path_id(id)?))
+            .await
+    }
+
+    /// Update a draft; fields left as `None` keep their current value
+    pub async fn update_draft(&self, id: &str, params: &EmailDraftParams) -> Result<EmailDraft> {
+        self.client
+            .patch(&format!("/emails/drafts/{}", path_id(id)?), params)
+            .await
+    }
+
+    /// Send a draft, consuming it
+    ///
+    /// With `ClientConfig.max_recipients` or a per-domain send rate set, the
+    /// draft is fetched first: it is refused if it has too many recipients,
+    /// and paced by its `from` domain like [`send`](Self::send). In
+    /// `ClientConfig.sandbox` the draft is sent in test mode.
+    pub async fn send_draft(&self, id: &str) -> Result<SendEmailResult> {
+        let path = format!("/emails/drafts/{}/send", path_id(id)?);
+        if self.client.config().max_recipients.is_some() || self.client.throttles_sends() {
+            let draft = self.get_draft(id).await?;
+            self.check_recipient_limit(draft.recipient_count())?;
+            if let Some(from) = &draft.from {
+                self.client.throttle_send(from).await;
+            }
+        }
+        if self.client.config().sandbox {
+            self.client
+                .post(&path, &SendDraftRequest { test_mode: true })
+                .await
+        } else {
+            self.client.post_empty(&path).await
+        }
+    }
+
+    /// Discard a draft without sending it
+    pub async fn delete_draft(&self, id: &str) -> Result<()> {
+        self.client
+            .delete(&format!("/emails/drafts/{}", path_id(id)?))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClientConfig;
+    use crate::headers::{AutoSubmitted, Headers, UnsubscribeConfig};
+    use crate::types::{AttachmentContent, InlineAttachment};
+    use wiremock::matchers::{body_partial_json, header, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn setup() -> (MockServer, Emails) {
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+        let emails = Emails::new(client);
+        (mock_server, emails)
+    }
+
+    #[tokio::test]
+    async fn test_send_email() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "messageId": "msg_123abc"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let params = SendEmailParams {
+            from: "sender@example.com".to_string(),
+            to: vec!["recipient@example.com".to_string()],
+            subject: Some("Hello".to_string()),
+            html: Some("<p>Hello!</p>".to_string()),
+            ..Default::default()
+        };
+
+        let result = emails.send(&params).await.unwrap();
+        assert_eq!(result.message_id, "msg_123abc");
+    }
+
+    #[tokio::test]
+    async fn test_send_with_meta_keeps_envelope_fields() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(
+                ResponseTemplate::new(201)
+                    .insert_header("Deprecation", "true")
+                    .set_body_json(serde_json::json!({
+                        "success": true,
+                        "data": {"messageId": "msg_123abc"},
+                        "warnings": [
+                            "tags are ignored on transactional sends",
+                            {"code": "NEAR_QUOTA", "message": "90% of daily quota used"}
+                        ],
+                        "requestId": "req_env_1",
+                        "quotaRemaining": 120
+                    })),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let params = SendEmailParams {
+            from: "sender@example.com".to_string(),
+            to: vec!["recipient@example.com".to_string()],
+            subject: Some("Hello".to_string()),
+            ..Default::default()
+        };
+        let response = emails.send_with_meta(&params).await.unwrap();
+
+        assert_eq!(response.data.message_id, "msg_123abc");
+        assert_eq!(
+            response.warnings,
+            [
+                "tags are ignored on transactional sends",
+                "90% of daily quota used"
+            ]
+        );
+        assert_eq!(response.meta.status, 201);
+        assert_eq!(response.meta.request_id.as_deref(), Some("req_env_1"));
+        assert_eq!(response.deprecation.unwrap().path, "/emails");
+        assert_eq!(response.extra["quotaRemaining"], 120);
+        assert!(!response.extra.contains_key("data"));
+    }
+
+    #[tokio::test]
+    async fn test_send_bulk_keeps_going_after_failure() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(body_partial_json(serde_json::json!({"to": ["bad"]})))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "success": false,
+                "error": {"code": "VALIDATION_ERROR", "message": "invalid recipient"}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_ok"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let batch: Vec<SendEmailParams> = ["a@example.com", "bad", "c@example.com"]
+            .iter()
+            .map(|to| SendEmailParams {
+                from: "news@example.com".to_string(),
+                to: vec![to.to_string()],
+                subject: Some("Hi".to_string()),
+                ..Default::default()
+            })
+            .collect();
+        let progress = crate::LogProgress::default();
+        let results = emails.send_bulk(&batch, &progress).await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().message_id, "msg_ok");
+        assert!(results[1].is_err());
+        assert!(results[2].is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_send_to_suppressed_recipient() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(
+                ResponseTemplate::new(400)
+                    .insert_header("X-Request-Id", "req_sup")
+                    .set_body_json(serde_json::json!({
+                        "success": false,
+                        "error": "Recipient is suppressed",
+                        "code": "RECIPIENT_SUPPRESSED",
+                        "email": "gone@example.com",
+                        "reason": "unsubscribed"
+                    })),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let params = SendEmailParams {
+            from: "news@example.com".to_string(),
+            to: vec!["gone@example.com".to_string()],
+            subject: Some("Hi".to_string()),
+            ..Default::default()
+        };
+        let error = emails.send(&params).await.unwrap_err();
+
+        match &error {
+            Error::RecipientSuppressed { email, reason, .. } => {
+                assert_eq!(email, "gone@example.com");
+                assert_eq!(*reason, crate::types::SuppressReason::Unsubscribed);
+            }
+            other => panic!("expected RecipientSuppressed, got {:?}", other),
+        }
+        assert_eq!(error.status_code(), Some(400));
+        assert_eq!(error.request_id(), Some("req_sup"));
+        assert_eq!(
+            error.to_string(),
+            "Recipient gone@example.com is suppressed (unsubscribed)"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_email_with_reply_tracking() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(body_partial_json(
+                serde_json::json!({"replyTracking": true}),
+            ))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_123abc"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let params = SendEmailParams {
+            from: "support@example.com".to_string(),
+            to: vec!["customer@example.com".to_string()],
+            subject: Some("How did we do?".to_string()),
+            reply_tracking: Some(true),
+            ..Default::default()
+        };
+
+        emails.send(&params).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_send_email_with_tracking_disabled() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(body_partial_json(
+                serde_json::json!({"trackOpens": false, "trackClicks": false}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_123abc"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let params = SendEmailParams {
+            from: "receipts@example.com".to_string(),
+            to: vec!["customer@example.com".to_string()],
+            track_opens: Some(false),
+            track_clicks: Some(false),
+            ..Default::default()
+        };
+
+        emails.send(&params).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_send_writes_unsubscribe_headers() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(body_partial_json(serde_json::json!({
+                "headers": {
+                    "X-Campaign": "spring",
+                    "List-Unsubscribe": "<https://example.com/u/42>",
+                    "List-Unsubscribe-Post": "List-Unsubscribe=One-Click"
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_123abc"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut params = SendEmailParams {
+            from: "news@example.com".to_string(),
+            to: vec!["customer@example.com".to_string()],
+            headers: Some(Headers::new().header("X-Campaign", "spring")),
+            unsubscribe: Some(UnsubscribeConfig::url("https://example.com/u/42").one_click(true)),
+            ..Default::default()
+        };
+        emails.send(&params).await.unwrap();
+
+        params.unsubscribe =
+            Some(UnsubscribeConfig::url("http://example.com/u/42").one_click(true));
+        let error = emails.send(&params).await.unwrap_err();
+        assert_eq!(
+            error.field_errors("unsubscribe"),
+            vec!["oneClick requires an https url"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unsubscribe_is_sent_only_as_headers() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_123abc"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        // As a queued campaign send would arrive
+        let params: SendEmailParams = serde_json::from_value(serde_json::json!({
+            "from": "news@example.com",
+            "to": ["customer@example.com"],
+            "unsubscribe": {"url": "https://example.com/u/42", "oneClick": true}
+        }))
+        .unwrap();
+        emails.send(&params).await.unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let body: serde_json::Value = requests[0].body_json().unwrap();
+        assert!(body.get("unsubscribe").is_none());
+        assert_eq!(
+            body["headers"]["List-Unsubscribe-Post"],
+            "List-Unsubscribe=One-Click"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_email_with_metadata() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(body_partial_json(serde_json::json!({
+                "metadata": {"orderId": "1042", "userId": "u_7"}
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_123abc"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let params = SendEmailParams {
+            from: "receipts@example.com".to_string(),
+            to: vec!["customer@example.com".to_string()],
+            metadata: Some(
+                [("orderId", "1042"), ("userId", "u_7")]
+                    .into_iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            ),
+            ..Default::default()
+        };
+
+        emails.send(&params).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_send_to_list() {
+        use crate::types::BroadcastParams;
+
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails/broadcast"))
+            .and(body_partial_json(serde_json::json!({
+                "listId": "list_123",
+                "segmentId": "seg_vip",
+                "from": "news@example.com",
+                "templateId": "tmpl_1"
+            })))
+            .respond_with(ResponseTemplate::new(202).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"id": "bc_1", "recipientCount": 1250, "status": "queued"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let params = BroadcastParams {
+            from: "news@example.com".to_string(),
+            segment_id: Some("seg_vip".to_string()),
+            template_id: Some("tmpl_1".to_string()),
+            ..Default::default()
+        };
+        let broadcast = emails.send_to_list("list_123", &params).await.unwrap();
+        assert_eq!(broadcast.id, "bc_1");
+        assert_eq!(broadcast.recipient_count, 1250);
+    }
+
+    #[tokio::test]
+    async fn test_send_email_with_inline_attachment() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(body_partial_json(serde_json::json!({
+                "html": "<img src=\"cid:logo\">",
+                "inlineAttachments": [{"attachmentId": "att_123", "contentId": "logo"}]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_123abc"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let logo = InlineAttachment::new("att_123", "logo");
+        let params = SendEmailParams {
+            from: "receipts@example.com".to_string(),
+            to: vec!["customer@example.com".to_string()],
+            html: Some(format!("<img src=\"{}\">", logo.cid())),
+            inline_attachments: Some(vec![logo]),
+            ..Default::default()
+        };
+
+        emails.send(&params).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_send_email_with_attachment_content() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(body_partial_json(serde_json::json!({
+                "attachments": [{
+                    "filename": "invoice.pdf",
+                    "contentType": "application/pdf",
+                    "content": "JVBERi0xLjc="
+                }]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_123abc"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let params = SendEmailParams {
+            from: "billing@example.com".to_string(),
+            to: vec!["customer@example.com".to_string()],
+            attachments: Some(vec![AttachmentContent::new(
+                "invoice.pdf",
+                "application/pdf",
+                b"%PDF-1.7",
+            )]),
+            ..Default::default()
+        };
+
+        emails.send(&params).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_send_validates_params_locally() {
+        let (mock_server, emails) = setup().await;
+
+        let params = SendEmailParams {
+            from: "sender@example.com".to_string(),
+            to: vec!["ok@example.com".to_string(), "not-an-address".to_string()],
+            bcc: Some(vec!["also bad@example.com".to_string()]),
+            template_id: Some("tmpl_1".to_string()),
+            html: Some("<p>Hi</p>".to_string()),
+            ..Default::default()
+        };
+
+        let error = emails.send(&params).await.unwrap_err();
+        assert!(matches!(error, Error::Validation { .. }));
+        assert_eq!(
+            error.field_errors("to[1]"),
+            vec!["must be a valid email address"]
+        );
+        assert_eq!(
+            error.field_errors("bcc[0]"),
+            vec!["must be a valid email address"]
+        );
+        assert_eq!(
+            error.field_errors("html"),
+            vec!["cannot be combined with templateId"]
+        );
+        assert!(error.field_errors("to[0]").is_empty());
+
+        let error = emails
+            .send(&SendEmailParams {
+                from: "sender@example.com".to_string(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(
+            error.field_errors("to"),
+            vec!["must have at least one recipient"]
+        );
+        assert!(mock_server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_validate_on_server() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails/validate"))
+            .and(body_partial_json(serde_json::json!({"templateId": "tmpl_1"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "valid": false,
+                    "errors": [{"field": "variables.firstName", "message": "is required by the template"}],
+                    "missingVariables": ["firstName"],
+                    "spamScore": 2.5,
+                    "spamIndicators": [{"rule": "ALL_CAPS_SUBJECT", "description": "Subject is all capitals", "score": 2.5}]
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let params = SendEmailParams {
+            from: "sender@example.com".to_string(),
+            to: vec!["recipient@example.com".to_string()],
+            template_id: Some("tmpl_1".to_string()),
+            ..Default::default()
+        };
+        let check = emails.validate(&params).await.unwrap();
+        assert!(!check.valid);
+        assert_eq!(check.errors[0].field, "variables.firstName");
+        assert_eq!(check.missing_variables, vec!["firstName"]);
+        assert_eq!(check.spam_indicators[0].rule, "ALL_CAPS_SUBJECT");
+        assert_eq!(check.rendered_html, None);
+    }
+
+    #[cfg(not(feature = "mjml"))]
+    #[tokio::test]
+    async fn test_send_mjml_without_feature_is_rejected() {
+        let (mock_server, emails) = setup().await;
+
+        let params = SendEmailParams {
+            from: "sender@example.com".to_string(),
+            to: vec!["recipient@example.com".to_string()],
+            mjml: Some("<mjml><mj-body></mj-body></mjml>".to_string()),
+            ..Default::default()
+        };
+        let error = emails.send(&params).await.unwrap_err();
+        assert_eq!(
+            error.field_errors("mjml"),
+            vec!["requires the `mjml` feature"]
+        );
+        assert!(mock_server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[cfg(feature = "mjml")]
+    #[tokio::test]
+    async fn test_send_compiles_mjml() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_123abc"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let params = SendEmailParams {
+            from: "sender@example.com".to_string(),
+            to: vec!["recipient@example.com".to_string()],
+            mjml: Some("<mjml><mj-body><mj-text>Shipped</mj-text></mj-body></mjml>".to_string()),
+            ..Default::default()
+        };
+        emails.send(&params).await.unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let body: serde_json::Value = requests[0].body_json().unwrap();
+        let html = body["html"].as_str().unwrap();
+        assert!(html.starts_with("<!doctype html>") && html.contains("Shipped"));
+        assert!(body.get("mjml").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_send_inserts_preheader_into_html() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_123abc"}
+            })))
+            .expect(2)
+            .mount(&mock_server)
+            .await;
+
+        let mut params = SendEmailParams {
+            from: "sender@example.com".to_string(),
+            to: vec!["recipient@example.com".to_string()],
+            preheader: Some("Arrives Tuesday".to_string()),
+            html: Some("<body><p>Shipped</p></body>".to_string()),
+            ..Default::default()
+        };
+        emails.send(&params).await.unwrap();
+        params.html = None;
+        params.template_id = Some("tmpl_1".to_string());
+        emails.send(&params).await.unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let body: serde_json::Value = requests[0].body_json().unwrap();
+        let html = body["html"].as_str().unwrap();
+        assert!(html.starts_with("<body><div style=\"display:none"));
+        assert!(html.contains("Arrives Tuesday"));
+        assert!(body.get("preheader").is_none());
+
+        // Templates are rendered by the API, so the preheader is passed through
+        let body: serde_json::Value = requests[1].body_json().unwrap();
+        assert_eq!(body["preheader"], "Arrives Tuesday");
+    }
+
+    #[tokio::test]
+    async fn test_send_rejects_too_many_recipients() {
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .max_recipients(2);
+        let emails = Emails::new(HttpClient::new(config).unwrap());
+
+        let params = SendEmailParams {
+            from: "sender@example.com".to_string(),
+            to: vec!["a@example.com".to_string(), "b@example.com".to_string()],
+            cc: Some(vec!["c@example.com".to_string()]),
+            ..Default::default()
+        };
+
+        let error = emails.send(&params).await.unwrap_err();
+        assert!(matches!(
+            error,
+            Error::PayloadTooLarge {
+                size: 3,
+                limit: 2,
+                ..
+            }
+        ));
+        assert!(mock_server.received_requests().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_reply_in_thread() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(body_partial_json(serde_json::json!({
+                "headers": {
+                    "In-Reply-To": "<m2@mail.example.com>",
+                    "References": "<m1@mail.example.com> <m2@mail.example.com>"
+                }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_3", "messageIdHeader": "<m3@mail.example.com>"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let params = SendEmailParams {
+            from: "support@example.com".to_string(),
+            to: vec!["customer@example.com".to_string()],
+            in_reply_to: Some("m2@mail.example.com".to_string()),
+            references: Some(vec![
+                "<m1@mail.example.com>".to_string(),
+                "<m2@mail.example.com>".to_string(),
+            ]),
+            ..Default::default()
+        };
+        let result = emails.send(&params).await.unwrap();
+        assert_eq!(
+            result.message_id_header.as_deref(),
+            Some("<m3@mail.example.com>")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_threading_fields_are_sent_only_as_headers() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_3"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let params = SendEmailParams {
+            from: "support@example.com".to_string(),
+            to: vec!["customer@example.com".to_string()],
+            in_reply_to: Some("<m2@mail.example.com>".to_string()),
+            references: Some(vec!["<m1@mail.example.com>".to_string()]),
+            ..Default::default()
+        };
+        emails.send(&params).await.unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let body: serde_json::Value = requests[0].body_json().unwrap();
+        assert!(body.get("inReplyTo").is_none());
+        assert!(body.get("references").is_none());
+        assert_eq!(body["headers"]["In-Reply-To"], "<m2@mail.example.com>");
+    }
+
+    #[tokio::test]
+    async fn test_send_with_headers() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(body_partial_json(serde_json::json!({
+                "headers": {"Auto-Submitted": "auto-generated", "X-Entity-Ref-ID": "order-7"}
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_123"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut params = SendEmailParams {
+            from: "sender@example.com".to_string(),
+            to: vec!["recipient@example.com".to_string()],
+            headers: Some(
+                Headers::new()
+                    .auto_submitted(AutoSubmitted::AutoGenerated)
+                    .entity_ref_id("order-7"),
+            ),
+            ..Default::default()
+        };
+        emails.send(&params).await.unwrap();
+
+        params.headers = Some(Headers::new().header("Message-ID", "<spoofed@example.com>"));
+        let error = emails.send(&params).await.unwrap_err();
+        assert!(matches!(error, Error::InvalidHeader(_)));
+    }
+
+    #[tokio::test]
+    async fn test_sandbox_sets_test_mode_unless_overridden() {
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .sandbox(true);
+        let emails = Emails::new(HttpClient::new(config).unwrap());
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_123abc"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let mut params = SendEmailParams {
+            from: "sender@example.com".to_string(),
+            to: vec!["recipient@example.com".to_string()],
+            ..Default::default()
+        };
+        emails.send(&params).await.unwrap();
+        params.test_mode = Some(false);
+        emails.send(&params).await.unwrap();
+
+        let requests = mock_server.received_requests().await.unwrap();
+        let test_mode: Vec<serde_json::Value> = requests
+            .iter()
+            .map(|r| r.body_json::<serde_json::Value>().unwrap()["testMode"].clone())
+            .collect();
+        assert_eq!(
+            test_mode,
+            vec![serde_json::json!(true), serde_json::json!(false)]
+        );
     }
 
-    /// Get an email by ID
-    pub async fn get(&self, id: &str) -> Result<Email> {
-        self.client.get(&format!("/emails/{}", id)).await
-    }
+    #[tokio::test]
+    async fn test_send_to_list_is_validated_and_sandboxed() {
+        use crate::types::BroadcastParams;
 
-    /// List emails with optional filters
-    pub async fn list(&self, params: &ListEmailsParams) -> Result<EmailList> {
-        self.client.get_with_params("/emails", params).await
-    }
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .sandbox(true);
+        let emails = Emails::new(HttpClient::new(config).unwrap());
 
-    /// Get email statistics
-    pub async fn stats(&self) -> Result<EmailStats> {
-        let response: EmailStatsResponse = self.client.get("/emails/stats").await?;
-        Ok(response.stats)
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails/broadcast"))
+            .and(body_partial_json(serde_json::json!({"testMode": true})))
+            .respond_with(ResponseTemplate::new(202).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"id": "bc_1", "recipientCount": 3, "status": "queued"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let mut params = BroadcastParams {
+            from: "not-an-address".to_string(),
+            template_id: Some("tmpl_1".to_string()),
+            ..Default::default()
+        };
+        let err = emails.send_to_list("list_123", &params).await.unwrap_err();
+        match err {
+            Error::Validation { errors, .. } => assert!(errors.contains_key("from")),
+            other => panic!("expected validation error, got {:?}", other),
+        }
+
+        params.from = "news@example.com".to_string();
+        params.headers = Some(Headers::new().header("Bcc", "leak@example.com"));
+        let err = emails.send_to_list("list_123", &params).await.unwrap_err();
+        assert!(matches!(err, Error::InvalidHeader(_)));
+
+        params.headers = None;
+        emails.send_to_list("list_123", &params).await.unwrap();
     }
 
-    /// Cancel a pending email
-    pub async fn cancel(&self, id: &str) -> Result<CancelEmailResult> {
-        self.client
-            .post_empty(&format!("/emails/{}/cancel", id))
-            .await
+    #[tokio::test]
+    async fn test_send_draft_applies_sandbox_and_recipient_limit() {
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .sandbox(true)
+            .max_recipients(2);
+        let emails = Emails::new(HttpClient::new(config).unwrap());
+
+        for (id, to) in [
+            ("draft_small", vec!["a@example.com"]),
+            (
+                "draft_big",
+                vec!["a@example.com", "b@example.com", "c@example.com"],
+            ),
+        ] {
+            Mock::given(method("GET"))
+                .and(path(format!("/api/v1/emails/drafts/{}", id)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "success": true,
+                    "data": {"_id": id, "to": to}
+                })))
+                .mount(&mock_server)
+                .await;
+        }
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails/drafts/draft_small/send"))
+            .and(body_partial_json(serde_json::json!({"testMode": true})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_1"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails/drafts/draft_big/send"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&mock_server)
+            .await;
+
+        emails.send_draft("draft_small").await.unwrap();
+        let err = emails.send_draft("draft_big").await.unwrap_err();
+        assert!(matches!(
+            err,
+            Error::PayloadTooLarge {
+                size: 3,
+                limit: 2,
+                ..
+            }
+        ));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::client::ClientConfig;
-    use wiremock::matchers::{method, path};
-    use wiremock::{Mock, MockServer, ResponseTemplate};
+    #[tokio::test]
+    async fn test_send_paces_per_from_domain() {
+        let mock_server = MockServer::start().await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_1"}
+            })))
+            .mount(&mock_server)
+            .await;
+        // 1200/min: one send every 50ms per domain
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .send_rate_per_domain(1200);
+        let emails = Emails::new(HttpClient::new(config).unwrap());
+        let params = |from: &str| SendEmailParams {
+            from: from.to_string(),
+            to: vec!["a@example.com".to_string()],
+            ..Default::default()
+        };
 
-    async fn setup() -> (MockServer, Emails) {
+        let start = std::time::Instant::now();
+        emails.send(&params("a@example.com")).await.unwrap();
+        emails.send(&params("b@other.com")).await.unwrap();
+        emails.send(&params("c@example.com")).await.unwrap();
+        emails.send(&params("d@example.com")).await.unwrap();
+        assert!(start.elapsed() >= std::time::Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_send_draft_paces_per_from_domain() {
         let mock_server = MockServer::start().await;
-        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
-        let client = HttpClient::new(config).unwrap();
-        let emails = Emails::new(client);
-        (mock_server, emails)
+        Mock::given(method("GET"))
+            .and(path("/api/v1/emails/drafts/draft_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"_id": "draft_1", "from": "news@example.com", "to": ["a@example.com"]}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails/drafts/draft_1/send"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_1"}
+            })))
+            .mount(&mock_server)
+            .await;
+        // 1200/min: one send every 50ms per domain
+        let config = ClientConfig::new("test_key")
+            .base_url(mock_server.uri())
+            .send_rate_per_domain(1200);
+        let emails = Emails::new(HttpClient::new(config).unwrap());
+
+        let start = std::time::Instant::now();
+        for _ in 0..3 {
+            emails.send_draft("draft_1").await.unwrap();
+        }
+        assert!(start.elapsed() >= std::time::Duration::from_millis(100));
     }
 
     #[tokio::test]
-    async fn test_send_email() {
+    async fn test_send_email_with_callback_url() {
         let (mock_server, emails) = setup().await;
 
         Mock::given(method("POST"))
             .and(path("/api/v1/emails"))
+            .and(body_partial_json(serde_json::json!({
+                "callbackUrl": "https://hooks.example.com/msg/42"
+            })))
             .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
                 "success": true,
                 "data": {
-                    "messageId": "msg_123abc"
+                    "messageId": "msg_456def"
                 }
             })))
             .mount(&mock_server)
@@ -81,12 +1383,42 @@ mod tests {
             from: "sender@example.com".to_string(),
             to: vec!["recipient@example.com".to_string()],
             subject: Some("Hello".to_string()),
-            html: Some("<p>Hello!</p>".to_string()),
+            text: Some("Hello!".to_string()),
+            callback_url: Some("https://hooks.example.com/msg/42".to_string()),
             ..Default::default()
         };
 
         let result = emails.send(&params).await.unwrap();
-        assert_eq!(result.message_id, "msg_123abc");
+        assert_eq!(result.message_id, "msg_456def");
+    }
+
+    #[tokio::test]
+    async fn test_send_email_with_idempotency_key() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails"))
+            .and(header("Idempotency-Key", "order-42-receipt"))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "messageId": "msg_789ghi"
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let params = SendEmailParams {
+            from: "sender@example.com".to_string(),
+            to: vec!["recipient@example.com".to_string()],
+            subject: Some("Receipt".to_string()),
+            ..Default::default()
+        };
+        let options = RequestOptions::new().idempotency_key("order-42-receipt");
+
+        let result = emails.send_with_options(&params, &options).await.unwrap();
+        assert_eq!(result.message_id, "msg_789ghi");
     }
 
     #[tokio::test]
@@ -115,6 +1447,56 @@ mod tests {
         assert_eq!(email.status, crate::types::EmailStatus::Delivered);
     }
 
+    #[tokio::test]
+    async fn test_get_email_returns_metadata() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/emails/email_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "_id": "email_123",
+                    "from": "sender@example.com",
+                    "to": ["recipient@example.com"],
+                    "status": "delivered",
+                    "createdAt": "2024-01-01T00:00:00Z",
+                    "metadata": {"orderId": "1042"}
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let email = emails.get("email_123").await.unwrap();
+        assert_eq!(email.metadata.unwrap()["orderId"], "1042");
+    }
+
+    #[tokio::test]
+    async fn test_get_email_returns_tracking_settings() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/emails/email_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "_id": "email_123",
+                    "from": "sender@example.com",
+                    "to": ["recipient@example.com"],
+                    "status": "delivered",
+                    "createdAt": "2024-01-01T00:00:00Z",
+                    "trackOpens": false,
+                    "trackClicks": true
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let email = emails.get("email_123").await.unwrap();
+        assert_eq!(email.track_opens, Some(false));
+        assert_eq!(email.track_clicks, Some(true));
+    }
+
     #[tokio::test]
     async fn test_list_emails() {
         let (mock_server, emails) = setup().await;
@@ -139,6 +1521,136 @@ mod tests {
         assert_eq!(result.pagination.total, 2);
     }
 
+    #[tokio::test]
+    async fn test_list_all_follows_pages_in_order() {
+        use futures_util::TryStreamExt;
+        use wiremock::matchers::query_param;
+
+        let (mock_server, emails) = setup().await;
+
+        for page in 1..=2 {
+            Mock::given(method("GET"))
+                .and(path("/api/v1/emails"))
+                .and(query_param("page", page.to_string()))
+                .and(query_param("limit", "1"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "success": true,
+                    "data": {
+                        "emails": [
+                            {"_id": format!("email_{}", page), "from": "a@example.com", "to": ["b@example.com"], "status": "sent", "createdAt": "2024-01-01T00:00:00Z"}
+                        ],
+                        "pagination": {"page": page, "limit": 1, "total": 2, "totalPages": 2, "hasNext": page < 2, "hasPrev": page > 1}
+                    }
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let params = ListEmailsParams {
+            limit: Some(1),
+            ..Default::default()
+        };
+        let ids: Vec<String> = emails
+            .list_all(&params)
+            .map_ok(|email| email.id)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(ids, vec!["email_1", "email_2"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_emails_encodes_multi_value_filters() {
+        use crate::types::EmailStatus;
+
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/emails"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "emails": [],
+                    "pagination": {"page": 2, "limit": 50, "total": 0, "totalPages": 0, "hasNext": false, "hasPrev": true}
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let params = ListEmailsParams {
+            page: Some(2),
+            limit: Some(50),
+            statuses: vec![EmailStatus::Bounced, EmailStatus::Failed],
+            tags: vec!["receipt".to_string(), "q3 promo".to_string()],
+            ..Default::default()
+        };
+        emails.list(&params).await.unwrap();
+
+        // Numbers and enums unquoted, sequences as repeated keys
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(
+            requests[0].url.query(),
+            Some("page=2&limit=50&statuses=bounced&statuses=failed&tags=receipt&tags=q3+promo")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_streams_file_with_filters() {
+        use crate::types::{EmailExportParams, EmailStatus, ExportFormat};
+        use futures_util::TryStreamExt;
+
+        let (mock_server, emails) = setup().await;
+
+        let body = "{\"id\":\"email_1\"}\n{\"id\":\"email_2\"}\n";
+        Mock::given(method("GET"))
+            .and(path("/api/v1/emails/export"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let params = EmailExportParams {
+            format: ExportFormat::Ndjson,
+            statuses: vec![EmailStatus::Bounced],
+            ..Default::default()
+        };
+        let chunks: Vec<Bytes> = emails.export(&params).try_collect().await.unwrap();
+        assert_eq!(chunks.concat(), body.as_bytes());
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(
+            requests[0].url.query(),
+            Some("format=ndjson&statuses=bounced")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_reads_one_response_without_ranges() {
+        use crate::types::EmailExportParams;
+        use futures_util::TryStreamExt;
+
+        let (mock_server, emails) = setup().await;
+
+        let body = "id,status\nemail_1,sent\n";
+        Mock::given(method("GET"))
+            .and(path("/api/v1/emails/export"))
+            .respond_with(ResponseTemplate::new(200).set_body_string(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let chunks: Vec<Bytes> = emails
+            .export(&EmailExportParams::default())
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(chunks.concat(), body.as_bytes());
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert!(!requests[0].headers.contains_key("Range"));
+    }
+
     #[tokio::test]
     async fn test_get_stats() {
         let (mock_server, emails) = setup().await;
@@ -166,6 +1678,52 @@ mod tests {
         assert_eq!(stats.sent, 950);
     }
 
+    #[tokio::test]
+    async fn test_get_stats_time_series() {
+        use crate::types::{EmailStatsGrouping, EmailStatsParams};
+
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/emails/stats"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "stats": {
+                        "total": 30,
+                        "sent": 29,
+                        "failed": 1,
+                        "successRate": 96.7,
+                        "series": [
+                            {"period": "2024-06-03", "total": 10, "sent": 10, "failed": 0, "successRate": 100.0},
+                            {"period": "2024-06-10", "total": 20, "sent": 19, "failed": 1, "successRate": 95.0}
+                        ]
+                    }
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let params = EmailStatsParams {
+            from: Some("2024-06-01".to_string()),
+            to: Some("2024-06-16".to_string()),
+            group_by: Some(EmailStatsGrouping::Week),
+            tags: vec!["newsletter".to_string(), "promo".to_string()],
+            email_type: Some("marketing".to_string()),
+        };
+        let stats = emails.stats_with_params(&params).await.unwrap();
+        assert_eq!(stats.series.len(), 2);
+        assert_eq!(stats.series[1].period, "2024-06-10");
+        assert_eq!(stats.series[1].failed, 1);
+
+        let requests = mock_server.received_requests().await.unwrap();
+        assert_eq!(
+            requests[0].url.query(),
+            Some("from=2024-06-01&to=2024-06-16&group_by=week&tags=newsletter&tags=promo&email_type=marketing")
+        );
+    }
+
     #[tokio::test]
     async fn test_cancel_email() {
         let (mock_server, emails) = setup().await;
@@ -185,4 +1743,71 @@ mod tests {
         let result = emails.cancel("email_123").await.unwrap();
         assert!(result.cancelled);
     }
+
+    #[tokio::test]
+    async fn test_draft_lifecycle() {
+        let (mock_server, emails) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails/drafts"))
+            .and(body_partial_json(
+                serde_json::json!({"subject": "Quarterly report"}),
+            ))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"_id": "draft_1", "subject": "Quarterly report"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("PATCH"))
+            .and(path("/api/v1/emails/drafts/draft_1"))
+            .and(body_partial_json(
+                serde_json::json!({"attachmentIds": ["att_1"]}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "_id": "draft_1",
+                    "subject": "Quarterly report",
+                    "attachmentIds": ["att_1"]
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/emails/drafts/draft_1/send"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"messageId": "msg_789"}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let draft = emails
+            .create_draft(&EmailDraftParams {
+                subject: Some("Quarterly report".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(draft.id, "draft_1");
+
+        let draft = emails
+            .update_draft(
+                &draft.id,
+                &EmailDraftParams {
+                    attachment_ids: Some(vec!["att_1".to_string()]),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(draft.attachment_ids, vec!["att_1"]);
+
+        let sent = emails.send_draft(&draft.id).await.unwrap();
+        assert_eq!(sent.message_id, "msg_789");
+    }
 }
@@ -1,7 +1,10 @@
-use crate::client::HttpClient;
+use crate::client::{path_id, HttpClient};
 use crate::error::Result;
+use crate::options::RequestOptions;
+use crate::paginator::Paginator;
 use crate::types::{
-    CreateListParams, List, ListListsParams, ListStats, ListsResponse, Pagination, UpdateListParams,
+    CreateListParams, List, ListListsParams, ListSources, ListSourcesParams, ListStats,
+    ListsResponse, Pagination, UpdateListParams,
 };
 
 /// Contact lists API resource
@@ -39,6 +42,11 @@ impl Lists {
         Self { client }
     }
 
+    /// Return a handle that applies `options` to every request it makes
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self::new(self.client.with_options(options))
+    }
+
     /// Create a new contact list
     pub async fn create(&self, params: &CreateListParams) -> Result<List> {
         self.client.post("/contact-lists", params).await
@@ -46,47 +54,104 @@ impl Lists {
 
     /// Get a contact list by ID
     pub async fn get(&self, id: &str) -> Result<List> {
-        self.client.get(&format!("/contact-lists/{}", id)).await
+        self.client
+            .get(&format!("/contact-lists/{}", path_id(id)?))
+            .await
+    }
+
+    /// Get a contact list with its `ETag`, for use with [`Lists::delete_if_match`]
+    pub async fn get_with_etag(&self, id: &str) -> Result<(List, Option<String>)> {
+        self.client
+            .get_with_etag(&format!("/contact-lists/{}", path_id(id)?))
+            .await
     }
 
     /// Update a contact list
     pub async fn update(&self, id: &str, params: &UpdateListParams) -> Result<List> {
         self.client
-            .put(&format!("/contact-lists/{}", id), params)
+            .put(&format!("/contact-lists/{}", path_id(id)?), params)
             .await
     }
 
     /// Delete a contact list
     pub async fn delete(&self, id: &str) -> Result<()> {
-        self.client.delete(&format!("/contact-lists/{}", id)).await
+        self.client
+            .delete(&format!("/contact-lists/{}", path_id(id)?))
+            .await
+    }
+
+    /// Delete a contact list only if it still has `etag`
+    ///
+    /// A retried delete then can't remove a list that was recreated or modified
+    /// in between; that case fails with [`Error::PreconditionFailed`](crate::Error::PreconditionFailed).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// let (list, etag) = client.lists.get_with_etag("list_123").await?;
+    /// if let Some(etag) = etag {
+    ///     client.lists.delete_if_match(&list.id, &etag).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_if_match(&self, id: &str, etag: &str) -> Result<()> {
+        self.with_options(RequestOptions::new().if_match(etag))
+            .delete(id)
+            .await
     }
 
     /// List all contact lists with optional filters
     ///
-    /// Note: The current API returns a flat array. Pagination may be added in future versions.
+    /// Note: The current API returns a flat array without pagination metadata,
+    /// so `has_next` is set whenever the page came back full.
     pub async fn list(&self, params: &ListListsParams) -> Result<ListsResponse> {
         // API returns data as a direct array, not wrapped in {lists: [...]}
         let lists: Vec<List> = self
             .client
             .get_with_params("/contact-lists", params)
             .await?;
+        let limit = params.limit.unwrap_or(50);
         Ok(ListsResponse {
-            lists,
             pagination: Pagination {
                 page: params.page.unwrap_or(1),
-                limit: params.limit.unwrap_or(50),
+                limit,
                 total: 0, // API doesn't return total count currently
                 total_pages: 0,
-                has_next: false,
+                has_next: lists.len() >= limit.max(1) as usize,
                 has_prev: false,
             },
+            lists,
+        })
+    }
+
+    /// Stream every contact list, one page at a time, in order
+    ///
+    /// `params.page` is ignored; paging starts from the first page.
+    pub fn list_all(&self, params: &ListListsParams) -> Paginator<List> {
+        let lists = self.clone();
+        let params = params.clone();
+        Paginator::new(move |page| {
+            let lists = lists.clone();
+            let params = ListListsParams {
+                page: Some(page),
+                ..params.clone()
+            };
+            async move { lists.list(&params).await }
         })
     }
 
     /// Get contact list statistics
     pub async fn stats(&self, id: &str) -> Result<ListStats> {
         self.client
-            .get(&format!("/contact-lists/{}/stats", id))
+            .get(&format!("/contact-lists/{}/stats", path_id(id)?))
+            .await
+    }
+
+    /// Count contacts by acquisition source (api, import, form, ...) over time
+    pub async fn sources(&self, id: &str, params: &ListSourcesParams) -> Result<ListSources> {
+        self.client
+            .get_with_params(&format!("/contact-lists/{}/sources", path_id(id)?), params)
             .await
     }
 }
@@ -95,7 +160,7 @@ impl Lists {
 mod tests {
     use super::*;
     use crate::client::ClientConfig;
-    use wiremock::matchers::{method, path};
+    use wiremock::matchers::{header, method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     async fn setup() -> (MockServer, Lists) {
@@ -138,6 +203,39 @@ mod tests {
         assert_eq!(list.name, "Newsletter");
     }
 
+    #[tokio::test]
+    async fn test_list_all_reads_until_a_short_page() {
+        use futures_util::TryStreamExt;
+
+        let (mock_server, lists) = setup().await;
+
+        for (page, names) in [("1", vec!["A", "B"]), ("2", vec!["C"])] {
+            let data: Vec<_> = names
+                .iter()
+                .map(|name| serde_json::json!({"id": format!("list_{}", name), "name": name}))
+                .collect();
+            Mock::given(method("GET"))
+                .and(path("/api/v1/contact-lists"))
+                .and(query_param("page", page))
+                .and(query_param("limit", "2"))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "success": true,
+                    "data": data
+                })))
+                .expect(1)
+                .mount(&mock_server)
+                .await;
+        }
+
+        let params = ListListsParams {
+            limit: Some(2),
+            ..Default::default()
+        };
+        let all: Vec<List> = lists.list_all(&params).try_collect().await.unwrap();
+        let names: Vec<&str> = all.iter().map(|list| list.name.as_str()).collect();
+        assert_eq!(names, ["A", "B", "C"]);
+    }
+
     #[tokio::test]
     async fn test_get_list() {
         let (mock_server, lists) = setup().await;
@@ -249,4 +347,70 @@ mod tests {
         assert_eq!(stats.total_contacts, 1000);
         assert_eq!(stats.active_contacts, 900);
     }
+
+    #[tokio::test]
+    async fn test_list_sources() {
+        let (mock_server, lists) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/contact-lists/list_123/sources"))
+            .and(query_param("interval", "month"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "totals": {"api": 120, "form": 45},
+                    "series": [
+                        {"period": "2024-01-01", "counts": {"api": 70, "form": 20}},
+                        {"period": "2024-02-01", "counts": {"api": 50, "form": 25}}
+                    ]
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let params = ListSourcesParams {
+            interval: Some(crate::types::StatsInterval::Month),
+            ..Default::default()
+        };
+        let sources = lists.sources("list_123", &params).await.unwrap();
+        assert_eq!(sources.totals["api"], 120);
+        assert_eq!(sources.series.len(), 2);
+        assert_eq!(sources.series[1].counts["form"], 25);
+    }
+
+    #[tokio::test]
+    async fn test_delete_if_match_precondition_failed() {
+        let (mock_server, lists) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/contact-lists/list_123"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("ETag", "\"v2\"")
+                    .set_body_json(serde_json::json!({
+                        "success": true,
+                        "data": {"id": "list_123", "name": "Newsletter"}
+                    })),
+            )
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("DELETE"))
+            .and(path("/api/v1/contact-lists/list_123"))
+            .and(header("If-Match", "\"v2\""))
+            .respond_with(ResponseTemplate::new(412).set_body_json(serde_json::json!({
+                "error": "List was modified"
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let (list, etag) = lists.get_with_etag("list_123").await.unwrap();
+        assert_eq!(etag.as_deref(), Some("\"v2\""));
+
+        let error = lists
+            .delete_if_match(&list.id, &etag.unwrap())
+            .await
+            .unwrap_err();
+        assert!(matches!(error, crate::Error::PreconditionFailed { .. }));
+        assert_eq!(error.status_code(), Some(412));
+    }
 }
@@ -1,11 +1,27 @@
+mod account;
+mod alerts;
 mod attachments;
+mod automations;
+mod branding;
 mod contacts;
 mod emails;
 mod lists;
+mod reply_routes;
+mod suppressions;
+mod templates;
 mod verification;
+mod webhooks;
 
+pub use account::Account;
+pub use alerts::Alerts;
 pub use attachments::Attachments;
+pub use automations::Automations;
+pub use branding::Branding;
 pub use contacts::Contacts;
 pub use emails::Emails;
 pub use lists::Lists;
+pub use reply_routes::ReplyRoutes;
+pub use suppressions::Suppressions;
+pub use templates::Templates;
 pub use verification::Verification;
+pub use webhooks::Webhooks;
@@ -0,0 +1,169 @@
+use crate::client::{path_id, HttpClient};
+use crate::error::Result;
+use crate::options::RequestOptions;
+use crate::types::{CreateReplyRouteParams, ReplyRoute, UpdateReplyRouteParams};
+
+/// Reply routing rules API resource
+///
+/// Replies to transactional mail are dropped unless a route matches them. A
+/// route matches on the address the reply was sent to or on the tag of the
+/// original email, and either forwards the reply to a mailbox or posts it to
+/// a webhook URL.
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::{CreateReplyRouteParams, MailBreeze, ReplyAction};
+///
+/// #[tokio::main]
+/// async fn main() -> mailbreeze::Result<()> {
+///     let client = MailBreeze::new("your_api_key")?;
+///
+///     // Replies to receipts go to the support inbox
+///     client.reply_routes.create(&CreateReplyRouteParams::for_tag(
+///         "receipt",
+///         ReplyAction::Forward { to: "support@example.com".to_string() },
+///     )).await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ReplyRoutes {
+    client: HttpClient,
+}
+
+impl ReplyRoutes {
+    pub fn new(client: HttpClient) -> Self {
+        Self { client }
+    }
+
+    /// Return a handle that applies `options` to every request it makes
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self::new(self.client.with_options(options))
+    }
+
+    /// Create a reply route
+    pub async fn create(&self, params: &CreateReplyRouteParams) -> Result<ReplyRoute> {
+        self.client.post("/reply-routes", params).await
+    }
+
+    /// Get a reply route by ID
+    pub async fn get(&self, id: &str) -> Result<ReplyRoute> {
+        self.client
+            .get(&format!("/reply-routes/{}", path_id(id)?))
+            .await
+    }
+
+    /// Update a reply route
+    pub async fn update(&self, id: &str, params: &UpdateReplyRouteParams) -> Result<ReplyRoute> {
+        self.client
+            .put(&format!("/reply-routes/{}", path_id(id)?), params)
+            .await
+    }
+
+    /// Delete a reply route
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        self.client
+            .delete(&format!("/reply-routes/{}", path_id(id)?))
+            .await
+    }
+
+    /// List all reply routes
+    pub async fn list(&self) -> Result<Vec<ReplyRoute>> {
+        self.client.get("/reply-routes").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClientConfig;
+    use crate::types::ReplyAction;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn setup() -> (MockServer, ReplyRoutes) {
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+        let reply_routes = ReplyRoutes::new(client);
+        (mock_server, reply_routes)
+    }
+
+    #[tokio::test]
+    async fn test_create_address_route_to_webhook() {
+        let (mock_server, reply_routes) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/reply-routes"))
+            .and(body_json(serde_json::json!({
+                "address": "no-reply@example.com",
+                "action": {"type": "webhook", "url": "https://app.example.com/replies"}
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "_id": "rr_123",
+                    "address": "no-reply@example.com",
+                    "action": {"type": "webhook", "url": "https://app.example.com/replies"},
+                    "enabled": true,
+                    "createdAt": "2024-01-01T00:00:00Z"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let route = reply_routes
+            .create(&CreateReplyRouteParams::for_address(
+                "no-reply@example.com",
+                ReplyAction::Webhook {
+                    url: "https://app.example.com/replies".to_string(),
+                },
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(route.id, "rr_123");
+        assert_eq!(route.tag, None);
+        assert!(matches!(route.action, ReplyAction::Webhook { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_update_route_action() {
+        let (mock_server, reply_routes) = setup().await;
+
+        Mock::given(method("PUT"))
+            .and(path("/api/v1/reply-routes/rr_123"))
+            .and(body_json(serde_json::json!({
+                "action": {"type": "forward", "to": "support@example.com"},
+                "enabled": false
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "id": "rr_123",
+                    "tag": "receipt",
+                    "action": {"type": "forward", "to": "support@example.com"},
+                    "enabled": false
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let route = reply_routes
+            .update(
+                "rr_123",
+                &UpdateReplyRouteParams {
+                    action: Some(ReplyAction::Forward {
+                        to: "support@example.com".to_string(),
+                    }),
+                    enabled: Some(false),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(route.tag.as_deref(), Some("receipt"));
+        assert!(!route.enabled);
+    }
+}
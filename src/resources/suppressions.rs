@@ -0,0 +1,178 @@
+use crate::client::{path_segment, HttpClient};
+use crate::error::{Error, Result};
+use crate::options::RequestOptions;
+use crate::types::{AddSuppressionParams, SuppressReason, Suppression};
+use crate::webhooks::{WebhookEvent, WebhookPayload};
+
+/// Account-wide suppression list API resource
+///
+/// Suppressed addresses are never sent to, regardless of which list they are on.
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::{MailBreeze, WebhookEvent};
+///
+/// # async fn handle(client: MailBreeze, body: &[u8]) -> mailbreeze::Result<()> {
+/// let event = WebhookEvent::from_slice(body)?;
+/// client.suppressions.add_from_event(&event).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Suppressions {
+    client: HttpClient,
+}
+
+impl Suppressions {
+    pub fn new(client: HttpClient) -> Self {
+        Self { client }
+    }
+
+    /// Return a handle that applies `options` to every request it makes
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self::new(self.client.with_options(options))
+    }
+
+    /// Add an address to the suppression list
+    pub async fn add(&self, params: &AddSuppressionParams) -> Result<Suppression> {
+        self.client.post("/suppressions", params).await
+    }
+
+    /// Suppress the recipient of a hard bounce or complaint webhook event
+    ///
+    /// Returns [`Error::UnsupportedEvent`] for soft bounces and any other event type.
+    pub async fn add_from_event(&self, event: &WebhookEvent) -> Result<Suppression> {
+        let params = AddSuppressionParams::from_event(event)?;
+        self.add(&params).await
+    }
+
+    /// Remove an address from the suppression list
+    pub async fn remove(&self, email: &str) -> Result<()> {
+        self.client
+            .delete(&format!("/suppressions/{}", path_segment(email)?))
+            .await
+    }
+}
+
+impl AddSuppressionParams {
+    /// Extract the address and reason from a bounce or complaint event
+    pub fn from_event(event: &WebhookEvent) -> Result<Self> {
+        let unsupported = |message: &str| Error::UnsupportedEvent {
+            event_type: event.event_type.clone(),
+            message: message.to_string(),
+        };
+
+        let (email, reason) = match &event.payload {
+            WebhookPayload::EmailBounced(bounce) => {
+                if bounce.bounce_type.as_deref() == Some("soft") {
+                    return Err(unsupported("soft bounces are retried, not suppressed"));
+                }
+                (&bounce.email, SuppressReason::Bounced)
+            }
+            WebhookPayload::EmailComplained(data) => (&data.email, SuppressReason::Complained),
+            _ => {
+                return Err(unsupported(
+                    "only bounce and complaint events can be suppressed",
+                ))
+            }
+        };
+
+        if email.is_empty() {
+            return Err(unsupported("event has no recipient address"));
+        }
+
+        Ok(Self {
+            email: email.clone(),
+            reason,
+            source_event_id: Some(event.id.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClientConfig;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn setup() -> (MockServer, Suppressions) {
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+        let suppressions = Suppressions::new(client);
+        (mock_server, suppressions)
+    }
+
+    #[tokio::test]
+    async fn test_add_from_bounce_event() {
+        let (mock_server, suppressions) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/suppressions"))
+            .and(body_json(serde_json::json!({
+                "email": "gone@example.com",
+                "reason": "bounced",
+                "sourceEventId": "evt_1"
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "email": "gone@example.com",
+                    "reason": "bounced",
+                    "createdAt": "2024-01-01T00:00:00Z"
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let event = WebhookEvent::from_slice(
+            br#"{
+                "id": "evt_1",
+                "type": "email.bounced",
+                "data": {"messageId": "msg_1", "email": "gone@example.com", "bounceType": "hard"}
+            }"#,
+        )
+        .unwrap();
+
+        let suppression = suppressions.add_from_event(&event).await.unwrap();
+        assert_eq!(suppression.reason, SuppressReason::Bounced);
+    }
+
+    #[tokio::test]
+    async fn test_remove_encodes_the_address() {
+        let (mock_server, suppressions) = setup().await;
+
+        Mock::given(method("DELETE"))
+            .and(path(
+                "/api/v1/suppressions/jane%2Bnews%2Fq%3F%23@example.com",
+            ))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        suppressions
+            .remove("jane+news/q?#@example.com")
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn test_from_event_rejects_other_events() {
+        let soft = WebhookEvent::from_slice(
+            br#"{"id": "evt_2", "type": "email.bounced", "data": {"messageId": "m", "email": "a@example.com", "bounceType": "soft"}}"#,
+        )
+        .unwrap();
+        let opened = WebhookEvent::from_slice(
+            br#"{"id": "evt_3", "type": "email.opened", "data": {"messageId": "m", "email": "a@example.com"}}"#,
+        )
+        .unwrap();
+
+        for event in [soft, opened] {
+            let error = AddSuppressionParams::from_event(&event).unwrap_err();
+            assert!(matches!(error, Error::UnsupportedEvent { .. }));
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use crate::client::{path_id, HttpClient};
+use crate::error::Result;
+use crate::options::RequestOptions;
+use crate::types::{
+    CreateTemplateParams, ListTemplatesParams, Template, TemplateDiff, TemplatesResponse,
+    UpdateTemplateParams,
+};
+
+/// Templates API resource
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::MailBreeze;
+///
+/// #[tokio::main]
+/// async fn main() -> mailbreeze::Result<()> {
+///     let client = MailBreeze::new("your_api_key")?;
+///
+///     // Reuse a statically generated newsletter page; it is fetched at send time
+///     let template = client.templates.create(&mailbreeze::CreateTemplateParams {
+///         name: "Weekly newsletter".to_string(),
+///         subject: Some("This week at Acme".to_string()),
+///         content_url: Some("https://acme.com/newsletter/latest.html".to_string()),
+///         ..Default::default()
+///     }).await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Templates {
+    client: HttpClient,
+}
+
+impl Templates {
+    pub fn new(client: HttpClient) -> Self {
+        Self { client }
+    }
+
+    /// Return a handle that applies `options` to every request it makes
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self::new(self.client.with_options(options))
+    }
+
+    /// Create a new template
+    pub async fn create(&self, params: &CreateTemplateParams) -> Result<Template> {
+        self.client.post("/templates", params).await
+    }
+
+    /// Get a template by ID
+    pub async fn get(&self, id: &str) -> Result<Template> {
+        self.client
+            .get(&format!("/templates/{}", path_id(id)?))
+            .await
+    }
+
+    /// Get a specific version of a template
+    pub async fn get_version(&self, id: &str, version: i32) -> Result<Template> {
+        self.client
+            .get(&format!("/templates/{}/versions/{}", path_id(id)?, version))
+            .await
+    }
+
+    /// Diff the subject, HTML and text of two versions of a template
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// let diff = client.templates.diff("tmpl_123", 3, 4).await?;
+    /// if diff.has_changes() {
+    ///     println!("{}", diff);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn diff(&self, id: &str, version_a: i32, version_b: i32) -> Result<TemplateDiff> {
+        let (a, b) = tokio::try_join!(
+            self.get_version(id, version_a),
+            self.get_version(id, version_b)
+        )?;
+        Ok(TemplateDiff::between(&a, &b))
+    }
+
+    /// Update a template
+    pub async fn update(&self, id: &str, params: &UpdateTemplateParams) -> Result<Template> {
+        self.client
+            .put(&format!("/templates/{}", path_id(id)?), params)
+            .await
+    }
+
+    /// Delete a template
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        self.client
+            .delete(&format!("/templates/{}", path_id(id)?))
+            .await
+    }
+
+    /// List templates
+    pub async fn list(&self, params: &ListTemplatesParams) -> Result<TemplatesResponse> {
+        self.client.get_with_params("/templates", params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClientConfig;
+    use crate::types::DiffLine;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn setup() -> (MockServer, Templates) {
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+        let templates = Templates::new(client);
+        (mock_server, templates)
+    }
+
+    #[tokio::test]
+    async fn test_create_template_from_url() {
+        let (mock_server, templates) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/templates"))
+            .and(body_json(serde_json::json!({
+                "name": "Newsletter",
+                "contentUrl": "https://example.com/newsletter.html"
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "id": "tmpl_123",
+                    "name": "Newsletter",
+                    "contentUrl": "https://example.com/newsletter.html",
+                    "version": 1,
+                    "createdAt": "2024-01-01T00:00:00Z"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let template = templates
+            .create(&CreateTemplateParams {
+                name: "Newsletter".to_string(),
+                content_url: Some("https://example.com/newsletter.html".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(template.id, "tmpl_123");
+        assert_eq!(
+            template.content_url.as_deref(),
+            Some("https://example.com/newsletter.html")
+        );
+        assert!(template.html.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_diff_fetches_both_versions() {
+        let (mock_server, templates) = setup().await;
+
+        for (version, subject) in [(1, "Hello"), (2, "Hello there")] {
+            Mock::given(method("GET"))
+                .and(path(format!("/api/v1/templates/tmpl_1/versions/{}", version)))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "success": true,
+                    "data": {"id": "tmpl_1", "name": "Welcome", "subject": subject, "version": version}
+                })))
+                .mount(&mock_server)
+                .await;
+        }
+
+        let diff = templates.diff("tmpl_1", 1, 2).await.unwrap();
+        assert_eq!(diff.from_version, 1);
+        assert_eq!(diff.to_version, 2);
+        assert_eq!(
+            diff.subject,
+            vec![
+                DiffLine::Removed("Hello".to_string()),
+                DiffLine::Added("Hello there".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_templates() {
+        let (mock_server, templates) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/templates"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "templates": [
+                        {"id": "tmpl_1", "name": "Welcome", "html": "<p>Hi</p>", "version": 3}
+                    ],
+                    "pagination": {"page": 1, "limit": 10, "total": 1, "totalPages": 1, "hasNext": false, "hasPrev": false}
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let result = templates
+            .list(&ListTemplatesParams::default())
+            .await
+            .unwrap();
+        assert_eq!(result.templates.len(), 1);
+        assert_eq!(result.templates[0].version, 3);
+    }
+}
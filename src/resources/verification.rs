@@ -1,10 +1,15 @@
-use crate::client::HttpClient;
-use crate::error::Result;
+use crate::client::{path_id, HttpClient};
+use crate::error::{Error, Result};
+use crate::options::{PollOptions, RequestOptions};
+use crate::progress::{NoProgress, ProgressReporter};
 use crate::types::{
-    BatchVerificationResult, VerificationListItem, VerificationListResponse, VerificationResult,
-    VerificationStats,
+    AggregateBatchResult, BatchVerificationResult, ChunkFailure, VerificationListItem,
+    VerificationListResponse, VerificationResult, VerificationStats, VerificationStatsParams,
 };
+use bytes::Bytes;
+use futures_util::stream::{self, Stream, StreamExt};
 use serde::Serialize;
+use std::time::Instant;
 
 /// Verification API resource
 #[derive(Debug, Clone)]
@@ -27,6 +32,11 @@ impl Verification {
         Self { client }
     }
 
+    /// Return a handle that applies `options` to every request it makes
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self::new(self.client.with_options(options))
+    }
+
     /// Verify a single email address
     pub async fn verify(&self, email: &str) -> Result<VerificationResult> {
         self.client
@@ -46,13 +56,180 @@ impl Verification {
             .await
     }
 
+    /// Verify a large set of emails as sub-batches of at most `chunk_size`
+    ///
+    /// Every chunk is submitted (each through the normal retry loop), then
+    /// polled with `options` until it finishes. Chunks that fail are recorded in
+    /// [`AggregateBatchResult::failures`] rather than aborting the run, so one
+    /// bad chunk never discards the results of the others.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze, emails: Vec<String>) -> mailbreeze::Result<()> {
+    /// use mailbreeze::PollOptions;
+    ///
+    /// let report = client
+    ///     .verification
+    ///     .batch_chunked(emails, 1_000, &PollOptions::default())
+    ///     .await;
+    /// println!("{:.1}% clean", report.analytics.clean_percentage);
+    /// if !report.is_complete() {
+    ///     let retry = report.failed_emails();
+    ///     println!("{} emails need resubmitting", retry.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn batch_chunked(
+        &self,
+        emails: Vec<String>,
+        chunk_size: usize,
+        options: &PollOptions,
+    ) -> AggregateBatchResult {
+        self.batch_chunked_with_progress(emails, chunk_size, options, &NoProgress)
+            .await
+    }
+
+    /// Like [`batch_chunked`](Self::batch_chunked), reporting emails as their chunk settles
+    ///
+    /// The total is the number of emails; a chunk counts once it completes
+    /// or is recorded as a failure.
+    pub async fn batch_chunked_with_progress(
+        &self,
+        emails: Vec<String>,
+        chunk_size: usize,
+        options: &PollOptions,
+        progress: &dyn ProgressReporter,
+    ) -> AggregateBatchResult {
+        progress.start("verify emails", Some(emails.len() as u64));
+        let mut report = AggregateBatchResult::default();
+        let mut submitted = Vec::new();
+
+        for (chunk, emails) in emails.chunks(chunk_size.max(1)).enumerate() {
+            match self.batch(emails.to_vec()).await {
+                Ok(batch) => submitted.push((chunk, emails.to_vec(), batch)),
+                Err(e) => {
+                    report.add_failure(ChunkFailure {
+                        chunk,
+                        emails: emails.to_vec(),
+                        verification_id: None,
+                        message: e.to_string(),
+                    });
+                    progress.inc(emails.len() as u64);
+                }
+            }
+        }
+
+        for (chunk, emails, batch) in submitted {
+            let settled = emails.len() as u64;
+            let verification_id = batch.verification_id.clone();
+            let finished = if batch.is_complete() {
+                Ok(batch)
+            } else {
+                self.wait_for_completion(&verification_id, options).await
+            };
+            match finished {
+                Ok(batch) if batch.status == "completed" => report.add_batch(batch),
+                Ok(batch) => report.add_failure(ChunkFailure {
+                    chunk,
+                    emails,
+                    verification_id: Some(verification_id),
+                    message: format!("batch finished with status {}", batch.status),
+                }),
+                Err(e) => report.add_failure(ChunkFailure {
+                    chunk,
+                    emails,
+                    verification_id: Some(verification_id),
+                    message: e.to_string(),
+                }),
+            }
+            progress.inc(settled);
+        }
+
+        report.failures.sort_by_key(|failure| failure.chunk);
+        progress.finish();
+        report
+    }
+
     /// Get batch verification status
     pub async fn get(&self, verification_id: &str) -> Result<BatchVerificationResult> {
         self.client
-            .get(&format!("/email-verification/{}", verification_id))
+            .get(&format!(
+                "/email-verification/{}",
+                path_id(verification_id)?
+            ))
             .await
     }
 
+    /// Poll a batch until it completes, fails, or `options.max_wait` elapses
+    ///
+    /// The deadline also cuts short a status request or sleep in progress.
+    /// On timeout, returns [`Error::PollTimeout`] with the last status seen so
+    /// the caller can decide whether to keep waiting, or [`Error::Timeout`] if
+    /// no status arrived before the deadline.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// use mailbreeze::{Error, PollOptions};
+    /// use std::time::Duration;
+    ///
+    /// let options = PollOptions::new().max_wait(Duration::from_secs(300));
+    /// match client.verification.wait_for_completion("ver_123", &options).await {
+    ///     Ok(batch) => println!("done: {}", batch.status),
+    ///     Err(Error::PollTimeout { last, .. }) => {
+    ///         println!("{} of {} processed so far", last.processed, last.total_emails)
+    ///     }
+    ///     Err(e) => return Err(e),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_completion(
+        &self,
+        verification_id: &str,
+        options: &PollOptions,
+    ) -> Result<BatchVerificationResult> {
+        let poller = match options.poll_timeout {
+            Some(timeout) => self.with_options(RequestOptions::new().timeout(timeout)),
+            None => self.clone(),
+        };
+        let started = Instant::now();
+        let deadline = tokio::time::Instant::from_std(started + options.max_wait);
+        let timed_out = |last: Option<BatchVerificationResult>| match last {
+            Some(last) => Error::PollTimeout {
+                waited: started.elapsed(),
+                last: Box::new(last),
+            },
+            None => Error::Timeout,
+        };
+        let mut last = None;
+        let mut polls = 0;
+
+        loop {
+            polls += 1;
+            let status = match tokio::time::timeout_at(deadline, poller.get(verification_id)).await
+            {
+                Ok(status) => status?,
+                Err(_) => return Err(timed_out(last)),
+            };
+            if status.is_complete() {
+                return Ok(status);
+            }
+
+            let Some(delay) = options.backoff.delay(polls) else {
+                return Err(timed_out(Some(status)));
+            };
+            last = Some(status);
+            if tokio::time::timeout_at(deadline, tokio::time::sleep(delay))
+                .await
+                .is_err()
+            {
+                return Err(timed_out(last));
+            }
+        }
+    }
+
     /// List verification batches
     pub async fn list(&self) -> Result<Vec<VerificationListItem>> {
         // API returns data as {items: [...]}
@@ -60,17 +237,80 @@ impl Verification {
         Ok(response.items)
     }
 
+    /// Download a batch's full results file into `writer`
+    ///
+    /// Large files are fetched in ranges and resume after dropped connections;
+    /// returns the number of bytes written.
+    pub async fn download_results<W>(&self, verification_id: &str, writer: &mut W) -> Result<u64>
+    where
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        self.client
+            .download_to(
+                &format!("/email-verification/{}/download", path_id(verification_id)?),
+                writer,
+            )
+            .await
+    }
+
+    /// Stream a batch's full results file as it arrives
+    ///
+    /// Like [`download_results`](Self::download_results), but hands each chunk
+    /// to the caller instead of writing it, so the file is never held in
+    /// memory. The file is read over one request, which is not resumed if the
+    /// connection drops.
+    pub fn results_stream(&self, verification_id: &str) -> impl Stream<Item = Result<Bytes>> + '_ {
+        match path_id(verification_id) {
+            Ok(id) => self
+                .client
+                .get_stream(format!("/email-verification/{}/download", id))
+                .left_stream(),
+            Err(e) => stream::once(async { Err(e) }).right_stream(),
+        }
+    }
+
     /// Get verification statistics
     pub async fn stats(&self) -> Result<VerificationStats> {
         self.client.get("/email-verification/stats").await
     }
+
+    /// Get verification statistics for a date range, optionally broken down by group
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// # async fn run(client: mailbreeze::MailBreeze) -> mailbreeze::Result<()> {
+    /// use mailbreeze::{VerificationStatsGrouping, VerificationStatsParams};
+    ///
+    /// let stats = client
+    ///     .verification
+    ///     .stats_with_params(&VerificationStatsParams {
+    ///         from: Some("2024-06-01".to_string()),
+    ///         to: Some("2024-06-30".to_string()),
+    ///         group_by: Some(VerificationStatsGrouping::Source),
+    ///     })
+    ///     .await?;
+    /// for source in &stats.breakdown {
+    ///     println!("{}: {:.1}% valid", source.key, source.valid_percentage);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stats_with_params(
+        &self,
+        params: &VerificationStatsParams,
+    ) -> Result<VerificationStats> {
+        self.client
+            .get_with_params("/email-verification/stats", params)
+            .await
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::client::ClientConfig;
-    use wiremock::matchers::{method, path};
+    use std::time::Duration;
+    use wiremock::matchers::{body_partial_json, header, method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     async fn setup() -> (MockServer, Verification) {
@@ -268,4 +508,192 @@ mod tests {
         assert_eq!(stats.total_verified, 10000);
         assert_eq!(stats.total_valid, 8500);
     }
+
+    #[tokio::test]
+    async fn test_verification_stats_by_source() {
+        let (mock_server, verification) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/email-verification/stats"))
+            .and(query_param("from", "2024-06-01"))
+            .and(query_param("to", "2024-06-30"))
+            .and(query_param("group_by", "source"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "totalVerified": 1500,
+                    "totalValid": 1200,
+                    "totalInvalid": 250,
+                    "totalUnknown": 50,
+                    "totalVerifications": 3,
+                    "validPercentage": 80.0,
+                    "creditsUsed": 1500,
+                    "breakdown": [
+                        {"key": "signup_form", "totalVerified": 1000, "totalValid": 900,
+                         "totalInvalid": 80, "totalUnknown": 20, "validPercentage": 90.0,
+                         "creditsUsed": 1000},
+                        {"key": "purchased_list", "totalVerified": 500, "totalValid": 300,
+                         "totalInvalid": 170, "totalUnknown": 30, "validPercentage": 60.0}
+                    ]
+                }
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+
+        let params = VerificationStatsParams {
+            from: Some("2024-06-01".to_string()),
+            to: Some("2024-06-30".to_string()),
+            group_by: Some(crate::types::VerificationStatsGrouping::Source),
+        };
+        let stats = verification.stats_with_params(&params).await.unwrap();
+        assert_eq!(stats.credits_used, Some(1500));
+        assert_eq!(stats.breakdown.len(), 2);
+        assert_eq!(stats.breakdown[1].key, "purchased_list");
+        assert_eq!(stats.breakdown[1].credits_used, None);
+    }
+
+    #[tokio::test]
+    async fn test_with_options_applies_headers_and_timeout() {
+        let (mock_server, verification) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/email-verification/batch_slow"))
+            .and(header("X-Tenant", "acme"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_delay(std::time::Duration::from_millis(500))
+                    .set_body_json(serde_json::json!({
+                        "success": true,
+                        "data": {"verificationId": "batch_slow", "status": "processing"}
+                    })),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let scoped = verification.with_options(RequestOptions::new().header("X-Tenant", "acme"));
+        let result = scoped.get("batch_slow").await.unwrap();
+        assert_eq!(result.status, "processing");
+
+        let impatient = scoped
+            .with_options(RequestOptions::new().timeout(std::time::Duration::from_millis(50)));
+        let err = impatient.get("batch_slow").await.unwrap_err();
+        assert!(matches!(err, crate::Error::Http(ref e) if e.is_timeout()));
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_completion_times_out_with_progress() {
+        use crate::retry::RetryPolicy;
+
+        let (mock_server, verification) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/email-verification/ver_123"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"id": "ver_123", "status": "processing", "totalEmails": 100, "processed": 40}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let options = PollOptions::new()
+            .max_wait(Duration::from_millis(50))
+            .backoff(RetryPolicy::Fixed(Duration::from_millis(10)));
+        let error = verification
+            .wait_for_completion("ver_123", &options)
+            .await
+            .unwrap_err();
+
+        match error {
+            Error::PollTimeout { last, waited } => {
+                assert_eq!(last.processed, 40);
+                assert!(waited >= Duration::from_millis(50));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_wait_for_completion_cuts_short_a_slow_poll() {
+        let (mock_server, verification) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/email-verification/ver_123"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_delay(Duration::from_secs(5))
+                    .set_body_json(serde_json::json!({
+                        "success": true,
+                        "data": {"id": "ver_123", "status": "processing", "totalEmails": 100, "processed": 40}
+                    })),
+            )
+            .mount(&mock_server)
+            .await;
+
+        let options = PollOptions::new().max_wait(Duration::from_millis(100));
+        let started = Instant::now();
+        let error = verification
+            .wait_for_completion("ver_123", &options)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, Error::Timeout));
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    async fn test_batch_chunked_merges_and_tracks_failures() {
+        let (mock_server, verification) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/email-verification/batch"))
+            .and(body_partial_json(serde_json::json!({
+                "emails": ["a@example.com", "b@example.com"]
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "verificationId": "ver_1",
+                    "status": "completed",
+                    "results": {"clean": ["a@example.com"], "dirty": ["b@example.com"]},
+                    "analytics": {"cleanCount": 1, "dirtyCount": 1}
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/v1/email-verification/batch"))
+            .and(body_partial_json(
+                serde_json::json!({"emails": ["c@example.com"]}),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"verificationId": "ver_2", "status": "processing"}
+            })))
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/email-verification/ver_2"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {"verificationId": "ver_2", "status": "failed"}
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let emails = ["a@example.com", "b@example.com", "c@example.com"]
+            .map(String::from)
+            .to_vec();
+        let report = verification
+            .batch_chunked(emails, 2, &PollOptions::default())
+            .await;
+
+        assert_eq!(report.batches.len(), 1);
+        assert_eq!(report.results.clean, vec!["a@example.com"]);
+        assert_eq!(report.analytics.clean_percentage, 50.0);
+        assert!(!report.is_complete());
+        assert_eq!(report.failures[0].chunk, 1);
+        assert_eq!(report.failures[0].verification_id.as_deref(), Some("ver_2"));
+        assert_eq!(report.failed_emails(), vec!["c@example.com"]);
+    }
 }
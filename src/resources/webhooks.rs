@@ -0,0 +1,154 @@
+use crate::client::{path_id, HttpClient};
+use crate::error::Result;
+use crate::options::RequestOptions;
+use crate::types::{CreateWebhookParams, UpdateWebhookParams, WebhookEndpoint};
+
+/// Webhook endpoints API resource
+///
+/// Endpoints can subscribe to email events (`email.delivered`, `email.bounced`, ...)
+/// and account events (`account.credit_low`, `domain.verification_changed`,
+/// `api_key.created`).
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::MailBreeze;
+///
+/// #[tokio::main]
+/// async fn main() -> mailbreeze::Result<()> {
+///     let client = MailBreeze::new("your_api_key")?;
+///
+///     let endpoint = client.webhooks.create(&mailbreeze::CreateWebhookParams {
+///         url: "https://ops.example.com/hooks/mailbreeze".to_string(),
+///         events: vec![
+///             "account.credit_low".to_string(),
+///             "domain.verification_changed".to_string(),
+///         ],
+///         ..Default::default()
+///     }).await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Webhooks {
+    client: HttpClient,
+}
+
+impl Webhooks {
+    pub fn new(client: HttpClient) -> Self {
+        Self { client }
+    }
+
+    /// Return a handle that applies `options` to every request it makes
+    pub fn with_options(&self, options: RequestOptions) -> Self {
+        Self::new(self.client.with_options(options))
+    }
+
+    /// Register a webhook endpoint
+    pub async fn create(&self, params: &CreateWebhookParams) -> Result<WebhookEndpoint> {
+        self.client.post("/webhooks", params).await
+    }
+
+    /// Get a webhook endpoint by ID
+    pub async fn get(&self, id: &str) -> Result<WebhookEndpoint> {
+        self.client
+            .get(&format!("/webhooks/{}", path_id(id)?))
+            .await
+    }
+
+    /// Update a webhook endpoint
+    pub async fn update(&self, id: &str, params: &UpdateWebhookParams) -> Result<WebhookEndpoint> {
+        self.client
+            .put(&format!("/webhooks/{}", path_id(id)?), params)
+            .await
+    }
+
+    /// Delete a webhook endpoint
+    pub async fn delete(&self, id: &str) -> Result<()> {
+        self.client
+            .delete(&format!("/webhooks/{}", path_id(id)?))
+            .await
+    }
+
+    /// List all webhook endpoints
+    pub async fn list(&self) -> Result<Vec<WebhookEndpoint>> {
+        self.client.get("/webhooks").await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::ClientConfig;
+    use wiremock::matchers::{body_json, method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    async fn setup() -> (MockServer, Webhooks) {
+        let mock_server = MockServer::start().await;
+        let config = ClientConfig::new("test_key").base_url(mock_server.uri());
+        let client = HttpClient::new(config).unwrap();
+        let webhooks = Webhooks::new(client);
+        (mock_server, webhooks)
+    }
+
+    #[tokio::test]
+    async fn test_create_webhook_for_account_events() {
+        let (mock_server, webhooks) = setup().await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/v1/webhooks"))
+            .and(body_json(serde_json::json!({
+                "url": "https://ops.example.com/hooks",
+                "events": ["account.credit_low", "api_key.created"]
+            })))
+            .respond_with(ResponseTemplate::new(201).set_body_json(serde_json::json!({
+                "success": true,
+                "data": {
+                    "id": "wh_123",
+                    "url": "https://ops.example.com/hooks",
+                    "events": ["account.credit_low", "api_key.created"],
+                    "enabled": true,
+                    "secret": "whsec_abc",
+                    "createdAt": "2024-01-01T00:00:00Z"
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let endpoint = webhooks
+            .create(&CreateWebhookParams {
+                url: "https://ops.example.com/hooks".to_string(),
+                events: vec![
+                    "account.credit_low".to_string(),
+                    "api_key.created".to_string(),
+                ],
+                description: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(endpoint.id, "wh_123");
+        assert!(endpoint.enabled);
+        assert_eq!(endpoint.secret.as_deref(), Some("whsec_abc"));
+    }
+
+    #[tokio::test]
+    async fn test_list_webhooks() {
+        let (mock_server, webhooks) = setup().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/webhooks"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "data": [
+                    {"id": "wh_1", "url": "https://a.example.com", "events": ["email.bounced"], "enabled": true}
+                ]
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let endpoints = webhooks.list().await.unwrap();
+        assert_eq!(endpoints.len(), 1);
+        assert_eq!(endpoints[0].events, vec!["email.bounced"]);
+    }
+}
@@ -0,0 +1,142 @@
+use crate::deprecation::DeprecationNotice;
+use crate::transport::TransportResponse;
+use reqwest::header::HeaderMap;
+use serde_json::{Map, Value};
+
+/// Envelope fields decoded by the client itself, never reported in `extra`
+const ENVELOPE_FIELDS: [&str; 3] = ["success", "data", "error"];
+
+/// A decoded response with the envelope and header metadata the plain
+/// methods discard
+///
+/// Returned by the `*_with_meta` methods, e.g.
+/// [`Emails::send_with_meta`](crate::Emails::send_with_meta).
+#[derive(Debug, Clone)]
+pub struct Response<T> {
+    pub data: T,
+    /// Status, request ID and rate-limit state
+    pub meta: ResponseMeta,
+    /// Envelope `warnings`, e.g. ignored fields or soft limits being approached
+    pub warnings: Vec<String>,
+    /// Set when the endpoint is flagged with `Deprecation`/`Sunset` headers
+    pub deprecation: Option<DeprecationNotice>,
+    /// Any other top-level envelope fields, as sent
+    pub extra: Map<String, Value>,
+}
+
+impl<T> Response<T> {
+    /// Parse the envelope metadata of `response`; `data` is decoded separately
+    pub(crate) fn new(data: T, method: &str, path: &str, response: &TransportResponse) -> Self {
+        let mut extra = match serde_json::from_slice(&response.body) {
+            Ok(Value::Object(envelope)) => envelope,
+            _ => Map::new(),
+        };
+        for field in ENVELOPE_FIELDS {
+            extra.remove(field);
+        }
+
+        let warnings = match extra.remove("warnings") {
+            Some(Value::Array(warnings)) => warnings.into_iter().filter_map(warning_text).collect(),
+            Some(warning) => warning_text(warning).into_iter().collect(),
+            None => Vec::new(),
+        };
+
+        let mut meta = ResponseMeta::from_response(response);
+        if meta.request_id.is_none() {
+            if let Some(Value::String(id)) = extra.remove("requestId") {
+                meta.request_id = Some(id);
+            }
+        }
+
+        Self {
+            data,
+            meta,
+            warnings,
+            deprecation: DeprecationNotice::from_headers(method, path, &response.headers),
+            extra,
+        }
+    }
+
+    pub fn into_data(self) -> T {
+        self.data
+    }
+
+    /// Transform the payload, keeping the metadata
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Response<U> {
+        Response {
+            data: f(self.data),
+            meta: self.meta,
+            warnings: self.warnings,
+            deprecation: self.deprecation,
+            extra: self.extra,
+        }
+    }
+}
+
+/// A warning sent either as a plain string or as an object with a `message`
+fn warning_text(warning: Value) -> Option<String> {
+    match warning {
+        Value::String(text) => Some(text),
+        Value::Object(mut object) => match object.remove("message") {
+            Some(Value::String(text)) => Some(text),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Metadata from the most recent API response
+///
+/// Clients derived with `with_options` share the same record. With concurrent
+/// requests, this reflects whichever response arrived last.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseMeta {
+    /// HTTP status code
+    pub status: u16,
+    /// Value of the `X-Request-Id` header; quote it in support tickets
+    pub request_id: Option<String>,
+    /// Rate-limit window state, when the API sent `X-RateLimit-*` headers
+    pub rate_limit: Option<RateLimitInfo>,
+    /// Response schema version from `MailBreeze-Schema-Version`, compare with
+    /// [`SCHEMA_VERSION`](crate::SCHEMA_VERSION)
+    pub schema_version: Option<u32>,
+}
+
+/// Rate-limit state from `X-RateLimit-Limit/Remaining/Reset` headers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// Requests allowed in the current window
+    pub limit: u64,
+    /// Requests left in the current window
+    pub remaining: u64,
+    /// `X-RateLimit-Reset` as sent by the API (seconds until the window resets)
+    pub reset: Option<u64>,
+}
+
+impl RateLimitInfo {
+    fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let number = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.trim().parse::<u64>().ok())
+        };
+
+        Some(Self {
+            limit: number("X-RateLimit-Limit")?,
+            remaining: number("X-RateLimit-Remaining")?,
+            reset: number("X-RateLimit-Reset"),
+        })
+    }
+}
+
+impl ResponseMeta {
+    pub(crate) fn from_response(response: &TransportResponse) -> Self {
+        Self {
+            status: response.status.as_u16(),
+            request_id: crate::client::response_request_id(&response.headers),
+            rate_limit: RateLimitInfo::from_headers(&response.headers),
+            schema_version: crate::schema::schema_version(&response.headers),
+        }
+    }
+}
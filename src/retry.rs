@@ -0,0 +1,193 @@
+use crate::error::Error;
+use reqwest::Method;
+use std::sync::Arc;
+use std::time::Duration;
+
+const DEFAULT_BASE_DELAY_MS: u64 = 100;
+const DEFAULT_MAX_DELAY_SECS: u64 = 30;
+
+/// Backoff strategy used between retry attempts
+///
+/// The number of attempts is still bounded by `max_retries`; the policy only
+/// decides how long to wait before each retry (or that no retry should happen).
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::{MailBreeze, RetryPolicy};
+/// use std::time::Duration;
+///
+/// # fn main() -> mailbreeze::Result<()> {
+/// let client = MailBreeze::builder("your_api_key")
+///     .retry_policy(RetryPolicy::exponential_with_jitter(
+///         Duration::from_millis(200),
+///         Duration::from_secs(10),
+///     ))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub enum RetryPolicy {
+    /// Exponential backoff: `base * 2^(attempt - 1)`, capped at `max`.
+    /// With `jitter`, a random delay between zero and that value is used instead.
+    Exponential {
+        base: Duration,
+        max: Duration,
+        jitter: bool,
+    },
+    /// Wait the same amount of time before every retry
+    Fixed(Duration),
+    /// Never retry
+    None,
+    /// Compute the delay from the attempt number (starting at 1)
+    Custom(Arc<dyn Fn(u32) -> Duration + Send + Sync>),
+}
+
+impl RetryPolicy {
+    /// Exponential backoff without jitter
+    pub fn exponential(base: Duration, max: Duration) -> Self {
+        RetryPolicy::Exponential {
+            base,
+            max,
+            jitter: false,
+        }
+    }
+
+    /// Exponential backoff with full jitter
+    pub fn exponential_with_jitter(base: Duration, max: Duration) -> Self {
+        RetryPolicy::Exponential {
+            base,
+            max,
+            jitter: true,
+        }
+    }
+
+    /// Backoff computed by a closure receiving the attempt number
+    pub fn custom(f: impl Fn(u32) -> Duration + Send + Sync + 'static) -> Self {
+        RetryPolicy::Custom(Arc::new(f))
+    }
+
+    /// Delay before retrying after the given attempt, or `None` if the policy never retries
+    pub fn delay(&self, attempt: u32) -> Option<Duration> {
+        match self {
+            RetryPolicy::Exponential { base, max, jitter } => {
+                let factor = 1u32
+                    .checked_shl(attempt.saturating_sub(1))
+                    .unwrap_or(u32::MAX);
+                let delay = base.saturating_mul(factor).min(*max);
+                if *jitter {
+                    Some(delay.mul_f64(rand::random::<f64>()))
+                } else {
+                    Some(delay)
+                }
+            }
+            RetryPolicy::Fixed(delay) => Some(*delay),
+            RetryPolicy::None => None,
+            RetryPolicy::Custom(f) => Some(f(attempt)),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::exponential(
+            Duration::from_millis(DEFAULT_BASE_DELAY_MS),
+            Duration::from_secs(DEFAULT_MAX_DELAY_SECS),
+        )
+    }
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RetryPolicy::Exponential { base, max, jitter } => f
+                .debug_struct("Exponential")
+                .field("base", base)
+                .field("max", max)
+                .field("jitter", jitter)
+                .finish(),
+            RetryPolicy::Fixed(delay) => f.debug_tuple("Fixed").field(delay).finish(),
+            RetryPolicy::None => f.write_str("None"),
+            RetryPolicy::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Decides whether a failed request should be retried
+///
+/// Replaces the built-in [`Error::is_retryable`] check. Rate limit errors are
+/// still governed by `retry_rate_limits`, and attempts remain bounded by
+/// `max_retries`. Closures taking `(&Method, &Error)` implement this trait.
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::{Error, MailBreeze, Method};
+///
+/// # fn main() -> mailbreeze::Result<()> {
+/// let client = MailBreeze::builder("your_api_key")
+///     .retry_classifier(|method: &Method, error: &Error| match error.status_code() {
+///         Some(408 | 425) => true,
+///         // A 500 on a POST may have been applied; don't risk a duplicate
+///         Some(500) => method != Method::POST,
+///         _ => error.is_retryable(),
+///     })
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait RetryClassifier: Send + Sync {
+    fn should_retry(&self, method: &Method, error: &Error) -> bool;
+}
+
+impl<F> RetryClassifier for F
+where
+    F: Fn(&Method, &Error) -> bool + Send + Sync,
+{
+    fn should_retry(&self, method: &Method, error: &Error) -> bool {
+        self(method, error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matches_legacy_backoff() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay(1), Some(Duration::from_millis(100)));
+        assert_eq!(policy.delay(2), Some(Duration::from_millis(200)));
+        assert_eq!(policy.delay(3), Some(Duration::from_millis(400)));
+    }
+
+    #[test]
+    fn test_exponential_is_capped() {
+        let policy = RetryPolicy::exponential(Duration::from_secs(1), Duration::from_secs(5));
+        assert_eq!(policy.delay(10), Some(Duration::from_secs(5)));
+        assert_eq!(policy.delay(64), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let policy = RetryPolicy::exponential_with_jitter(
+            Duration::from_millis(100),
+            Duration::from_secs(1),
+        );
+        for attempt in 1..8 {
+            let delay = policy.delay(attempt).unwrap();
+            assert!(delay <= Duration::from_secs(1));
+        }
+    }
+
+    #[test]
+    fn test_fixed_none_and_custom() {
+        assert_eq!(
+            RetryPolicy::Fixed(Duration::from_millis(50)).delay(3),
+            Some(Duration::from_millis(50))
+        );
+        assert_eq!(RetryPolicy::None.delay(1), None);
+
+        let policy = RetryPolicy::custom(|attempt| Duration::from_millis(attempt as u64 * 10));
+        assert_eq!(policy.delay(4), Some(Duration::from_millis(40)));
+    }
+}
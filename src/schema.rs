@@ -0,0 +1,60 @@
+use reqwest::header::HeaderMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+
+/// Response schema version this SDK release was built against
+///
+/// Sent with every request as `Accept: application/json; schema-version=N`.
+pub const SCHEMA_VERSION: u32 = 1;
+
+/// Callback invoked when the server reports a newer response schema than the SDK knows
+pub type SchemaDriftHandler = Arc<dyn Fn(&SchemaDrift) + Send + Sync>;
+
+/// The server answered with a newer response schema than [`SCHEMA_VERSION`]
+///
+/// Newer schemas may add or reshape fields; upgrading the SDK before they
+/// cause decode errors is the usual fix.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaDrift {
+    /// [`SCHEMA_VERSION`] of this SDK build
+    pub sdk_version: u32,
+    /// Version from the response's `MailBreeze-Schema-Version` header
+    pub server_version: u32,
+    /// HTTP method of the request that revealed the drift
+    pub method: String,
+    /// API path of the request, without the version prefix
+    pub path: String,
+}
+
+impl std::fmt::Display for SchemaDrift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {} answered with response schema {}, newer than the {} this SDK decodes; upgrade mailbreeze",
+            self.method, self.path, self.server_version, self.sdk_version
+        )
+    }
+}
+
+/// `MailBreeze-Schema-Version` response header, if present and numeric
+pub(crate) fn schema_version(headers: &HeaderMap) -> Option<u32> {
+    headers
+        .get("MailBreeze-Schema-Version")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse().ok())
+}
+
+/// Highest server schema version already reported, so each newer version
+/// is reported once rather than on every response
+#[derive(Debug, Default)]
+pub(crate) struct DriftTracker {
+    reported: AtomicU32,
+}
+
+impl DriftTracker {
+    /// Whether `server_version` is newer than the SDK and not yet reported
+    pub(crate) fn first_sighting(&self, server_version: u32) -> bool {
+        server_version > SCHEMA_VERSION
+            && self.reported.fetch_max(server_version, Ordering::Relaxed) < server_version
+    }
+}
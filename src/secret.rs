@@ -0,0 +1,57 @@
+//! Secret string for API keys
+
+use zeroize::Zeroize;
+
+/// A string that is wiped from memory when dropped and never printed
+///
+/// `Debug` shows `[REDACTED]` and there is no `Display`; read the value
+/// explicitly with [`expose_secret`](Self::expose_secret).
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::SecretString;
+///
+/// let key = SecretString::from("mb_live_123");
+/// assert_eq!(format!("{:?}", key), "[REDACTED]");
+/// assert_eq!(key.expose_secret(), "mb_live_123");
+/// ```
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretString(String);
+
+impl SecretString {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(secret.into())
+    }
+
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+}
+
+impl std::fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("[REDACTED]")
+    }
+}
+
+impl Drop for SecretString {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
@@ -0,0 +1,127 @@
+//! Request signing for deployments that require signed API calls
+//!
+//! A [`RequestSigner`] sees every request just before it is sent and may add
+//! headers to it. [`HmacSigner`] covers the common scheme of an HMAC-SHA256
+//! over the method, path, timestamp and body.
+
+use crate::error::{Error, Result};
+use crate::secret::SecretString;
+use crate::transport::TransportRequest;
+use hmac::{Hmac, Mac};
+use reqwest::header::{HeaderName, HeaderValue};
+use sha2::Sha256;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Adds signature headers to outgoing requests
+///
+/// Called on every attempt, including retries and failover, after all other
+/// headers are set and the body is in its final (possibly compressed) form,
+/// so a signature always matches the bytes on the wire.
+pub trait RequestSigner: std::fmt::Debug + Send + Sync {
+    fn sign(&self, request: &mut TransportRequest) -> Result<()>;
+}
+
+/// HMAC-SHA256 request signer
+///
+/// Signs the string `{timestamp}\n{METHOD}\n{path?query}\n{body}`, where
+/// `timestamp` is Unix seconds, and sends:
+///
+/// - `X-MailBreeze-Timestamp`: the timestamp
+/// - `X-MailBreeze-Signature`: `v1=` followed by the lowercase hex digest
+/// - `X-MailBreeze-Key-Id`: the key ID, when one is set
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::{HmacSigner, MailBreeze};
+///
+/// # fn main() -> mailbreeze::Result<()> {
+/// let client = MailBreeze::builder("your_api_key")
+///     .signer(HmacSigner::new("signing_secret").key_id("key_2024"))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HmacSigner {
+    secret: SecretString,
+    key_id: Option<String>,
+}
+
+impl HmacSigner {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self {
+            secret: SecretString::new(secret),
+            key_id: None,
+        }
+    }
+
+    /// Identify which signing key was used, for servers that rotate keys
+    pub fn key_id(mut self, key_id: impl Into<String>) -> Self {
+        self.key_id = Some(key_id.into());
+        self
+    }
+
+    /// Hex signature of a request made at `timestamp`
+    pub fn signature(&self, method: &str, path: &str, body: &[u8], timestamp: u64) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.expose_secret().as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(format!("{}\n{}\n{}\n", timestamp, method, path).as_bytes());
+        mac.update(body);
+        mac.finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+impl RequestSigner for HmacSigner {
+    fn sign(&self, request: &mut TransportRequest) -> Result<()> {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        let path = match request.url.query() {
+            Some(query) => format!("{}?{}", request.url.path(), query),
+            None => request.url.path().to_string(),
+        };
+        let signature = self.signature(
+            request.method.as_str(),
+            &path,
+            request.body.as_deref().unwrap_or_default(),
+            timestamp,
+        );
+
+        let mut headers = vec![
+            ("x-mailbreeze-timestamp", timestamp.to_string()),
+            ("x-mailbreeze-signature", format!("v1={}", signature)),
+        ];
+        if let Some(key_id) = &self.key_id {
+            headers.push(("x-mailbreeze-key-id", key_id.clone()));
+        }
+        for (name, value) in headers {
+            let value = HeaderValue::from_str(&value)
+                .map_err(|_| Error::InvalidHeader(name.to_string()))?;
+            request.headers.insert(HeaderName::from_static(name), value);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_signature_known_vector() {
+        let signer = HmacSigner::new("secret");
+        assert_eq!(
+            signer.signature(
+                "POST",
+                "/api/v1/emails?dryRun=true",
+                br#"{"to":["a@example.com"]}"#,
+                1_700_000_000
+            ),
+            "2b6c95445147a6553f55b9db1b968749ff2fce1879765cefd54924364d51423b"
+        );
+    }
+}
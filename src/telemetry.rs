@@ -0,0 +1,184 @@
+//! Request instrumentation hooks called from the retry loop in `client.rs`
+//!
+//! With the `metrics` feature enabled, these record through the
+//! [`metrics`](https://docs.rs/metrics) facade, and with `otel` each request
+//! gets an OpenTelemetry client span; otherwise they compile to nothing.
+
+use crate::error::Error;
+use crate::transport::TransportRequest;
+use reqwest::Method;
+use std::time::Duration;
+
+/// Resource label for a request path, e.g. `/contact-lists/x/contacts` -> `contact-lists`
+pub(crate) fn resource(path: &str) -> String {
+    path.trim_start_matches('/')
+        .split(['/', '?'])
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Record one attempt and how long it took
+///
+/// `status` is `None` when the transport failed before a response arrived.
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub(crate) fn record_attempt(method: &Method, path: &str, status: Option<u16>, elapsed: Duration) {
+    #[cfg(feature = "metrics")]
+    {
+        let status = status.map_or_else(|| "error".to_string(), |s| s.to_string());
+        let resource = resource(path);
+        ::metrics::counter!(
+            "mailbreeze_requests_total",
+            "method" => method.to_string(),
+            "resource" => resource.clone(),
+            "status" => status
+        )
+        .increment(1);
+        ::metrics::histogram!(
+            "mailbreeze_request_duration_seconds",
+            "method" => method.to_string(),
+            "resource" => resource
+        )
+        .record(elapsed.as_secs_f64());
+    }
+}
+
+/// Record that a failed attempt is about to be retried
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub(crate) fn record_retry(method: &Method, path: &str) {
+    #[cfg(feature = "metrics")]
+    ::metrics::counter!(
+        "mailbreeze_retries_total",
+        "method" => method.to_string(),
+        "resource" => resource(path)
+    )
+    .increment(1);
+}
+
+/// Record a 429 response
+#[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+pub(crate) fn record_rate_limit(method: &Method, path: &str) {
+    #[cfg(feature = "metrics")]
+    ::metrics::counter!(
+        "mailbreeze_rate_limited_total",
+        "method" => method.to_string(),
+        "resource" => resource(path)
+    )
+    .increment(1);
+}
+
+/// Client span covering every attempt of a single request
+pub(crate) struct RequestSpan {
+    #[cfg(feature = "otel")]
+    cx: opentelemetry::Context,
+}
+
+impl RequestSpan {
+    /// Start a span and inject its W3C trace context into the request headers
+    #[cfg_attr(not(feature = "otel"), allow(unused_variables))]
+    pub(crate) fn start(method: &Method, path: &str, request: &mut TransportRequest) -> Self {
+        #[cfg(feature = "otel")]
+        {
+            use opentelemetry::trace::{SpanKind, TraceContextExt, Tracer};
+            use opentelemetry::{global, Context, KeyValue};
+
+            let tracer = global::tracer("mailbreeze");
+            let mut attributes = vec![
+                KeyValue::new("http.request.method", method.to_string()),
+                KeyValue::new("url.path", crate::client::redact_path(request.url.path())),
+            ];
+            if let Some(host) = request.url.host_str() {
+                attributes.push(KeyValue::new("server.address", host.to_string()));
+            }
+
+            let span = tracer
+                .span_builder(format!("{} {}", method, resource(path)))
+                .with_kind(SpanKind::Client)
+                .with_attributes(attributes)
+                .start(&tracer);
+            let cx = Context::current().with_span(span);
+
+            global::get_text_map_propagator(|propagator| {
+                propagator.inject_context(&cx, &mut HeaderInjector(&mut request.headers))
+            });
+
+            Self { cx }
+        }
+
+        #[cfg(not(feature = "otel"))]
+        Self {}
+    }
+
+    /// End the span with the final status and number of attempts
+    #[cfg_attr(not(feature = "otel"), allow(unused_variables))]
+    pub(crate) fn finish(self, status: Option<u16>, error: Option<&Error>, attempts: u32) {
+        #[cfg(feature = "otel")]
+        {
+            use opentelemetry::trace::{Status, TraceContextExt};
+            use opentelemetry::KeyValue;
+
+            let span = self.cx.span();
+            if let Some(status) = status {
+                span.set_attribute(KeyValue::new("http.response.status_code", status as i64));
+            }
+            if attempts > 1 {
+                span.set_attribute(KeyValue::new(
+                    "http.request.resend_count",
+                    (attempts - 1) as i64,
+                ));
+            }
+            if let Some(error) = error {
+                span.set_status(Status::error(error.to_string()));
+            }
+            span.end();
+        }
+    }
+}
+
+/// Writes propagated trace context into outgoing request headers
+#[cfg(feature = "otel")]
+struct HeaderInjector<'a>(&'a mut reqwest::header::HeaderMap);
+
+#[cfg(feature = "otel")]
+impl opentelemetry::propagation::Injector for HeaderInjector<'_> {
+    fn set(&mut self, key: &str, value: String) {
+        if let (Ok(name), Ok(value)) = (
+            reqwest::header::HeaderName::from_bytes(key.as_bytes()),
+            reqwest::header::HeaderValue::from_str(&value),
+        ) {
+            self.0.insert(name, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resource_label() {
+        assert_eq!(resource("/contact-lists/list_1/contacts"), "contact-lists");
+        assert_eq!(resource("/emails"), "emails");
+        assert_eq!(resource("/emails?page=2"), "emails");
+    }
+
+    #[cfg(feature = "otel")]
+    #[test]
+    fn test_header_injector() {
+        use opentelemetry::propagation::Injector;
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        let mut injector = HeaderInjector(&mut headers);
+        injector.set(
+            "traceparent",
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01".to_string(),
+        );
+        injector.set("bad header", "ignored".to_string());
+
+        assert_eq!(headers.len(), 1);
+        assert_eq!(
+            headers["traceparent"],
+            "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01"
+        );
+    }
+}
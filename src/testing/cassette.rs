@@ -0,0 +1,345 @@
+use crate::error::{Error, Result};
+use crate::testing::API_PREFIX;
+use crate::transport::{Transport, TransportRequest, TransportResponse};
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Whether a [`CassetteTransport`] talks to the network
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CassetteMode {
+    /// Forward requests to the real transport and save every exchange
+    Record,
+    /// Answer requests from the cassette file without touching the network
+    Replay,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct Cassette {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Interaction {
+    request: RecordedCall,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedCall {
+    method: String,
+    path: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    query: Option<String>,
+    #[serde(flatten)]
+    body: RecordedBody,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedResponse {
+    status: u16,
+    #[serde(default)]
+    headers: Vec<(String, String)>,
+    #[serde(flatten)]
+    body: RecordedBody,
+}
+
+/// Body stored as JSON when it parses, otherwise as UTF-8 text
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct RecordedBody {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    json: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    text: Option<String>,
+}
+
+impl RecordedBody {
+    fn encode(body: &[u8]) -> Self {
+        if body.is_empty() {
+            return Self::default();
+        }
+        match serde_json::from_slice(body) {
+            Ok(json) => Self {
+                json: Some(json),
+                text: None,
+            },
+            Err(_) => Self {
+                json: None,
+                text: Some(String::from_utf8_lossy(body).into_owned()),
+            },
+        }
+    }
+
+    fn decode(&self) -> Result<Vec<u8>> {
+        match (&self.json, &self.text) {
+            (Some(json), _) => Ok(serde_json::to_vec(json)?),
+            (None, Some(text)) => Ok(text.clone().into_bytes()),
+            (None, None) => Ok(Vec::new()),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Tape {
+    interactions: Vec<Interaction>,
+    /// Which interactions have been replayed, so repeated polls get successive responses
+    used: Vec<bool>,
+}
+
+/// [`Transport`] that records exchanges to a cassette file and replays them
+///
+/// Record once against the real API, commit the cassette, and the same test
+/// runs deterministically offline in CI. On replay, each request is answered by
+/// the first unused recording with the same method, path and query, so a flow
+/// that polls the same endpoint gets the recorded responses in order.
+///
+/// Request headers are never written, so API keys stay out of the cassette.
+/// Request and response bodies are stored as recorded; scrub any personal data
+/// before committing.
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::testing::CassetteTransport;
+/// use mailbreeze::{MailBreeze, ReqwestTransport};
+/// use std::time::Duration;
+///
+/// # fn main() -> mailbreeze::Result<()> {
+/// // Replays tests/cassettes/verify.json if present, otherwise records it
+/// let transport = CassetteTransport::auto(
+///     "tests/cassettes/verify.json",
+///     ReqwestTransport::new(Duration::from_secs(30))?,
+/// )?;
+/// let client = MailBreeze::builder(std::env::var("MAILBREEZE_API_KEY").unwrap_or_default())
+///     .transport(transport)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct CassetteTransport {
+    path: PathBuf,
+    inner: Option<Arc<dyn Transport>>,
+    tape: Mutex<Tape>,
+}
+
+impl CassetteTransport {
+    /// Send requests through `inner` and write every exchange to `path`
+    ///
+    /// The file is replaced, and rewritten after each exchange.
+    pub fn record(path: impl Into<PathBuf>, inner: impl Transport + 'static) -> Self {
+        Self {
+            path: path.into(),
+            inner: Some(Arc::new(inner)),
+            tape: Mutex::default(),
+        }
+    }
+
+    /// Answer requests from the cassette at `path`
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let cassette: Cassette = serde_json::from_slice(&std::fs::read(&path)?)?;
+        let used = vec![false; cassette.interactions.len()];
+        Ok(Self {
+            path,
+            inner: None,
+            tape: Mutex::new(Tape {
+                interactions: cassette.interactions,
+                used,
+            }),
+        })
+    }
+
+    /// Replay `path` if it exists, otherwise record it through `inner`
+    pub fn auto(path: impl Into<PathBuf>, inner: impl Transport + 'static) -> Result<Self> {
+        let path = path.into();
+        if path.exists() {
+            Self::replay(path)
+        } else {
+            Ok(Self::record(path, inner))
+        }
+    }
+
+    pub fn mode(&self) -> CassetteMode {
+        match self.inner {
+            Some(_) => CassetteMode::Record,
+            None => CassetteMode::Replay,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn recorded_call(request: &TransportRequest) -> RecordedCall {
+        let path = request.url.path();
+        RecordedCall {
+            method: request.method.to_string(),
+            path: path.strip_prefix(API_PREFIX).unwrap_or(path).to_string(),
+            query: request.url.query().map(str::to_string),
+            body: request
+                .body
+                .as_deref()
+                .map(RecordedBody::encode)
+                .unwrap_or_default(),
+        }
+    }
+
+    fn save(&self, call: RecordedCall, response: &TransportResponse) -> Result<()> {
+        let headers = response
+            .headers
+            .iter()
+            .filter(|(name, _)| *name != reqwest::header::SET_COOKIE)
+            .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+            .collect();
+        let interaction = Interaction {
+            request: call,
+            response: RecordedResponse {
+                status: response.status.as_u16(),
+                headers,
+                body: RecordedBody::encode(&response.body),
+            },
+        };
+
+        let mut tape = self.tape.lock().unwrap_or_else(|e| e.into_inner());
+        tape.interactions.push(interaction);
+        let cassette = Cassette {
+            interactions: tape.interactions.clone(),
+        };
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&self.path, serde_json::to_vec_pretty(&cassette)?)?;
+        Ok(())
+    }
+
+    fn play(&self, call: &RecordedCall) -> Result<TransportResponse> {
+        let mut tape = self.tape.lock().unwrap_or_else(|e| e.into_inner());
+        let Tape { interactions, used } = &mut *tape;
+        let index = interactions
+            .iter()
+            .enumerate()
+            .position(|(i, recorded)| {
+                !used[i]
+                    && recorded.request.method == call.method
+                    && recorded.request.path == call.path
+                    && recorded.request.query == call.query
+            })
+            .ok_or_else(|| Error::Transport {
+                message: format!(
+                    "no recorded response for {} {} in {}",
+                    call.method,
+                    call.path,
+                    self.path.display()
+                ),
+                retryable: false,
+            })?;
+        used[index] = true;
+
+        let recorded = &interactions[index].response;
+        let mut headers = HeaderMap::new();
+        for (name, value) in &recorded.headers {
+            if let (Ok(name), Ok(value)) = (
+                HeaderName::from_bytes(name.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                headers.append(name, value);
+            }
+        }
+        Ok(TransportResponse {
+            status: StatusCode::from_u16(recorded.status).map_err(|e| Error::Transport {
+                message: format!("invalid status in {}: {}", self.path.display(), e),
+                retryable: false,
+            })?,
+            headers,
+            body: recorded.body.decode()?,
+        })
+    }
+}
+
+#[async_trait]
+impl Transport for CassetteTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let call = Self::recorded_call(&request);
+        match &self.inner {
+            Some(inner) => {
+                let response = inner.send(request).await?;
+                self.save(call, &response)?;
+                Ok(response)
+            }
+            None => self.play(&call),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{ClientConfig, HttpClient};
+    use crate::transport::ReqwestTransport;
+    use std::time::Duration;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn status_body(status: &str) -> serde_json::Value {
+        serde_json::json!({"success": true, "data": {"status": status}})
+    }
+
+    #[tokio::test]
+    async fn test_record_then_replay_in_order() {
+        let cassette = std::env::temp_dir().join(format!(
+            "mailbreeze-cassette-{}-record-replay.json",
+            std::process::id()
+        ));
+        let mock_server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/v1/verification/batch/b_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(status_body("processing")))
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/verification/batch/b_1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(status_body("completed")))
+            .mount(&mock_server)
+            .await;
+
+        let recorder = CassetteTransport::record(
+            &cassette,
+            ReqwestTransport::new(Duration::from_secs(5)).unwrap(),
+        );
+        assert_eq!(recorder.mode(), CassetteMode::Record);
+        let config = ClientConfig::new("secret_key")
+            .base_url(mock_server.uri())
+            .transport(recorder);
+        let client = HttpClient::new(config).unwrap();
+        for expected in ["processing", "completed"] {
+            let batch: serde_json::Value = client.get("/verification/batch/b_1").await.unwrap();
+            assert_eq!(batch["status"], expected);
+        }
+        drop(mock_server);
+
+        let saved = std::fs::read_to_string(&cassette).unwrap();
+        assert!(!saved.contains("secret_key"));
+
+        let config = ClientConfig::new("other_key")
+            .base_url("http://offline.invalid")
+            .max_retries(0)
+            .transport(CassetteTransport::replay(&cassette).unwrap());
+        let client = HttpClient::new(config).unwrap();
+        for expected in ["processing", "completed"] {
+            let batch: serde_json::Value = client.get("/verification/batch/b_1").await.unwrap();
+            assert_eq!(batch["status"], expected);
+        }
+        let error = client
+            .get::<serde_json::Value>("/verification/batch/b_1")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, Error::Transport { .. }));
+
+        std::fs::remove_file(&cassette).unwrap();
+    }
+}
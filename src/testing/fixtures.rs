@@ -0,0 +1,200 @@
+//! Realistic API objects for tests
+//!
+//! Each constructor returns a value shaped like a real API response, with a
+//! fresh ID on every call so fixtures can be mixed in one test. Adjust the
+//! public fields for the case at hand instead of hand-writing JSON.
+//!
+//! # Example
+//! ```rust
+//! use mailbreeze::testing::MockMailBreeze;
+//! use mailbreeze::{Contact, Method};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> mailbreeze::Result<()> {
+//! let mut contact = Contact::fake();
+//! contact.first_name = Some("Ada".to_string());
+//!
+//! let mock = MockMailBreeze::new();
+//! let path = format!("/contact-lists/list_1/contacts/{}", contact.id);
+//! mock.respond(Method::GET, &path, &contact);
+//!
+//! let fetched = mock.client().contacts("list_1").get(&contact.id).await?;
+//! assert_eq!(fetched.first_name.as_deref(), Some("Ada"));
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::types::{Contact, ContactStatus, Email, EmailStatus};
+use crate::webhooks::{BounceEventData, EmailEventData, WebhookEvent, WebhookPayload};
+use std::sync::atomic::{AtomicU32, Ordering};
+
+/// Fixed timestamps, so assertions on dates are stable
+const CREATED_AT: &str = "2024-03-14T09:26:53.000Z";
+const SENT_AT: &str = "2024-03-14T09:26:54.000Z";
+const DELIVERED_AT: &str = "2024-03-14T09:26:57.000Z";
+
+static NEXT_ID: AtomicU32 = AtomicU32::new(1);
+
+/// A unique 24-hex-digit ID in the API's ObjectId format
+fn object_id() -> String {
+    format!(
+        "65f2c1a4e8b3d70012{:06x}",
+        NEXT_ID.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+impl Contact {
+    /// An active, subscribed contact with a name and an `api` source
+    pub fn fake() -> Self {
+        let id = object_id();
+        Contact {
+            email: format!("jane.doe+{}@example.com", &id[18..]),
+            id,
+            first_name: Some("Jane".to_string()),
+            last_name: Some("Doe".to_string()),
+            phone_number: None,
+            status: ContactStatus::Active,
+            custom_fields: None,
+            source: Some("api".to_string()),
+            created_at: CREATED_AT.to_string(),
+            updated_at: Some(CREATED_AT.to_string()),
+            subscribed_at: Some(CREATED_AT.to_string()),
+            unsubscribed_at: None,
+            consent_type: None,
+            consent_source: None,
+            consent_timestamp: None,
+            consent_ip_address: None,
+        }
+    }
+
+    /// A contact who has unsubscribed
+    pub fn fake_unsubscribed() -> Self {
+        Contact {
+            status: ContactStatus::Unsubscribed,
+            unsubscribed_at: Some(DELIVERED_AT.to_string()),
+            ..Self::fake()
+        }
+    }
+}
+
+impl Email {
+    /// A transactional email that has been sent but not yet delivered
+    pub fn fake_sent() -> Self {
+        let id = object_id();
+        Email {
+            message_id: Some(format!("<{}@mail.mailbreeze.com>", id)),
+            id,
+            from: "Acme <notifications@acme.example>".to_string(),
+            to: vec!["jane.doe@example.com".to_string()],
+            cc: Vec::new(),
+            bcc: Vec::new(),
+            subject: Some("Your order has shipped".to_string()),
+            status: EmailStatus::Sent,
+            email_type: Some("transactional".to_string()),
+            created_at: CREATED_AT.to_string(),
+            sent_at: Some(SENT_AT.to_string()),
+            delivered_at: None,
+            track_opens: Some(true),
+            track_clicks: Some(true),
+            metadata: None,
+        }
+    }
+
+    /// A transactional email delivered to the recipient's mailbox
+    pub fn fake_delivered() -> Self {
+        Email {
+            status: EmailStatus::Delivered,
+            delivered_at: Some(DELIVERED_AT.to_string()),
+            ..Self::fake_sent()
+        }
+    }
+
+    /// A transactional email that hard-bounced
+    pub fn fake_bounced() -> Self {
+        Email {
+            status: EmailStatus::Bounced,
+            ..Self::fake_sent()
+        }
+    }
+}
+
+impl WebhookEvent {
+    fn fake(event_type: &str, payload: WebhookPayload) -> Self {
+        WebhookEvent {
+            id: format!("evt_{}", object_id()),
+            event_type: event_type.to_string(),
+            created_at: DELIVERED_AT.to_string(),
+            payload,
+        }
+    }
+
+    /// An `email.delivered` event
+    pub fn fake_delivered() -> Self {
+        Self::fake(
+            "email.delivered",
+            WebhookPayload::EmailDelivered(EmailEventData {
+                message_id: format!("<{}@mail.mailbreeze.com>", object_id()),
+                email: "jane.doe@example.com".to_string(),
+                url: None,
+                metadata: None,
+            }),
+        )
+    }
+
+    /// An `email.bounced` event for a hard bounce on a nonexistent mailbox
+    pub fn fake_bounce() -> Self {
+        Self::fake(
+            "email.bounced",
+            WebhookPayload::EmailBounced(BounceEventData {
+                message_id: format!("<{}@mail.mailbreeze.com>", object_id()),
+                email: "no-such-user@example.com".to_string(),
+                bounce_type: Some("hard".to_string()),
+                reason: Some(
+                    "550 5.1.1 The email account that you tried to reach does not exist"
+                        .to_string(),
+                ),
+                metadata: None,
+            }),
+        )
+    }
+
+    /// An `email.complained` event, i.e. the recipient marked the email as spam
+    pub fn fake_complaint() -> Self {
+        Self::fake(
+            "email.complained",
+            WebhookPayload::EmailComplained(EmailEventData {
+                message_id: format!("<{}@mail.mailbreeze.com>", object_id()),
+                email: "jane.doe@example.com".to_string(),
+                url: None,
+                metadata: None,
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixtures_survive_a_wire_round_trip() {
+        let contact = Contact::fake();
+        let json = serde_json::to_vec(&contact).unwrap();
+        let parsed: Contact = serde_json::from_slice(&json).unwrap();
+        assert_eq!(parsed.email, contact.email);
+        assert_ne!(Contact::fake().id, contact.id);
+
+        let email: Email =
+            serde_json::from_value(serde_json::to_value(Email::fake_delivered()).unwrap()).unwrap();
+        assert_eq!(email.status, EmailStatus::Delivered);
+
+        let body = serde_json::to_vec(&WebhookEvent::fake_bounce()).unwrap();
+        let event = WebhookEvent::from_slice(&body).unwrap();
+        match event.payload {
+            WebhookPayload::EmailBounced(bounce) => {
+                assert_eq!(bounce.bounce_type.as_deref(), Some("hard"))
+            }
+            other => panic!("expected a bounce, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,274 @@
+use crate::error::Result;
+use crate::testing::API_PREFIX;
+use crate::transport::{Transport, TransportRequest, TransportResponse};
+use crate::{MailBreeze, MailBreezeBuilder};
+use async_trait::async_trait;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode};
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+
+const MOCK_API_KEY: &str = "test_key";
+const MOCK_BASE_URL: &str = "http://mailbreeze.mock";
+
+/// A request received by a [`MockMailBreeze`] client
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: Method,
+    /// Path below the API version prefix, e.g. `/emails`
+    pub path: String,
+    /// Raw query string, if any
+    pub query: Option<String>,
+    pub headers: HeaderMap,
+    /// JSON request body, if one was sent
+    pub body: Option<serde_json::Value>,
+}
+
+#[derive(Debug)]
+struct Route {
+    method: Method,
+    path: String,
+    status: StatusCode,
+    body: serde_json::Value,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+    routes: Mutex<Vec<Route>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+#[derive(Debug)]
+struct MockTransport(Arc<MockState>);
+
+#[async_trait]
+impl Transport for MockTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let path = request.url.path();
+        let path = path.strip_prefix(API_PREFIX).unwrap_or(path).to_string();
+        let body = request
+            .body
+            .as_deref()
+            .and_then(|body| serde_json::from_slice(body).ok());
+
+        let response = {
+            let routes = self.0.routes.lock().unwrap_or_else(|e| e.into_inner());
+            // Later registrations win, so a test can override an earlier stub
+            match routes
+                .iter()
+                .rev()
+                .find(|route| route.method == request.method && route.path == path)
+            {
+                Some(route) => (route.status, route.body.clone()),
+                None => (
+                    StatusCode::NOT_FOUND,
+                    serde_json::json!({
+                        "success": false,
+                        "error": format!("no mock response for {} {}", request.method, path),
+                    }),
+                ),
+            }
+        };
+
+        self.0
+            .requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(RecordedRequest {
+                method: request.method,
+                path,
+                query: request.url.query().map(str::to_string),
+                headers: request.headers,
+                body,
+            });
+
+        Ok(TransportResponse {
+            status: response.0,
+            headers: HeaderMap::new(),
+            body: serde_json::to_vec(&response.1)?,
+        })
+    }
+}
+
+/// In-memory stand-in for the MailBreeze API
+///
+/// Register responses by method and path (relative to `/api/v1`), run the code
+/// under test against [`client`](Self::client), then inspect what it sent.
+/// Requests without a registered response fail with `Error::NotFound`, and
+/// retries are disabled so failures surface immediately.
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::testing::MockMailBreeze;
+/// use mailbreeze::{MailBreeze, Method, SendEmailParams};
+///
+/// async fn send_welcome(client: &MailBreeze, to: &str) -> mailbreeze::Result<String> {
+///     let result = client.emails.send(&SendEmailParams {
+///         from: "hello@example.com".to_string(),
+///         to: vec![to.to_string()],
+///         ..Default::default()
+///     }).await?;
+///     Ok(result.message_id)
+/// }
+///
+/// # #[tokio::main]
+/// # async fn main() -> mailbreeze::Result<()> {
+/// let mock = MockMailBreeze::new();
+/// mock.respond(Method::POST, "/emails", serde_json::json!({"messageId": "msg_1"}));
+///
+/// let id = send_welcome(&mock.client(), "new@example.com").await?;
+/// assert_eq!(id, "msg_1");
+///
+/// let sent = mock.requests_to(Method::POST, "/emails");
+/// assert_eq!(sent[0].body.as_ref().unwrap()["to"][0], "new@example.com");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockMailBreeze {
+    client: MailBreeze,
+    state: Arc<MockState>,
+}
+
+impl Default for MockMailBreeze {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MockMailBreeze {
+    pub fn new() -> Self {
+        Self::from_builder(MailBreeze::builder(MOCK_API_KEY))
+    }
+
+    /// Build the mock client from `builder`, e.g. to test `max_recipients` handling
+    ///
+    /// The builder's transport, base URL and retry count are replaced.
+    pub fn from_builder(builder: MailBreezeBuilder) -> Self {
+        let state = Arc::new(MockState::default());
+        let client = builder
+            .base_url(MOCK_BASE_URL)
+            .max_retries(0)
+            .transport(MockTransport(state.clone()))
+            .build()
+            .expect("mock client configuration is valid");
+        Self { client, state }
+    }
+
+    /// A client whose requests are answered by this mock
+    pub fn client(&self) -> MailBreeze {
+        self.client.clone()
+    }
+
+    /// Answer `method path` with `data` wrapped in a success envelope
+    pub fn respond(&self, method: Method, path: impl Into<String>, data: impl Serialize) {
+        let data = serde_json::to_value(data).expect("mock response serializes to JSON");
+        self.route(
+            method,
+            path.into(),
+            StatusCode::OK,
+            serde_json::json!({"success": true, "data": data}),
+        );
+    }
+
+    /// Answer `method path` with an API error, e.g. `422` or `503`
+    pub fn respond_error(
+        &self,
+        method: Method,
+        path: impl Into<String>,
+        status: u16,
+        message: impl Into<String>,
+    ) {
+        let status = StatusCode::from_u16(status).expect("valid HTTP status code");
+        self.route(
+            method,
+            path.into(),
+            status,
+            serde_json::json!({"success": false, "error": message.into()}),
+        );
+    }
+
+    /// Every request received so far, oldest first
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state
+            .requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Requests received for `method path`, oldest first
+    pub fn requests_to(&self, method: Method, path: &str) -> Vec<RecordedRequest> {
+        self.requests()
+            .into_iter()
+            .filter(|request| request.method == method && request.path == path)
+            .collect()
+    }
+
+    /// Forget recorded requests, keeping registered responses
+    pub fn clear_requests(&self) {
+        self.state
+            .requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+
+    fn route(&self, method: Method, path: String, status: StatusCode, body: serde_json::Value) {
+        self.state
+            .routes
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(Route {
+                method,
+                path,
+                status,
+                body,
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, SendEmailParams};
+
+    #[tokio::test]
+    async fn test_records_requests_and_replays_responses() {
+        let mock = MockMailBreeze::new();
+        mock.respond(
+            Method::POST,
+            "/emails",
+            serde_json::json!({"messageId": "msg_1"}),
+        );
+
+        let result = mock
+            .client()
+            .emails
+            .send(&SendEmailParams {
+                from: "a@example.com".to_string(),
+                to: vec!["b@example.com".to_string()],
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(result.message_id, "msg_1");
+
+        let sent = mock.requests_to(Method::POST, "/emails");
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].body.as_ref().unwrap()["from"], "a@example.com");
+    }
+
+    #[tokio::test]
+    async fn test_errors_and_unmatched_requests() {
+        let mock = MockMailBreeze::new();
+        mock.respond_error(Method::GET, "/emails/e_1", 503, "Unavailable");
+
+        let error = mock.client().emails.get("e_1").await.unwrap_err();
+        assert_eq!(error.status_code(), Some(503));
+        assert_eq!(mock.requests().len(), 1);
+
+        let error = mock.client().emails.get("e_2").await.unwrap_err();
+        assert!(matches!(error, Error::NotFound { .. }));
+    }
+}
@@ -0,0 +1,20 @@
+//! Test helpers for code that uses the SDK
+//!
+//! Enabled with the `testing` feature.
+//!
+//! - [`MockMailBreeze`] answers requests from canned responses in memory, for
+//!   unit tests that shouldn't need an HTTP server.
+//! - [`CassetteTransport`] records real request/response pairs to a file and
+//!   replays them offline, for reproducible integration tests.
+//! - [`fixtures`] adds constructors such as `Contact::fake()` for realistic
+//!   API objects.
+
+mod cassette;
+pub mod fixtures;
+mod mock;
+
+pub use cassette::{CassetteMode, CassetteTransport};
+pub use mock::{MockMailBreeze, RecordedRequest};
+
+/// Path prefix stripped from recorded request paths
+const API_PREFIX: &str = "/api/v1";
@@ -0,0 +1,116 @@
+//! Per-domain send pacing, enabled with `ClientConfig::send_rate_per_domain`
+//!
+//! Sends from the same domain are spaced evenly over the minute, so a burst
+//! is released at the configured rate instead of all at once. Each domain
+//! keeps its own schedule; domains without a rate are never delayed.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
+
+#[derive(Debug)]
+pub(crate) struct SendThrottle {
+    default_rate: Option<u32>,
+    rates: HashMap<String, u32>,
+    /// Earliest time the next send from each domain may go out
+    next_slot: Mutex<HashMap<String, Instant>>,
+}
+
+impl SendThrottle {
+    /// Returns `None` when no domain has a rate
+    pub(crate) fn new(default_rate: Option<u32>, overrides: &[(String, u32)]) -> Option<Self> {
+        if default_rate.is_none() && overrides.is_empty() {
+            return None;
+        }
+        let rates = overrides
+            .iter()
+            .map(|(domain, rate)| (domain.to_ascii_lowercase(), *rate))
+            .collect();
+        Some(Self {
+            default_rate,
+            rates,
+            next_slot: Mutex::default(),
+        })
+    }
+
+    /// Wait until a send from `from` fits within its domain's rate
+    pub(crate) async fn wait(&self, from: &str) {
+        let Some(slot) = self.reserve(from, Instant::now()) else {
+            return;
+        };
+        if slot > Instant::now() {
+            log::debug!(target: "mailbreeze", "pacing send from {}", from);
+            tokio::time::sleep_until(slot).await;
+        }
+    }
+
+    /// Claim the next free slot for `from`'s domain
+    fn reserve(&self, from: &str, now: Instant) -> Option<Instant> {
+        let domain = sender_domain(from)?;
+        let rate = self.rates.get(&domain).copied().or(self.default_rate)?;
+        let interval = Duration::from_secs(60) / rate.max(1);
+
+        let mut next_slot = self.next_slot.lock().unwrap_or_else(|e| e.into_inner());
+        // Drop schedules that have lapsed so idle domains don't accumulate
+        next_slot.retain(|_, slot| *slot > now);
+        let slot = next_slot.get(&domain).copied().unwrap_or(now).max(now);
+        next_slot.insert(domain, slot + interval);
+        Some(slot)
+    }
+}
+
+/// Lowercased domain of a `from` address, with or without a display name
+fn sender_domain(from: &str) -> Option<String> {
+    let address = match (from.rfind('<'), from.rfind('>')) {
+        (Some(start), Some(end)) if start < end => &from[start + 1..end],
+        _ => from,
+    };
+    let (_, domain) = address.trim().rsplit_once('@')?;
+    (!domain.is_empty()).then(|| domain.to_ascii_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sender_domain() {
+        assert_eq!(sender_domain("a@Example.com").unwrap(), "example.com");
+        assert_eq!(
+            sender_domain("Acme <news@mail.acme.io>").unwrap(),
+            "mail.acme.io"
+        );
+        assert_eq!(sender_domain("no-at-sign"), None);
+    }
+
+    #[test]
+    fn test_reserve_spaces_sends_per_domain() {
+        let throttle =
+            SendThrottle::new(Some(60), &[("bulk.example.com".to_string(), 120)]).unwrap();
+        let now = Instant::now();
+
+        // 60/min: one send per second from the same domain
+        assert_eq!(throttle.reserve("a@example.com", now), Some(now));
+        assert_eq!(
+            throttle.reserve("b@example.com", now),
+            Some(now + Duration::from_secs(1))
+        );
+        // Other domains have their own schedule
+        assert_eq!(throttle.reserve("a@other.com", now), Some(now));
+        // Overrides apply by domain
+        assert_eq!(throttle.reserve("a@bulk.example.com", now), Some(now));
+        assert_eq!(
+            throttle.reserve("Bulk <b@BULK.example.com>", now),
+            Some(now + Duration::from_millis(500))
+        );
+    }
+
+    #[test]
+    fn test_overrides_only_leave_other_domains_unpaced() {
+        let throttle = SendThrottle::new(None, &[("example.com".to_string(), 10)]).unwrap();
+        let now = Instant::now();
+        assert_eq!(throttle.reserve("a@other.com", now), None);
+        assert!(SendThrottle::new(None, &[]).is_none());
+    }
+}
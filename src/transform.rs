@@ -0,0 +1,116 @@
+//! Response rewriting for MailBreeze-compatible deployments
+//!
+//! Self-hosted gateways may not follow the hosted API's camelCase field naming.
+//! Responses can be normalised before they are decoded, either with a built-in
+//! [`FieldCase`] conversion or a custom [`ResponseTransform`].
+
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Hook that rewrites a decoded JSON response body before it is deserialized
+pub type ResponseTransform = Arc<dyn Fn(&mut Value) + Send + Sync>;
+
+/// Field naming used by the API's responses
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FieldCase {
+    /// `messageId`, as sent by the hosted API
+    #[default]
+    Camel,
+    /// `message_id`; field names are converted to camelCase before decoding,
+    /// while keys inside user maps such as `metadata` are kept as sent
+    Snake,
+}
+
+/// Fields whose values are maps keyed by user data (metadata keys, custom
+/// field names, template variables, ...), after conversion to camelCase
+const USER_KEYED_FIELDS: &[&str] = &[
+    "metadata",
+    "variables",
+    "customFields",
+    "headers",
+    "errors",
+    "counts",
+    "totals",
+    "mapping",
+];
+
+/// Recursively convert struct field names from snake_case to camelCase
+///
+/// The keys inside [`USER_KEYED_FIELDS`] are left as the user wrote them.
+pub(crate) fn camel_case_keys(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            let entries = std::mem::take(map);
+            for (key, mut value) in entries {
+                let key = to_camel_case(&key);
+                if !USER_KEYED_FIELDS.contains(&key.as_str()) {
+                    camel_case_keys(&mut value);
+                }
+                map.insert(key, value);
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(camel_case_keys),
+        _ => {}
+    }
+}
+
+fn to_camel_case(key: &str) -> String {
+    let mut out = String::with_capacity(key.len());
+    let mut upper = false;
+    for c in key.chars() {
+        if c == '_' && !out.is_empty() {
+            upper = true;
+        } else if upper {
+            out.extend(c.to_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_camel_case_keys_recurses() {
+        let mut value = serde_json::json!({
+            "message_id": "msg_1",
+            "_id": "abc",
+            "items": [{"created_at": "2024-01-01"}],
+            "hasNext": true
+        });
+        camel_case_keys(&mut value);
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "messageId": "msg_1",
+                "_id": "abc",
+                "items": [{"createdAt": "2024-01-01"}],
+                "hasNext": true
+            })
+        );
+    }
+
+    #[test]
+    fn test_camel_case_keys_keeps_user_map_keys() {
+        let mut value = serde_json::json!({
+            "custom_fields": {"plan_tier": "pro"},
+            "metadata": {"order_id": "1001"},
+            "variables": {"first_name": "Jane", "items": [{"sku_code": "A1"}]},
+            "errors": {"to_address": ["is invalid"]}
+        });
+        camel_case_keys(&mut value);
+        assert_eq!(
+            value,
+            serde_json::json!({
+                "customFields": {"plan_tier": "pro"},
+                "metadata": {"order_id": "1001"},
+                "variables": {"first_name": "Jane", "items": [{"sku_code": "A1"}]},
+                "errors": {"to_address": ["is invalid"]}
+            })
+        );
+    }
+}
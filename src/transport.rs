@@ -0,0 +1,206 @@
+use crate::client::ClientConfig;
+use crate::error::{Error, Result};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::stream::{self, BoxStream, StreamExt, TryStreamExt};
+use reqwest::header::HeaderMap;
+use reqwest::{Client, Method, StatusCode, Url};
+use std::time::Duration;
+
+/// A fully-built request handed to a [`Transport`]
+///
+/// The URL already contains the API version prefix and any query parameters,
+/// and the headers include authentication.
+#[derive(Debug, Clone)]
+pub struct TransportRequest {
+    pub method: Method,
+    pub url: Url,
+    pub headers: HeaderMap,
+    pub body: Option<Vec<u8>>,
+    /// Per-request timeout overriding the transport's default
+    pub timeout: Option<Duration>,
+}
+
+/// Raw response returned by a [`Transport`]
+#[derive(Debug, Clone)]
+pub struct TransportResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+/// Response whose body is read as it arrives, from [`Transport::send_streaming`]
+pub struct StreamingResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: BoxStream<'static, Result<Bytes>>,
+}
+
+impl StreamingResponse {
+    /// Read the rest of the body into a [`TransportResponse`]
+    pub async fn buffer(self) -> Result<TransportResponse> {
+        let chunks: Vec<Bytes> = self.body.try_collect().await?;
+        Ok(TransportResponse {
+            status: self.status,
+            headers: self.headers,
+            body: chunks.concat(),
+        })
+    }
+}
+
+impl std::fmt::Debug for StreamingResponse {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("StreamingResponse")
+            .field("status", &self.status)
+            .field("headers", &self.headers)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The HTTP layer underneath [`HttpClient`](crate::HttpClient)
+///
+/// Implement this to route requests through a different HTTP stack or a test
+/// double. Retries, error mapping and response decoding are handled by the
+/// client, so a transport only has to perform a single round trip.
+///
+/// Connection failures should be reported as [`Error::Transport`] with
+/// `retryable: true` (or [`Error::Timeout`]) so the client's retry loop can
+/// pick them up.
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::{MailBreeze, Transport, TransportRequest, TransportResponse};
+///
+/// #[derive(Debug)]
+/// struct LoggingTransport(mailbreeze::ReqwestTransport);
+///
+/// #[mailbreeze::async_trait]
+/// impl Transport for LoggingTransport {
+///     async fn send(&self, request: TransportRequest) -> mailbreeze::Result<TransportResponse> {
+///         println!("{} {}", request.method, request.url);
+///         self.0.send(request).await
+///     }
+/// }
+///
+/// # fn main() -> mailbreeze::Result<()> {
+/// let inner = mailbreeze::ReqwestTransport::new(std::time::Duration::from_secs(30))?;
+/// let client = MailBreeze::builder("your_api_key")
+///     .transport(LoggingTransport(inner))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[async_trait]
+pub trait Transport: std::fmt::Debug + Send + Sync {
+    /// Perform a single HTTP round trip
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse>;
+
+    /// Perform a single HTTP round trip, returning as soon as the headers arrive
+    ///
+    /// Used for downloads and exports. The default implementation buffers the
+    /// body with [`send`](Self::send); [`ReqwestTransport`] streams it.
+    async fn send_streaming(&self, request: TransportRequest) -> Result<StreamingResponse> {
+        let response = self.send(request).await?;
+        Ok(StreamingResponse {
+            status: response.status,
+            headers: response.headers,
+            body: stream::once(async move { Ok(Bytes::from(response.body)) }).boxed(),
+        })
+    }
+}
+
+/// Default [`Transport`] backed by `reqwest`
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    /// Create a transport with the given request timeout
+    ///
+    /// gzip and brotli responses are negotiated and decoded transparently.
+    pub fn new(timeout: Duration) -> Result<Self> {
+        Self::with_decompression(timeout, true)
+    }
+
+    /// Create a transport, choosing whether to advertise compressed responses
+    pub fn with_decompression(timeout: Duration, enabled: bool) -> Result<Self> {
+        let client = Client::builder()
+            .timeout(timeout)
+            .gzip(enabled)
+            .brotli(enabled)
+            .build()
+            .map_err(Error::Http)?;
+
+        Ok(Self { client })
+    }
+
+    /// Create the default transport for a client configuration, including TLS settings
+    pub(crate) fn from_config(config: &ClientConfig) -> Result<Self> {
+        Ok(Self {
+            client: reqwest_client(config)?,
+        })
+    }
+
+    /// Wrap an existing `reqwest::Client`
+    pub fn from_client(client: Client) -> Self {
+        Self { client }
+    }
+
+    /// A `reqwest` request for `request`, without sending it
+    fn request(&self, request: TransportRequest) -> reqwest::RequestBuilder {
+        let mut builder = self
+            .client
+            .request(request.method, request.url)
+            .headers(request.headers);
+
+        if let Some(body) = request.body {
+            builder = builder.body(body);
+        }
+
+        if let Some(timeout) = request.timeout {
+            builder = builder.timeout(timeout);
+        }
+        builder
+    }
+}
+
+/// A `reqwest::Client` with the timeout, compression and TLS settings of `config`
+pub(crate) fn reqwest_client(config: &ClientConfig) -> Result<Client> {
+    let mut builder = Client::builder()
+        .timeout(config.timeout)
+        .gzip(config.decompress_responses)
+        .brotli(config.decompress_responses);
+    for certificate in &config.root_certificates {
+        builder = builder.add_root_certificate(certificate.clone());
+    }
+    if let Some(identity) = &config.client_identity {
+        builder = builder.identity(identity.clone());
+    }
+    builder.build().map_err(Error::Http)
+}
+
+#[async_trait]
+impl Transport for ReqwestTransport {
+    async fn send(&self, request: TransportRequest) -> Result<TransportResponse> {
+        let response = self.request(request).send().await.map_err(Error::Http)?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes().await.map_err(Error::Http)?.to_vec();
+
+        Ok(TransportResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+
+    async fn send_streaming(&self, request: TransportRequest) -> Result<StreamingResponse> {
+        let response = self.request(request).send().await.map_err(Error::Http)?;
+        Ok(StreamingResponse {
+            status: response.status(),
+            headers: response.headers().clone(),
+            body: response.bytes_stream().map_err(Error::Http).boxed(),
+        })
+    }
+}
@@ -1,8 +1,64 @@
+use crate::headers::{Headers, UnsubscribeConfig};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Range filter for list params, encoded as `field[gte]=..&field[lte]=..`
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::{ListEmailsParams, RangeFilter};
+///
+/// let params = ListEmailsParams {
+///     created_at: Some(RangeFilter::between("2024-01-01", "2024-01-31")),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct RangeFilter<T> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gte: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gt: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lte: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lt: Option<T>,
+}
+
+impl<T> RangeFilter<T> {
+    /// Inclusive range on both ends
+    pub fn between(from: impl Into<T>, to: impl Into<T>) -> Self {
+        Self {
+            gte: Some(from.into()),
+            gt: None,
+            lte: Some(to.into()),
+            lt: None,
+        }
+    }
+
+    /// Values greater than or equal to `from`
+    pub fn since(from: impl Into<T>) -> Self {
+        Self {
+            gte: Some(from.into()),
+            gt: None,
+            lte: None,
+            lt: None,
+        }
+    }
+
+    /// Values strictly less than `to`
+    pub fn before(to: impl Into<T>) -> Self {
+        Self {
+            gte: None,
+            gt: None,
+            lte: None,
+            lt: Some(to.into()),
+        }
+    }
+}
+
 /// Pagination information returned with list endpoints
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Pagination {
     pub page: i32,
@@ -16,8 +72,11 @@ pub struct Pagination {
 }
 
 /// Email delivery status
+///
+/// Statuses added to the API after this SDK release arrive as `Other`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum EmailStatus {
     #[default]
     Pending,
@@ -27,6 +86,8 @@ pub enum EmailStatus {
     Bounced,
     Complained,
     Failed,
+    #[serde(untagged)]
+    Other(String),
 }
 
 /// Result from sending an email
@@ -34,6 +95,53 @@ pub enum EmailStatus {
 #[serde(rename_all = "camelCase")]
 pub struct SendEmailResult {
     pub message_id: String,
+    /// The `Message-ID` header of the sent email; pass it as
+    /// [`SendEmailParams::in_reply_to`] to thread a follow-up
+    #[serde(default)]
+    pub message_id_header: Option<String>,
+}
+
+/// Server-side check of a send payload, from `emails.validate`
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendValidation {
+    /// Whether the payload would be accepted by `emails.send`
+    pub valid: bool,
+    #[serde(default)]
+    pub errors: Vec<SendValidationIssue>,
+    /// Template variables referenced by the template but absent from `variables`
+    #[serde(default)]
+    pub missing_variables: Vec<String>,
+    /// Estimated spam score; higher is more likely to be filtered
+    #[serde(default)]
+    pub spam_score: Option<f64>,
+    #[serde(default)]
+    pub spam_indicators: Vec<SpamIndicator>,
+    #[serde(default)]
+    pub rendered_subject: Option<String>,
+    #[serde(default)]
+    pub rendered_html: Option<String>,
+}
+
+/// A problem that would make a send fail
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendValidationIssue {
+    /// Field path, e.g. `variables.firstName`
+    pub field: String,
+    pub message: String,
+}
+
+/// A content rule that raised the spam score
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpamIndicator {
+    pub rule: String,
+    #[serde(default)]
+    pub description: String,
+    /// Contribution to `spam_score`
+    #[serde(default)]
+    pub score: f64,
 }
 
 /// Email object (from list/get endpoints)
@@ -65,40 +173,383 @@ pub struct Email {
     pub sent_at: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub delivered_at: Option<String>,
+    /// Whether opens were tracked for this email
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub track_opens: Option<bool>,
+    /// Whether links were rewritten to track clicks
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub track_clicks: Option<bool>,
+    /// Caller-supplied key/value pairs from [`SendEmailParams::metadata`]
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub metadata: Option<HashMap<String, String>>,
 }
 
 /// Parameters for sending an email
-#[derive(Debug, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct SendEmailParams {
     pub from: String,
     pub to: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subject: Option<String>,
+    /// Preview text shown after the subject in the inbox
+    ///
+    /// With `html`, the SDK inserts it as a hidden element at the top of the
+    /// body; with `template_id`, the API does.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preheader: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub html: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub text: Option<String>,
+    /// MJML markup, compiled to `html` on the client before sending
+    ///
+    /// Needs the `mjml` feature; without it, sending fails with an
+    /// [`Error::Validation`](crate::Error::Validation) on `mjml`. Replaced by
+    /// `html` before sending, so the API never sees the field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mjml: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub template_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub variables: Option<HashMap<String, serde_json::Value>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub attachment_ids: Option<Vec<String>>,
+    /// Files sent in the request body itself, without an upload step
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachments: Option<Vec<AttachmentContent>>,
+    /// Uploaded images embedded in `html` via `<img src="cid:...">`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inline_attachments: Option<Vec<InlineAttachment>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reply_to: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cc: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bcc: Option<Vec<String>>,
+    /// `Message-ID` of the email this one replies to, written as `In-Reply-To`
+    ///
+    /// Moved into `headers` before sending, so the API never sees the field.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub headers: Option<HashMap<String, String>>,
+    pub in_reply_to: Option<String>,
+    /// Message IDs of the thread, oldest first, written as `References`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub references: Option<Vec<String>>,
+    /// Custom MIME headers; checked with [`Headers::validate`] before sending
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Headers>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Accept and record the email without delivering it; unset follows
+    /// `ClientConfig::sandbox`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_mode: Option<bool>,
+    /// Unsubscribe options, written into `List-Unsubscribe` headers when sending
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unsubscribe: Option<UnsubscribeConfig>,
+    /// Your own identifiers, e.g. an order or user ID; returned on the
+    /// [`Email`] and on its webhook events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<HashMap<String, String>>,
+    /// One-off URL that receives delivery/bounce events for this message only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_url: Option<String>,
+    /// Embed an open-tracking pixel; unset uses the account default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track_opens: Option<bool>,
+    /// Rewrite links to track clicks; unset uses the account default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track_clicks: Option<bool>,
+    /// Track replies to this message; they arrive as `email.replied` webhook events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_tracking: Option<bool>,
+}
+
+/// A small file attached directly to a send, base64-encoded in the request
+///
+/// Skips the `attachment_ids` upload, confirm and send sequence for one-off
+/// files such as an invoice PDF. The whole request still counts against
+/// `max_body_size`, and base64 grows the file by a third, so upload large or
+/// reused files with [`Attachments`](crate::Attachments) (`client.attachments`)
+/// and pass their IDs in [`SendEmailParams::attachment_ids`] instead.
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::{AttachmentContent, SendEmailParams};
+///
+/// let pdf = b"%PDF-1.7 ...".to_vec();
+/// let params = SendEmailParams {
+///     from: "billing@shop.example".to_string(),
+///     to: vec!["jane@example.com".to_string()],
+///     subject: Some("Invoice 1042".to_string()),
+///     attachments: Some(vec![AttachmentContent::new("invoice-1042.pdf", "application/pdf", &pdf)]),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachmentContent {
+    pub filename: String,
+    pub content_type: String,
+    /// File contents, standard base64 with padding
+    pub content: String,
+}
+
+impl AttachmentContent {
+    /// Attach `bytes`, encoding them as base64
+    pub fn new(
+        filename: impl Into<String>,
+        content_type: impl Into<String>,
+        bytes: impl AsRef<[u8]>,
+    ) -> Self {
+        use base64::Engine;
+        Self {
+            filename: filename.into(),
+            content_type: content_type.into(),
+            content: base64::engine::general_purpose::STANDARD.encode(bytes),
+        }
+    }
+}
+
+/// An uploaded attachment embedded in the HTML body under a content ID
+///
+/// The image is sent as an inline MIME part instead of a download, so
+/// templates can show a logo without hosting it publicly.
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::{InlineAttachment, SendEmailParams};
+///
+/// let logo = InlineAttachment::new("att_logo_123", "logo");
+/// let params = SendEmailParams {
+///     from: "receipts@shop.example".to_string(),
+///     to: vec!["jane@example.com".to_string()],
+///     html: Some(format!(r#"<img src="{}" alt="Shop"><p>Thanks for your order</p>"#, logo.cid())),
+///     inline_attachments: Some(vec![logo]),
+///     ..Default::default()
+/// };
+/// assert!(params.html.unwrap().contains(r#"src="cid:logo""#));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InlineAttachment {
+    /// ID of a confirmed upload from `attachments.create_upload_url`
+    pub attachment_id: String,
+    /// Content ID the HTML refers to, without `cid:` or angle brackets
+    pub content_id: String,
+}
+
+impl InlineAttachment {
+    pub fn new(attachment_id: impl Into<String>, content_id: impl Into<String>) -> Self {
+        Self {
+            attachment_id: attachment_id.into(),
+            content_id: content_id.into(),
+        }
+    }
+
+    /// The `cid:` URL to use as an `<img src>` in the HTML body
+    pub fn cid(&self) -> String {
+        format!("cid:{}", self.content_id)
+    }
+}
+
+impl SendEmailParams {
+    /// Total number of to, cc and bcc recipients
+    pub fn recipient_count(&self) -> usize {
+        self.to.len() + self.cc.as_ref().map_or(0, Vec::len) + self.bcc.as_ref().map_or(0, Vec::len)
+    }
+
+    /// Check the parameters offline, as every send does before any request
+    ///
+    /// Catches obviously invalid payloads: malformed addresses, no `to`
+    /// recipients, and `template_id` combined with `html` or `text`. Problems
+    /// are reported together as an [`Error::Validation`](crate::Error::Validation)
+    /// keyed by field path, the same shape the API uses.
+    ///
+    /// # Example
+    /// ```rust
+    /// use mailbreeze::SendEmailParams;
+    ///
+    /// let params = SendEmailParams {
+    ///     from: "Acme <news@acme.example>".to_string(),
+    ///     to: vec!["jane@example".to_string()],
+    ///     ..Default::default()
+    /// };
+    /// let error = params.validate().unwrap_err();
+    /// assert_eq!(error.field_errors("to[0]"), vec!["must be a valid email address"]);
+    /// ```
+    pub fn validate(&self) -> crate::Result<()> {
+        let mut errors: HashMap<String, Vec<String>> = HashMap::new();
+        let mut fail = |field: String, message: &str| {
+            errors.entry(field).or_default().push(message.to_string());
+        };
+
+        if self.from.trim().is_empty() {
+            fail("from".to_string(), "is required");
+        } else if !crate::address::is_valid_mailbox(&self.from) {
+            fail("from".to_string(), "must be a valid email address");
+        }
+        if self.to.is_empty() {
+            fail("to".to_string(), "must have at least one recipient");
+        }
+        let recipients = [
+            ("to", Some(&self.to)),
+            ("cc", self.cc.as_ref()),
+            ("bcc", self.bcc.as_ref()),
+        ];
+        for (field, addresses) in recipients {
+            for (i, address) in addresses.into_iter().flatten().enumerate() {
+                if !crate::address::is_valid_mailbox(address) {
+                    fail(format!("{}[{}]", field, i), "must be a valid email address");
+                }
+            }
+        }
+        if let Some(reply_to) = &self.reply_to {
+            if !crate::address::is_valid_mailbox(reply_to) {
+                fail("replyTo".to_string(), "must be a valid email address");
+            }
+        }
+        for problem in self
+            .unsubscribe
+            .iter()
+            .flat_map(UnsubscribeConfig::problems)
+        {
+            fail("unsubscribe".to_string(), problem);
+        }
+        if self.mjml.is_some() {
+            for (field, set) in [
+                ("html", self.html.is_some()),
+                ("templateId", self.template_id.is_some()),
+            ] {
+                if set {
+                    fail(field.to_string(), "cannot be combined with mjml");
+                }
+            }
+        }
+        if self.template_id.is_some() {
+            for (field, set) in [("html", self.html.is_some()), ("text", self.text.is_some())] {
+                if set {
+                    fail(field.to_string(), "cannot be combined with templateId");
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            return Ok(());
+        }
+        let mut fields: Vec<&str> = errors.keys().map(String::as_str).collect();
+        fields.sort_unstable();
+        Err(crate::Error::Validation {
+            message: format!("invalid {}", fields.join(", ")),
+            errors,
+            code: None,
+            request_id: None,
+        })
+    }
+}
+
+/// Parameters for [`Emails::send_to_list`](crate::Emails::send_to_list)
+///
+/// Like [`SendEmailParams`] without recipients: every active contact in the
+/// list (or in `segment_id`, when set) receives the email, with the
+/// contact's fields available as template variables.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastParams {
+    pub from: String,
+    /// Send only to contacts in this segment of the list
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segment_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+    /// Variables shared by every recipient; contact fields take precedence
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<HashMap<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tags: Option<Vec<String>>,
+    /// Send later, as an RFC 3339 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_at: Option<String>,
+    /// Custom MIME headers; checked with [`Headers::validate`] before sending
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<Headers>,
+    /// Accept and record the broadcast without delivering it; unset follows
+    /// `ClientConfig::sandbox`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub test_mode: Option<bool>,
+}
+
+impl BroadcastParams {
+    /// Check the sender, reply-to address, content and headers locally
+    ///
+    /// Called by [`Emails::send_to_list`](crate::Emails::send_to_list); failures
+    /// are reported as [`Error::Validation`](crate::Error::Validation).
+    pub fn validate(&self) -> crate::Result<()> {
+        let mut errors: HashMap<String, Vec<String>> = HashMap::new();
+        let mut fail = |field: &str, message: &str| {
+            errors
+                .entry(field.to_string())
+                .or_default()
+                .push(message.to_string());
+        };
+
+        if self.from.trim().is_empty() {
+            fail("from", "is required");
+        } else if !crate::address::is_valid_mailbox(&self.from) {
+            fail("from", "must be a valid email address");
+        }
+        if let Some(reply_to) = &self.reply_to {
+            if !crate::address::is_valid_mailbox(reply_to) {
+                fail("replyTo", "must be a valid email address");
+            }
+        }
+        if self.template_id.is_some() {
+            for (field, set) in [("html", self.html.is_some()), ("text", self.text.is_some())] {
+                if set {
+                    fail(field, "cannot be combined with templateId");
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            if let Some(headers) = &self.headers {
+                headers.validate()?;
+            }
+            return Ok(());
+        }
+        let mut fields: Vec<&str> = errors.keys().map(String::as_str).collect();
+        fields.sort_unstable();
+        Err(crate::Error::Validation {
+            message: format!("invalid {}", fields.join(", ")),
+            errors,
+            code: None,
+            request_id: None,
+        })
+    }
+}
+
+/// A broadcast accepted by the API; emails go out in the background
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BroadcastResult {
+    #[serde(alias = "_id")]
+    pub id: String,
+    /// Contacts the broadcast will be sent to, after suppressions
+    pub recipient_count: i64,
+    /// e.g. `queued` or `scheduled`
+    pub status: String,
 }
 
 /// Parameters for listing emails
-#[derive(Debug, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ListEmailsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<EmailStatus>,
@@ -106,6 +557,39 @@ pub struct ListEmailsParams {
     pub page: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i32>,
+    /// Match any of these statuses (sent as repeated `statuses` keys)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub statuses: Vec<EmailStatus>,
+    /// Match emails carrying any of these tags (sent as repeated `tags` keys)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<RangeFilter<String>>,
+}
+
+/// File format for [`Emails::export`](crate::Emails::export)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// Comma-separated values with a header row
+    #[default]
+    Csv,
+    /// One JSON email object per line
+    Ndjson,
+}
+
+/// Parameters for [`Emails::export`](crate::Emails::export)
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EmailExportParams {
+    pub format: ExportFormat,
+    /// Match any of these statuses (sent as repeated `statuses` keys)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub statuses: Vec<EmailStatus>,
+    /// Match emails carrying any of these tags (sent as repeated `tags` keys)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<RangeFilter<String>>,
 }
 
 /// Paginated list of emails (API returns {emails: [], pagination: {}})
@@ -127,6 +611,68 @@ pub struct EmailStats {
     #[serde(default)]
     pub marketing: i64,
     pub success_rate: f64,
+    /// Per-period figures, when requested with [`EmailStatsParams::group_by`]
+    #[serde(default)]
+    pub series: Vec<EmailStatsBucket>,
+}
+
+/// Period length for an email statistics time series
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum EmailStatsGrouping {
+    /// One bucket per calendar day (UTC)
+    Day,
+    /// One bucket per ISO week, starting Monday
+    Week,
+}
+
+/// Parameters for [`Emails::stats_with_params`](crate::Emails::stats_with_params)
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::{EmailStatsGrouping, EmailStatsParams};
+///
+/// // Daily marketing sends tagged `newsletter` in June
+/// let params = EmailStatsParams {
+///     from: Some("2024-06-01".to_string()),
+///     to: Some("2024-06-30".to_string()),
+///     group_by: Some(EmailStatsGrouping::Day),
+///     tags: vec!["newsletter".to_string()],
+///     email_type: Some("marketing".to_string()),
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EmailStatsParams {
+    /// First day included, as `YYYY-MM-DD` or an RFC 3339 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// Last day included, as `YYYY-MM-DD` or an RFC 3339 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<EmailStatsGrouping>,
+    /// Count only emails carrying any of these tags (sent as repeated `tags` keys)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// `transactional` or `marketing`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_type: Option<String>,
+}
+
+/// Email statistics for one day or week
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailStatsBucket {
+    /// First day of the period, `YYYY-MM-DD`
+    pub period: String,
+    pub total: i64,
+    pub sent: i64,
+    pub failed: i64,
+    #[serde(default)]
+    pub transactional: i64,
+    #[serde(default)]
+    pub marketing: i64,
+    pub success_rate: f64,
 }
 
 /// Wrapper for email stats response from API
@@ -135,9 +681,87 @@ pub struct EmailStatsResponse {
     pub stats: EmailStats,
 }
 
+/// Fields of an email draft; unset fields are left unchanged on update
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailDraftParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<HashMap<String, serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub attachment_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cc: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bcc: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub headers: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+/// Email draft, assembled over several calls and sent with `send_draft`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailDraft {
+    #[serde(alias = "_id")]
+    pub id: String,
+    #[serde(default)]
+    pub from: Option<String>,
+    #[serde(default)]
+    pub to: Vec<String>,
+    #[serde(default)]
+    pub cc: Vec<String>,
+    #[serde(default)]
+    pub bcc: Vec<String>,
+    #[serde(default)]
+    pub subject: Option<String>,
+    #[serde(default)]
+    pub html: Option<String>,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub template_id: Option<String>,
+    #[serde(default)]
+    pub variables: Option<HashMap<String, serde_json::Value>>,
+    #[serde(default)]
+    pub attachment_ids: Vec<String>,
+    #[serde(default)]
+    pub reply_to: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+impl EmailDraft {
+    /// Total number of `to`, `cc` and `bcc` addresses
+    pub fn recipient_count(&self) -> usize {
+        self.to.len() + self.cc.len() + self.bcc.len()
+    }
+}
+
 /// Contact subscription status
+///
+/// Statuses added to the API after this SDK release arrive as `Other`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum ContactStatus {
     #[default]
     Active,
@@ -145,6 +769,8 @@ pub enum ContactStatus {
     Bounced,
     Complained,
     Suppressed,
+    #[serde(untagged)]
+    Other(String),
 }
 
 /// Consent type for NDPR compliance
@@ -194,7 +820,7 @@ pub struct Contact {
 }
 
 /// Parameters for creating a contact
-#[derive(Debug, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateContactParams {
     pub email: String,
@@ -216,10 +842,13 @@ pub struct CreateContactParams {
     pub consent_timestamp: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub consent_ip_address: Option<String>,
+    /// Client-generated token; repeated creates with the same token return the original contact
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotency_token: Option<String>,
 }
 
 /// Parameters for updating a contact
-#[derive(Debug, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateContactParams {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -241,7 +870,7 @@ pub struct UpdateContactParams {
 }
 
 /// Parameters for listing contacts
-#[derive(Debug, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ListContactsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub status: Option<ContactStatus>,
@@ -249,6 +878,11 @@ pub struct ListContactsParams {
     pub page: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub limit: Option<i32>,
+    /// Match any of these statuses (sent as repeated `statuses` keys)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub statuses: Vec<ContactStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<RangeFilter<String>>,
 }
 
 /// Paginated list of contacts (API returns {contacts: [], pagination: {}})
@@ -258,15 +892,56 @@ pub struct ContactsResponse {
     pub pagination: Pagination,
 }
 
+/// A custom contact field defined on a list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomFieldDefinition {
+    /// Key used in `Contact::custom_fields`
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub label: Option<String>,
+    /// `text`, `number`, `date` or `boolean`
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none", default)]
+    pub field_type: Option<String>,
+}
+
+/// Options for exporting a list's contacts to CSV
+#[derive(Debug, Clone, Default)]
+pub struct CsvExportOptions {
+    /// Filters for the exported contacts; `page` and `limit` are managed by the export
+    pub filter: ListContactsParams,
+    /// Write each custom field from the list's schema as its own
+    /// `customFields.<key>` column instead of one JSON column
+    pub flatten_custom_fields: bool,
+}
+
 /// Reason for suppressing a contact
+///
+/// Reasons added to the API after this SDK release arrive as `Other`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum SuppressReason {
     Manual,
     Unsubscribed,
     Bounced,
     Complained,
     SpamTrap,
+    #[serde(untagged)]
+    Other(String),
+}
+
+impl std::fmt::Display for SuppressReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SuppressReason::Manual => "manual",
+            SuppressReason::Unsubscribed => "unsubscribed",
+            SuppressReason::Bounced => "bounced",
+            SuppressReason::Complained => "complained",
+            SuppressReason::SpamTrap => "spam_trap",
+            SuppressReason::Other(reason) => reason,
+        })
+    }
 }
 
 /// Contact list object
@@ -293,7 +968,7 @@ pub struct List {
 }
 
 /// Parameters for creating a list
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateListParams {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -301,7 +976,7 @@ pub struct CreateListParams {
 }
 
 /// Parameters for updating a list
-#[derive(Debug, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct UpdateListParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -310,7 +985,7 @@ pub struct UpdateListParams {
 }
 
 /// Parameters for listing lists
-#[derive(Debug, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ListListsParams {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub page: Option<i32>,
@@ -318,63 +993,323 @@ pub struct ListListsParams {
     pub limit: Option<i32>,
 }
 
-/// Contact list statistics
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct ListStats {
-    pub total_contacts: i64,
-    pub active_contacts: i64,
-    #[serde(default)]
-    pub suppressed_contacts: i64,
-}
-
-/// Verification result status
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Time bucket size for grouped statistics
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
-pub enum VerificationStatus {
-    Clean,
-    Dirty,
-    Valid,
-    Invalid,
-    Risky,
-    Unknown,
+pub enum StatsInterval {
+    Day,
+    Week,
+    Month,
 }
 
-/// Single email verification result
-#[derive(Debug, Clone, Deserialize)]
+/// Parameters for contact source attribution
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
-pub struct VerificationResult {
-    pub email: String,
-    pub status: VerificationStatus,
+pub struct ListSourcesParams {
+    /// Start date (YYYY-MM-DD), inclusive
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub remarks: Option<String>,
-    // Legacy fields (for compatibility)
-    #[serde(default)]
-    pub is_valid: bool,
-    #[serde(default)]
-    pub is_disposable: bool,
-    #[serde(default)]
-    pub is_role_based: bool,
-    #[serde(default)]
-    pub is_free_provider: bool,
-    #[serde(default)]
-    pub mx_found: bool,
+    pub from: Option<String>,
+    /// End date (YYYY-MM-DD), inclusive
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub smtp_check: Option<bool>,
+    pub to: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub suggestion: Option<String>,
+    pub interval: Option<StatsInterval>,
 }
 
-/// Categorized batch results (clean, dirty, unknown email lists)
-#[derive(Debug, Clone, Deserialize, Default)]
+/// Contacts added per source in one time bucket
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct BatchResults {
+pub struct SourceBucket {
+    /// Start of the bucket (YYYY-MM-DD)
+    pub period: String,
+    /// Contact counts keyed by source (`api`, `import`, `form`, ...)
     #[serde(default)]
-    pub clean: Vec<String>,
+    pub counts: HashMap<String, i64>,
+}
+
+/// Contact counts grouped by acquisition source
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListSources {
+    /// Totals over the whole range, keyed by source
     #[serde(default)]
-    pub dirty: Vec<String>,
+    pub totals: HashMap<String, i64>,
     #[serde(default)]
-    pub unknown: Vec<String>,
+    pub series: Vec<SourceBucket>,
+}
+
+/// Maps CSV columns to contact fields for an import
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::ImportMapping;
+///
+/// let mapping = ImportMapping::new()
+///     .email("Email Address")
+///     .first_name("First Name")
+///     .custom_field("plan", "Subscription");
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct ImportMapping {
+    /// Contact field keyed by CSV column header
+    pub columns: HashMap<String, String>,
+}
+
+impl ImportMapping {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn email(self, column: impl Into<String>) -> Self {
+        self.column(column, "email")
+    }
+
+    pub fn first_name(self, column: impl Into<String>) -> Self {
+        self.column(column, "firstName")
+    }
+
+    pub fn last_name(self, column: impl Into<String>) -> Self {
+        self.column(column, "lastName")
+    }
+
+    pub fn phone_number(self, column: impl Into<String>) -> Self {
+        self.column(column, "phoneNumber")
+    }
+
+    /// Store `column` in the custom field `name`
+    pub fn custom_field(self, name: &str, column: impl Into<String>) -> Self {
+        self.column(column, format!("customFields.{}", name))
+    }
+
+    /// Map `column` to a raw contact field path
+    pub fn column(mut self, column: impl Into<String>, field: impl Into<String>) -> Self {
+        self.columns.insert(column.into(), field.into());
+        self
+    }
+}
+
+/// Parameters for importing contacts from a remote CSV
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportFromUrlParams {
+    /// CSV location the API fetches, e.g. a signed URL or a public sheet export
+    pub source_url: String,
+    pub mapping: ImportMapping,
+    /// Update contacts that already exist instead of skipping them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub update_existing: Option<bool>,
+}
+
+/// Progress of a contact import
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContactImport {
+    #[serde(alias = "_id")]
+    pub id: String,
+    /// `pending`, `processing`, `completed` or `failed`
+    pub status: String,
+    #[serde(default)]
+    pub total_rows: i64,
+    #[serde(default)]
+    pub imported: i64,
+    #[serde(default)]
+    pub skipped: i64,
+    #[serde(default)]
+    pub failed: i64,
+    /// Why the import failed, e.g. the source URL was unreachable
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub completed_at: Option<String>,
+}
+
+impl ContactImport {
+    /// Whether the import has finished, successfully or not
+    pub fn is_complete(&self) -> bool {
+        matches!(self.status.as_str(), "completed" | "failed")
+    }
+}
+
+/// Contact list statistics
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListStats {
+    pub total_contacts: i64,
+    pub active_contacts: i64,
+    #[serde(default)]
+    pub suppressed_contacts: i64,
+}
+
+/// Verification result status
+///
+/// Statuses added to the API after this SDK release arrive as `Other`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum VerificationStatus {
+    Clean,
+    Dirty,
+    Valid,
+    Invalid,
+    Risky,
+    Unknown,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Single email verification result
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationResult {
+    pub email: String,
+    pub status: VerificationStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remarks: Option<String>,
+    // Legacy fields (for compatibility)
+    #[serde(default)]
+    pub is_valid: bool,
+    #[serde(default)]
+    pub is_disposable: bool,
+    #[serde(default)]
+    pub is_role_based: bool,
+    #[serde(default)]
+    pub is_free_provider: bool,
+    #[serde(default)]
+    pub mx_found: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub smtp_check: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestion: Option<String>,
+}
+
+impl VerificationResult {
+    /// A corrected address for an obvious typo, if there is one
+    ///
+    /// Uses the API's `suggestion` when present, otherwise a built-in table of
+    /// misspelled provider domains (`gmial.com`, `hotmal.com`, ...).
+    pub fn suggested_email(&self) -> Option<String> {
+        self.suggestion
+            .clone()
+            .filter(|suggestion| !suggestion.eq_ignore_ascii_case(&self.email))
+            .or_else(|| crate::typo::correct_domain(&self.email))
+    }
+
+    /// The address to use: the suggested correction, or the original email
+    pub fn apply_suggestion(&self) -> String {
+        self.suggested_email().unwrap_or_else(|| self.email.clone())
+    }
+}
+
+/// Options for [`Contacts::create_verified`](crate::Contacts::create_verified)
+#[derive(Debug, Clone, Default)]
+pub struct CreateVerifiedOptions {
+    /// Decides which verification results are rejected
+    pub policy: VerificationPolicy,
+    /// Replace an address that has a suggested correction, if the correction verifies
+    pub auto_correct: bool,
+}
+
+/// Outcome of [`Contacts::create_verified`](crate::Contacts::create_verified)
+#[derive(Debug, Clone)]
+pub struct VerifiedContact {
+    /// The created contact, or `None` if verification rejected the address
+    pub contact: Option<Contact>,
+    /// Verification of the address that was used
+    pub verification: VerificationResult,
+    /// The address as submitted, when auto-correct replaced it
+    pub corrected_from: Option<String>,
+}
+
+/// Categorized batch results (clean, dirty, unknown email lists)
+#[derive(Debug, Clone, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchResults {
+    #[serde(default)]
+    pub clean: Vec<String>,
+    #[serde(default)]
+    pub dirty: Vec<String>,
+    #[serde(default)]
+    pub unknown: Vec<String>,
+}
+
+/// Category an email falls into after verification
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum VerificationCategory {
+    Clean,
+    Dirty,
+    Unknown,
+}
+
+/// Client-side strictness rules for categorizing verification results
+///
+/// Different products need different strictness: a signup form might reject
+/// disposable addresses but accept role accounts, while a B2B outreach tool
+/// might reject free providers too.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct VerificationPolicy {
+    /// Keep disposable addresses in the clean category
+    pub allow_disposable: bool,
+    /// Keep role-based addresses (info@, support@) in the clean category
+    pub allow_role_based: bool,
+    /// Keep free-provider addresses (gmail.com, yahoo.com) in the clean category
+    pub allow_free_providers: bool,
+    /// Move unknown/risky results into the dirty category
+    pub treat_unknown_as_dirty: bool,
+}
+
+impl Default for VerificationPolicy {
+    fn default() -> Self {
+        Self {
+            allow_disposable: false,
+            allow_role_based: true,
+            allow_free_providers: true,
+            treat_unknown_as_dirty: false,
+        }
+    }
+}
+
+impl VerificationPolicy {
+    /// Categorize a single verification result under this policy
+    pub fn categorize(&self, result: &VerificationResult) -> VerificationCategory {
+        let category = match result.status {
+            VerificationStatus::Clean | VerificationStatus::Valid => VerificationCategory::Clean,
+            VerificationStatus::Dirty | VerificationStatus::Invalid => VerificationCategory::Dirty,
+            VerificationStatus::Risky
+            | VerificationStatus::Unknown
+            | VerificationStatus::Other(_) => VerificationCategory::Unknown,
+        };
+
+        match category {
+            VerificationCategory::Clean
+                if (result.is_disposable && !self.allow_disposable)
+                    || (result.is_role_based && !self.allow_role_based)
+                    || (result.is_free_provider && !self.allow_free_providers) =>
+            {
+                VerificationCategory::Dirty
+            }
+            VerificationCategory::Unknown if self.treat_unknown_as_dirty => {
+                VerificationCategory::Dirty
+            }
+            category => category,
+        }
+    }
+
+    /// Re-categorize batch results under this policy
+    ///
+    /// Batch results only carry categorized addresses, so only
+    /// `treat_unknown_as_dirty` can be applied here.
+    pub fn apply_to_batch(&self, results: &BatchResults) -> BatchResults {
+        let mut results = results.clone();
+        if self.treat_unknown_as_dirty {
+            results.dirty.append(&mut results.unknown);
+        }
+        results
+    }
 }
 
 /// Analytics for batch verification
@@ -419,6 +1354,92 @@ pub struct BatchVerificationResult {
     pub completed_at: Option<String>,
 }
 
+impl BatchVerificationResult {
+    /// Whether the batch has finished, successfully or not
+    pub fn is_complete(&self) -> bool {
+        matches!(self.status.as_str(), "completed" | "failed" | "cancelled")
+    }
+}
+
+/// A sub-batch of a chunked verification that did not complete
+#[derive(Debug, Clone)]
+pub struct ChunkFailure {
+    /// Position of the chunk, starting at 0
+    pub chunk: usize,
+    /// The chunk's emails, so they can be resubmitted
+    pub emails: Vec<String>,
+    /// Verification ID, if the chunk was accepted before failing
+    pub verification_id: Option<String>,
+    pub message: String,
+}
+
+/// One report merged from the sub-batches of a chunked verification
+#[derive(Debug, Clone, Default)]
+pub struct AggregateBatchResult {
+    /// Completed sub-batches, in chunk order
+    pub batches: Vec<BatchVerificationResult>,
+    /// Categorized emails across all completed sub-batches
+    pub results: BatchResults,
+    /// Counts across all completed sub-batches
+    pub analytics: BatchAnalytics,
+    /// Chunks that failed to submit, failed server-side, or timed out
+    pub failures: Vec<ChunkFailure>,
+}
+
+impl AggregateBatchResult {
+    /// Merge a completed sub-batch into the report
+    pub fn add_batch(&mut self, batch: BatchVerificationResult) {
+        let (clean, dirty, unknown) = match (&batch.analytics, &batch.results) {
+            (Some(analytics), _) => (
+                analytics.clean_count,
+                analytics.dirty_count,
+                analytics.unknown_count,
+            ),
+            (None, Some(results)) => (
+                results.clean.len() as i32,
+                results.dirty.len() as i32,
+                results.unknown.len() as i32,
+            ),
+            (None, None) => (0, 0, 0),
+        };
+        self.analytics.clean_count += clean;
+        self.analytics.dirty_count += dirty;
+        self.analytics.unknown_count += unknown;
+        let total =
+            self.analytics.clean_count + self.analytics.dirty_count + self.analytics.unknown_count;
+        self.analytics.clean_percentage = if total > 0 {
+            f64::from(self.analytics.clean_count) * 100.0 / f64::from(total)
+        } else {
+            0.0
+        };
+
+        if let Some(results) = &batch.results {
+            self.results.clean.extend(results.clean.iter().cloned());
+            self.results.dirty.extend(results.dirty.iter().cloned());
+            self.results.unknown.extend(results.unknown.iter().cloned());
+        }
+        self.batches.push(batch);
+    }
+
+    /// Record a chunk that did not complete
+    pub fn add_failure(&mut self, failure: ChunkFailure) {
+        self.failures.push(failure);
+    }
+
+    /// Whether every chunk completed
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Emails from failed chunks, ready to resubmit
+    pub fn failed_emails(&self) -> Vec<String> {
+        self.failures
+            .iter()
+            .flat_map(|failure| failure.emails.iter().cloned())
+            .collect()
+    }
+}
+
 /// Verification statistics
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -429,6 +1450,64 @@ pub struct VerificationStats {
     pub total_unknown: i64,
     pub total_verifications: i64,
     pub valid_percentage: f64,
+    /// Verification credits consumed in the period
+    #[serde(default)]
+    pub credits_used: Option<i64>,
+    /// Per-group figures, when requested with [`VerificationStatsParams::group_by`]
+    #[serde(default)]
+    pub breakdown: Vec<VerificationStatsBucket>,
+}
+
+/// How to group verification statistics
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum VerificationStatsGrouping {
+    /// One bucket per calendar day (UTC)
+    Day,
+    /// One bucket per verification batch
+    Batch,
+    /// One bucket per contact source, e.g. an acquisition channel
+    Source,
+}
+
+/// Parameters for [`Verification::stats_with_params`](crate::Verification::stats_with_params)
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::{VerificationStatsGrouping, VerificationStatsParams};
+///
+/// // June's verification quality per acquisition channel
+/// let params = VerificationStatsParams {
+///     from: Some("2024-06-01".to_string()),
+///     to: Some("2024-06-30".to_string()),
+///     group_by: Some(VerificationStatsGrouping::Source),
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct VerificationStatsParams {
+    /// First day included, as `YYYY-MM-DD` or an RFC 3339 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    /// Last day included, as `YYYY-MM-DD` or an RFC 3339 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_by: Option<VerificationStatsGrouping>,
+}
+
+/// Verification statistics for one day, batch or source
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationStatsBucket {
+    /// The day (`YYYY-MM-DD`), batch ID or source name
+    pub key: String,
+    pub total_verified: i64,
+    pub total_valid: i64,
+    pub total_invalid: i64,
+    pub total_unknown: i64,
+    pub valid_percentage: f64,
+    #[serde(default)]
+    pub credits_used: Option<i64>,
 }
 
 /// Verification list item (returned by list endpoint)
@@ -467,7 +1546,7 @@ pub struct UploadUrl {
 }
 
 /// Parameters for creating an upload URL
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateUploadParams {
     pub filename: String,
@@ -475,6 +1554,23 @@ pub struct CreateUploadParams {
     pub size: i64,
 }
 
+/// Attachment lifecycle status
+///
+/// Statuses added to the API after this SDK release arrive as `Other`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum AttachmentStatus {
+    /// Upload URL issued, upload not yet confirmed
+    Pending,
+    /// Confirmed and usable in sends
+    Ready,
+    /// Past its TTL; emails referencing it can no longer be sent
+    Expired,
+    #[serde(untagged)]
+    Other(String),
+}
+
 /// Attachment object
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -483,8 +1579,27 @@ pub struct Attachment {
     pub filename: String,
     pub content_type: String,
     pub size: i64,
-    pub status: String,
+    pub status: AttachmentStatus,
     pub created_at: String,
+    /// When the stored file is deleted, unless extended
+    #[serde(default)]
+    pub expires_at: Option<String>,
+}
+
+impl Attachment {
+    /// Whether the attachment has lapsed, by status or by `expires_at`
+    ///
+    /// Check before resending an old email: sends referencing an expired
+    /// attachment are rejected.
+    pub fn is_expired(&self) -> bool {
+        if self.status == AttachmentStatus::Expired {
+            return true;
+        }
+        self.expires_at
+            .as_deref()
+            .and_then(|at| chrono::DateTime::parse_from_rfc3339(at).ok())
+            .is_some_and(|at| at <= chrono::Utc::now())
+    }
 }
 
 /// Cancel email result
@@ -495,14 +1610,902 @@ pub struct CancelEmailResult {
 }
 
 /// Suppress params for contact suppression
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SuppressParams {
     pub reason: SuppressReason,
 }
 
+/// An address on the account-wide suppression list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Suppression {
+    pub email: String,
+    pub reason: SuppressReason,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub created_at: Option<String>,
+}
+
+/// Parameters for adding an address to the suppression list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddSuppressionParams {
+    pub email: String,
+    pub reason: SuppressReason,
+    /// Webhook event that triggered the suppression, kept for auditing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_event_id: Option<String>,
+}
+
 /// Paginated list of contact lists
 #[derive(Debug, Clone, Deserialize)]
 pub struct ListsResponse {
     pub lists: Vec<List>,
     pub pagination: Pagination,
 }
+
+/// Automation enrollment status
+///
+/// Statuses added to the API after this SDK release arrive as `Other`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum EnrollmentStatus {
+    Active,
+    Paused,
+    Completed,
+    Exited,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// A contact's enrollment in an automation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Enrollment {
+    #[serde(alias = "_id")]
+    pub id: String,
+    pub automation_id: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub automation_name: Option<String>,
+    pub contact_id: String,
+    pub status: EnrollmentStatus,
+    /// Zero-based index of the step the contact is currently on
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub current_step: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub current_step_name: Option<String>,
+    #[serde(default)]
+    pub variables: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub enrolled_at: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub completed_at: Option<String>,
+}
+
+impl Enrollment {
+    /// Decode the enrollment's variables into a typed struct
+    pub fn variables_as<T: serde::de::DeserializeOwned>(&self) -> crate::Result<T> {
+        let map = self.variables.clone().into_iter().collect();
+        Ok(serde_json::from_value(serde_json::Value::Object(map))?)
+    }
+}
+
+/// Parameters for enrolling a contact in an automation
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EnrollParams {
+    pub contact_id: String,
+    /// Values available to the automation's steps, e.g. for template variables
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variables: Option<HashMap<String, serde_json::Value>>,
+}
+
+impl EnrollParams {
+    /// Set `variables` from any struct that serializes to a JSON object
+    pub fn typed_variables<T: Serialize>(mut self, variables: &T) -> crate::Result<Self> {
+        self.variables = Some(serde_json::from_value(serde_json::to_value(variables)?)?);
+        Ok(self)
+    }
+}
+
+/// Field to sort enrollments by
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum EnrollmentSort {
+    EnrolledAt,
+    CompletedAt,
+}
+
+/// Sort direction for list endpoints
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// Parameters for listing automation enrollments
+///
+/// Pass `cursor` from a previous [`EnrollmentsResponse::next_cursor`] to
+/// continue from where that page ended; cursors stay stable while
+/// enrollments are added, unlike `page` offsets.
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::{EnrollmentSort, EnrollmentStatus, ListEnrollmentsParams, SortOrder};
+///
+/// let params = ListEnrollmentsParams {
+///     automation_id: Some("auto_welcome".to_string()),
+///     status: Some(EnrollmentStatus::Active),
+///     enrolled_after: Some("2024-06-01T00:00:00Z".to_string()),
+///     sort_by: Some(EnrollmentSort::EnrolledAt),
+///     sort_order: Some(SortOrder::Desc),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ListEnrollmentsParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub automation_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<EnrollmentStatus>,
+    /// Only enrollments created at or after this RFC 3339 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enrolled_after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_by: Option<EnrollmentSort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort_order: Option<SortOrder>,
+    /// Opaque cursor from a previous response; takes precedence over `page`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+/// Paginated list of enrollments (API returns {enrollments: [], pagination: {}})
+#[derive(Debug, Clone, Deserialize)]
+pub struct EnrollmentsResponse {
+    pub enrollments: Vec<Enrollment>,
+    #[serde(default)]
+    pub pagination: Pagination,
+    /// Cursor for the next page, absent on the last page
+    #[serde(default, rename = "nextCursor")]
+    pub next_cursor: Option<String>,
+}
+
+/// Enrollments for a single contact across all automations
+#[derive(Debug, Clone, Deserialize)]
+pub struct ContactEnrollmentsResponse {
+    pub enrollments: Vec<Enrollment>,
+}
+
+/// Email template
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Template {
+    #[serde(alias = "_id")]
+    pub id: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub text: Option<String>,
+    /// URL the HTML is fetched from at send time, instead of a stored `html` body
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub content_url: Option<String>,
+    #[serde(default)]
+    pub version: i32,
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub updated_at: Option<String>,
+}
+
+/// Parameters for creating a template
+///
+/// Provide either `html` or `content_url`; with `content_url` the page is
+/// fetched each time the template is sent.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTemplateParams {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_url: Option<String>,
+}
+
+/// Parameters for updating a template
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateTemplateParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_url: Option<String>,
+}
+
+/// Parameters for listing templates
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ListTemplatesParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i32>,
+}
+
+/// Paginated list of templates
+#[derive(Debug, Clone, Deserialize)]
+pub struct TemplatesResponse {
+    pub templates: Vec<Template>,
+    pub pagination: Pagination,
+}
+
+/// One line of a line-based diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    Unchanged(String),
+    Added(String),
+    Removed(String),
+}
+
+/// Line-based differences between two versions of a template
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TemplateDiff {
+    pub template_id: String,
+    pub from_version: i32,
+    pub to_version: i32,
+    pub subject: Vec<DiffLine>,
+    pub html: Vec<DiffLine>,
+    pub text: Vec<DiffLine>,
+}
+
+impl TemplateDiff {
+    /// Compare two template versions field by field
+    pub fn between(from: &Template, to: &Template) -> Self {
+        let field = |a: &Option<String>, b: &Option<String>| {
+            diff_lines(a.as_deref().unwrap_or(""), b.as_deref().unwrap_or(""))
+        };
+
+        Self {
+            template_id: to.id.clone(),
+            from_version: from.version,
+            to_version: to.version,
+            subject: field(&from.subject, &to.subject),
+            html: field(&from.html, &to.html),
+            text: field(&from.text, &to.text),
+        }
+    }
+
+    /// Whether any field changed
+    pub fn has_changes(&self) -> bool {
+        [&self.subject, &self.html, &self.text]
+            .iter()
+            .any(|lines| lines.iter().any(|l| !matches!(l, DiffLine::Unchanged(_))))
+    }
+}
+
+/// Renders changed fields as `+`/`-` lines, suitable for a review comment
+impl std::fmt::Display for TemplateDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Template {}: v{} -> v{}",
+            self.template_id, self.from_version, self.to_version
+        )?;
+        for (name, lines) in [
+            ("subject", &self.subject),
+            ("html", &self.html),
+            ("text", &self.text),
+        ] {
+            if lines.iter().all(|l| matches!(l, DiffLine::Unchanged(_))) {
+                continue;
+            }
+            writeln!(f, "--- {}", name)?;
+            for line in lines {
+                match line {
+                    DiffLine::Unchanged(l) => writeln!(f, "  {}", l)?,
+                    DiffLine::Added(l) => writeln!(f, "+ {}", l)?,
+                    DiffLine::Removed(l) => writeln!(f, "- {}", l)?,
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Longest-common-subsequence line diff
+fn diff_lines(a: &str, b: &str) -> Vec<DiffLine> {
+    let a: Vec<&str> = a.lines().collect();
+    let b: Vec<&str> = b.lines().collect();
+
+    // lcs[i][j] = LCS length of a[i..] and b[j..]
+    let mut lcs = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            lines.push(DiffLine::Unchanged(a[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(DiffLine::Removed(a[i].to_string()));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(b[j].to_string()));
+            j += 1;
+        }
+    }
+    lines.extend(a[i..].iter().map(|l| DiffLine::Removed(l.to_string())));
+    lines.extend(b[j..].iter().map(|l| DiffLine::Added(l.to_string())));
+    lines
+}
+
+/// Today's account-wide activity, aggregated across all lists and sends
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSummary {
+    /// Day the figures cover (YYYY-MM-DD, account timezone)
+    pub date: String,
+    #[serde(default)]
+    pub sent: i64,
+    #[serde(default)]
+    pub delivered: i64,
+    #[serde(default)]
+    pub bounced: i64,
+    #[serde(default)]
+    pub new_contacts: i64,
+    #[serde(default)]
+    pub unsubscribed: i64,
+}
+
+/// Branding used in compliance footers
+///
+/// The account default applies unless a sending domain has its own branding.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrandingSettings {
+    /// Sending domain this branding applies to; `None` for the account default
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub domain: Option<String>,
+    /// Footer HTML appended to marketing emails
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub footer: Option<String>,
+    /// Postal address required in commercial email by anti-spam laws
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub physical_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub logo_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub updated_at: Option<String>,
+}
+
+/// Parameters for updating branding; unset fields are left unchanged
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateBrandingParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub physical_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+}
+
+/// A webhook endpoint registered on the account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookEndpoint {
+    #[serde(alias = "_id")]
+    pub id: String,
+    pub url: String,
+    /// Subscribed event types, e.g. `email.bounced` or `account.credit_low`
+    #[serde(default)]
+    pub events: Vec<String>,
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+    /// Signing secret, only returned when the endpoint is created
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub secret: Option<String>,
+    #[serde(default)]
+    pub created_at: String,
+}
+
+/// Parameters for creating a webhook endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CreateWebhookParams {
+    pub url: String,
+    pub events: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Parameters for updating a webhook endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UpdateWebhookParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub events: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// What happens to a reply matched by a [`ReplyRoute`]
+///
+/// Action types added to the API after this SDK release arrive as `Other`,
+/// holding the action object as sent.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ReplyAction {
+    /// Forward the reply to another mailbox
+    Forward { to: String },
+    /// POST the reply to a URL as an `email.replied` event
+    Webhook { url: String },
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+/// A rule that captures replies sent to an address or to emails with a tag
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplyRoute {
+    #[serde(alias = "_id")]
+    pub id: String,
+    /// Address the reply was sent to, e.g. `no-reply@example.com`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub address: Option<String>,
+    /// Tag of the email being replied to
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub tag: Option<String>,
+    pub action: ReplyAction,
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub created_at: String,
+}
+
+/// Parameters for creating a reply route; set either `address` or `tag`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateReplyRouteParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    pub action: ReplyAction,
+}
+
+impl CreateReplyRouteParams {
+    /// Route replies sent to `address`
+    pub fn for_address(address: impl Into<String>, action: ReplyAction) -> Self {
+        Self {
+            address: Some(address.into()),
+            tag: None,
+            action,
+        }
+    }
+
+    /// Route replies to emails sent with `tag`
+    pub fn for_tag(tag: impl Into<String>, action: ReplyAction) -> Self {
+        Self {
+            address: None,
+            tag: Some(tag.into()),
+            action,
+        }
+    }
+}
+
+/// Parameters for updating a reply route
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UpdateReplyRouteParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<ReplyAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+/// Account health metric watched by an [`AlertRule`]
+///
+/// Metrics added to the API after this SDK release arrive as `Other`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum AlertMetric {
+    /// Percentage of recent sends that hard bounced
+    BounceRate,
+    /// Percentage of recent deliveries reported as spam
+    ComplaintRate,
+    /// A sending IP or domain appeared on a blocklist; takes no threshold
+    Blocklist,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Where an alert notification is delivered
+///
+/// Channel types added to the API after this SDK release arrive as `Other`,
+/// holding the channel object as sent.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum AlertChannel {
+    /// POST the alert as JSON, e.g. to a PagerDuty or Opsgenie integration URL
+    Webhook { url: String },
+    /// Email the alert to an address
+    Email { to: String },
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+/// A threshold rule that raises deliverability alerts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertRule {
+    #[serde(alias = "_id")]
+    pub id: String,
+    pub metric: AlertMetric,
+    /// Percentage above which the alert fires; `None` for blocklist rules
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub threshold: Option<f64>,
+    #[serde(default)]
+    pub channels: Vec<AlertChannel>,
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub created_at: String,
+}
+
+/// Parameters for creating an alert rule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAlertRuleParams {
+    pub metric: AlertMetric,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<f64>,
+    pub channels: Vec<AlertChannel>,
+}
+
+impl CreateAlertRuleParams {
+    /// Alert when the bounce rate exceeds `percent`
+    pub fn bounce_rate_above(percent: f64, channel: AlertChannel) -> Self {
+        Self {
+            metric: AlertMetric::BounceRate,
+            threshold: Some(percent),
+            channels: vec![channel],
+        }
+    }
+
+    /// Alert when the complaint rate exceeds `percent`
+    pub fn complaint_rate_above(percent: f64, channel: AlertChannel) -> Self {
+        Self {
+            metric: AlertMetric::ComplaintRate,
+            threshold: Some(percent),
+            channels: vec![channel],
+        }
+    }
+
+    /// Alert when a sending IP or domain is blocklisted
+    pub fn blocklist_hit(channel: AlertChannel) -> Self {
+        Self {
+            metric: AlertMetric::Blocklist,
+            threshold: None,
+            channels: vec![channel],
+        }
+    }
+}
+
+/// Parameters for updating an alert rule
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct UpdateAlertRuleParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub channels: Option<Vec<AlertChannel>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+/// Lifecycle state of a triggered alert
+///
+/// States added to the API after this SDK release arrive as `Other`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum AlertState {
+    Active,
+    Acknowledged,
+    Resolved,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// An alert raised by an [`AlertRule`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Alert {
+    #[serde(alias = "_id")]
+    pub id: String,
+    pub rule_id: String,
+    pub metric: AlertMetric,
+    /// Metric value that crossed the threshold
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub value: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub threshold: Option<f64>,
+    #[serde(default)]
+    pub message: String,
+    pub status: AlertState,
+    #[serde(default)]
+    pub triggered_at: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub acknowledged_at: Option<String>,
+}
+
+/// Identity and permissions of the API key making the request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyInfo {
+    pub key_id: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub key_name: Option<String>,
+    pub account_id: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub account_name: Option<String>,
+    /// Granted scopes, e.g. `emails:send`
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub rate_limit: Option<KeyRateLimit>,
+    /// Sandbox keys accept requests but never deliver email
+    #[serde(default)]
+    pub sandbox: bool,
+}
+
+impl KeyInfo {
+    /// Whether the key holds `scope`
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.iter().any(|s| s == scope)
+    }
+}
+
+/// Rate limits applied to an API key
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyRateLimit {
+    pub requests_per_second: Option<u32>,
+    pub requests_per_minute: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verification_result(status: VerificationStatus) -> VerificationResult {
+        serde_json::from_value(serde_json::json!({
+            "email": "info@example.com",
+            "status": status,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_template_diff() {
+        let template = |version: i32, subject: &str, html: &str| Template {
+            id: "tmpl_1".to_string(),
+            name: "Welcome".to_string(),
+            subject: Some(subject.to_string()),
+            html: Some(html.to_string()),
+            text: None,
+            content_url: None,
+            version,
+            created_at: String::new(),
+            updated_at: None,
+        };
+        let v1 = template(1, "Welcome", "<h1>Hi</h1>\n<p>Thanks</p>");
+        let v2 = template(2, "Welcome", "<h1>Hello</h1>\n<p>Thanks</p>");
+
+        let diff = TemplateDiff::between(&v1, &v2);
+        assert!(diff.has_changes());
+        assert_eq!(
+            diff.subject,
+            vec![DiffLine::Unchanged("Welcome".to_string())]
+        );
+        assert_eq!(
+            diff.html,
+            vec![
+                DiffLine::Removed("<h1>Hi</h1>".to_string()),
+                DiffLine::Added("<h1>Hello</h1>".to_string()),
+                DiffLine::Unchanged("<p>Thanks</p>".to_string()),
+            ]
+        );
+        assert!(diff.text.is_empty());
+
+        let rendered = diff.to_string();
+        assert!(rendered.contains("--- html"));
+        assert!(!rendered.contains("--- subject"));
+        assert!(!TemplateDiff::between(&v1, &v1).has_changes());
+    }
+
+    #[test]
+    fn test_send_email_params_round_trip() {
+        let params = SendEmailParams {
+            from: "sender@example.com".to_string(),
+            to: vec!["recipient@example.com".to_string()],
+            subject: Some("Hello".to_string()),
+            template_id: Some("tmpl_123".to_string()),
+            tags: Some(vec!["welcome".to_string()]),
+            in_reply_to: Some("<msg_1@example.com>".to_string()),
+            references: Some(vec!["<msg_0@example.com>".to_string()]),
+            mjml: Some("<mjml><mj-body></mj-body></mjml>".to_string()),
+            unsubscribe: Some(
+                UnsubscribeConfig::url("https://example.com/unsubscribe?u=42").one_click(true),
+            ),
+            ..Default::default()
+        };
+
+        let json = serde_json::to_string(&params).unwrap();
+        let parsed: SendEmailParams = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(parsed.in_reply_to, params.in_reply_to);
+        assert_eq!(parsed.references, params.references);
+        assert_eq!(parsed.unsubscribe, params.unsubscribe);
+        assert_eq!(parsed.mjml, params.mjml);
+
+        assert_eq!(parsed.from, params.from);
+        assert_eq!(parsed.to, params.to);
+        assert_eq!(parsed.subject, params.subject);
+        assert_eq!(parsed.template_id, params.template_id);
+        assert_eq!(parsed.tags, params.tags);
+        assert!(parsed.html.is_none());
+    }
+
+    #[test]
+    fn test_deserialize_params_from_job_payload() {
+        let params: CreateContactParams = serde_json::from_value(serde_json::json!({
+            "email": "user@example.com",
+            "firstName": "Jane",
+            "consentType": "explicit"
+        }))
+        .unwrap();
+
+        assert_eq!(params.email, "user@example.com");
+        assert_eq!(params.first_name, Some("Jane".to_string()));
+        assert_eq!(params.consent_type, Some(ConsentType::Explicit));
+    }
+
+    #[test]
+    fn test_verification_policy_role_based_as_dirty() {
+        let mut result = verification_result(VerificationStatus::Valid);
+        result.is_role_based = true;
+
+        let lenient = VerificationPolicy::default();
+        assert_eq!(lenient.categorize(&result), VerificationCategory::Clean);
+
+        let strict = VerificationPolicy {
+            allow_role_based: false,
+            ..Default::default()
+        };
+        assert_eq!(strict.categorize(&result), VerificationCategory::Dirty);
+    }
+
+    #[test]
+    fn test_suggestion_falls_back_to_typo_table() {
+        let mut result = verification_result(VerificationStatus::Invalid);
+        assert_eq!(result.suggested_email(), None);
+        assert_eq!(result.apply_suggestion(), "info@example.com");
+
+        result.email = "info@hotmal.com".to_string();
+        assert_eq!(result.apply_suggestion(), "info@hotmail.com");
+
+        // The API's suggestion wins over the local table
+        result.suggestion = Some("info@hotmail.co.uk".to_string());
+        assert_eq!(result.apply_suggestion(), "info@hotmail.co.uk");
+    }
+
+    #[test]
+    fn test_verification_policy_unknown_handling() {
+        let result = verification_result(VerificationStatus::Risky);
+        let policy = VerificationPolicy {
+            treat_unknown_as_dirty: true,
+            ..Default::default()
+        };
+        assert_eq!(policy.categorize(&result), VerificationCategory::Dirty);
+
+        let batch = BatchResults {
+            clean: vec!["a@example.com".to_string()],
+            dirty: vec![],
+            unknown: vec!["b@example.com".to_string()],
+        };
+        let applied = policy.apply_to_batch(&batch);
+        assert_eq!(applied.dirty, vec!["b@example.com".to_string()]);
+        assert!(applied.unknown.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_enum_values_round_trip() {
+        let status: EmailStatus = serde_json::from_str(r#""deferred""#).unwrap();
+        assert_eq!(status, EmailStatus::Other("deferred".to_string()));
+        assert_eq!(serde_json::to_string(&status).unwrap(), r#""deferred""#);
+
+        let status: EmailStatus = serde_json::from_str(r#""delivered""#).unwrap();
+        assert_eq!(status, EmailStatus::Delivered);
+
+        let reason: SuppressReason = serde_json::from_str(r#""legal_hold""#).unwrap();
+        assert_eq!(reason.to_string(), "legal_hold");
+        assert_eq!(
+            serde_json::from_str::<ContactStatus>(r#""archived""#).unwrap(),
+            ContactStatus::Other("archived".to_string())
+        );
+        assert_eq!(
+            serde_json::from_str::<AttachmentStatus>(r#""scanning""#).unwrap(),
+            AttachmentStatus::Other("scanning".to_string())
+        );
+        assert_eq!(
+            serde_json::from_str::<EnrollmentStatus>(r#""waiting""#).unwrap(),
+            EnrollmentStatus::Other("waiting".to_string())
+        );
+        assert_eq!(
+            serde_json::from_str::<AlertMetric>(r#""open_rate""#).unwrap(),
+            AlertMetric::Other("open_rate".to_string())
+        );
+        assert_eq!(
+            serde_json::from_str::<AlertState>(r#""snoozed""#).unwrap(),
+            AlertState::Other("snoozed".to_string())
+        );
+        let ticket = serde_json::json!({"type": "ticket", "queue": "support"});
+        let action: ReplyAction = serde_json::from_value(ticket.clone()).unwrap();
+        assert_eq!(action, ReplyAction::Other(ticket.clone()));
+        assert_eq!(serde_json::to_value(&action).unwrap(), ticket);
+        let slack = serde_json::json!({"type": "slack", "channel": "#deliverability"});
+        let channel: AlertChannel = serde_json::from_value(slack.clone()).unwrap();
+        assert_eq!(channel, AlertChannel::Other(slack.clone()));
+        assert_eq!(serde_json::to_value(&channel).unwrap(), slack);
+        assert_eq!(
+            serde_json::from_value::<AlertChannel>(
+                serde_json::json!({"type": "email", "to": "ops@example.com"})
+            )
+            .unwrap(),
+            AlertChannel::Email {
+                to: "ops@example.com".to_string()
+            }
+        );
+
+        // New verification statuses are treated as unknown, not clean
+        let result = verification_result(VerificationStatus::Other("catch_all".to_string()));
+        assert_eq!(
+            VerificationPolicy::default().categorize(&result),
+            VerificationCategory::Unknown
+        );
+    }
+}
@@ -0,0 +1,53 @@
+//! Offline corrections for commonly mistyped mailbox provider domains
+
+/// Misspelled domain and the provider it almost certainly meant
+const TYPO_DOMAINS: &[(&str, &str)] = &[
+    ("gmial.com", "gmail.com"),
+    ("gmai.com", "gmail.com"),
+    ("gmal.com", "gmail.com"),
+    ("gamil.com", "gmail.com"),
+    ("gnail.com", "gmail.com"),
+    ("gmail.co", "gmail.com"),
+    ("gmail.con", "gmail.com"),
+    ("gmaill.com", "gmail.com"),
+    ("hotmial.com", "hotmail.com"),
+    ("hotmal.com", "hotmail.com"),
+    ("hotmai.com", "hotmail.com"),
+    ("hotmail.co", "hotmail.com"),
+    ("hotmail.con", "hotmail.com"),
+    ("yaho.com", "yahoo.com"),
+    ("yahooo.com", "yahoo.com"),
+    ("yahoo.co", "yahoo.com"),
+    ("yahoo.con", "yahoo.com"),
+    ("outlok.com", "outlook.com"),
+    ("outloo.com", "outlook.com"),
+    ("outlook.co", "outlook.com"),
+    ("iclod.com", "icloud.com"),
+    ("icloud.co", "icloud.com"),
+    ("icoud.com", "icloud.com"),
+];
+
+/// `email` with its domain corrected, if the domain is a known typo
+pub(crate) fn correct_domain(email: &str) -> Option<String> {
+    let (local, domain) = email.trim().rsplit_once('@')?;
+    let domain = domain.to_ascii_lowercase();
+    TYPO_DOMAINS
+        .iter()
+        .find(|(typo, _)| *typo == domain)
+        .map(|(_, fixed)| format!("{}@{}", local, fixed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_correct_domain() {
+        assert_eq!(
+            correct_domain("Jane.Doe@GMIAL.com").as_deref(),
+            Some("Jane.Doe@gmail.com")
+        );
+        assert_eq!(correct_domain("jane@gmail.com"), None);
+        assert_eq!(correct_domain("not-an-email"), None);
+    }
+}
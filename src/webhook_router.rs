@@ -0,0 +1,502 @@
+//! Dispatch webhook events to handlers, at most once per event ID
+//!
+//! MailBreeze retries deliveries until it gets a 2xx, so the same event can
+//! arrive more than once. [`WebhookRouter`] records event IDs in a
+//! [`DedupeStore`] and skips events it has already handled. A redelivery
+//! that arrives while the first is still being handled is reported as
+//! [`Dispatch::InFlight`], to be answered with a non-2xx so it is retried.
+
+use crate::error::Result;
+use crate::webhooks::WebhookEvent;
+use async_trait::async_trait;
+use futures_util::future::BoxFuture;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+/// Event IDs remembered by [`MemoryDedupeStore::default`]
+const DEFAULT_DEDUPE_CAPACITY: usize = 10_000;
+
+/// Error returned by a webhook handler
+pub type HandlerError = Box<dyn std::error::Error + Send + Sync>;
+
+type Handler = Box<
+    dyn Fn(WebhookEvent) -> BoxFuture<'static, std::result::Result<(), HandlerError>> + Send + Sync,
+>;
+
+/// Remembers which webhook events are being handled or have been handled
+///
+/// Implement this over Redis or a database table to deduplicate across
+/// processes and restarts.
+#[async_trait]
+pub trait DedupeStore: std::fmt::Debug + Send + Sync {
+    /// Claim `event_id`, returning `false` if it was already claimed
+    async fn claim(&self, event_id: &str) -> Result<bool>;
+
+    /// Mark a claimed event as handled
+    async fn complete(&self, event_id: &str) -> Result<()>;
+
+    /// Whether `event_id` was marked handled, as opposed to only claimed
+    async fn is_complete(&self, event_id: &str) -> Result<bool>;
+
+    /// Release a claim after its handler failed, so a redelivery is processed again
+    async fn release(&self, event_id: &str) -> Result<()>;
+}
+
+/// In-process [`DedupeStore`] that keeps the most recent event IDs
+#[derive(Debug)]
+pub struct MemoryDedupeStore {
+    capacity: usize,
+    seen: Mutex<(HashSet<String>, VecDeque<String>)>,
+    completed: Mutex<HashSet<String>>,
+}
+
+impl MemoryDedupeStore {
+    /// Remember up to `capacity` event IDs, forgetting the oldest first
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            seen: Mutex::default(),
+            completed: Mutex::default(),
+        }
+    }
+}
+
+impl Default for MemoryDedupeStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_DEDUPE_CAPACITY)
+    }
+}
+
+#[async_trait]
+impl DedupeStore for MemoryDedupeStore {
+    async fn claim(&self, event_id: &str) -> Result<bool> {
+        let mut guard = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+        let (ids, order) = &mut *guard;
+        if !ids.insert(event_id.to_string()) {
+            return Ok(false);
+        }
+        order.push_back(event_id.to_string());
+        if order.len() > self.capacity {
+            if let Some(oldest) = order.pop_front() {
+                ids.remove(&oldest);
+                let mut completed = self.completed.lock().unwrap_or_else(|e| e.into_inner());
+                completed.remove(&oldest);
+            }
+        }
+        Ok(true)
+    }
+
+    async fn complete(&self, event_id: &str) -> Result<()> {
+        let guard = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+        // Already forgotten if the store is over capacity
+        if guard.0.contains(event_id) {
+            let mut completed = self.completed.lock().unwrap_or_else(|e| e.into_inner());
+            completed.insert(event_id.to_string());
+        }
+        Ok(())
+    }
+
+    async fn is_complete(&self, event_id: &str) -> Result<bool> {
+        let completed = self.completed.lock().unwrap_or_else(|e| e.into_inner());
+        Ok(completed.contains(event_id))
+    }
+
+    async fn release(&self, event_id: &str) -> Result<()> {
+        let mut guard = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+        let (ids, order) = &mut *guard;
+        ids.remove(event_id);
+        order.retain(|id| id != event_id);
+        let mut completed = self.completed.lock().unwrap_or_else(|e| e.into_inner());
+        completed.remove(event_id);
+        Ok(())
+    }
+}
+
+/// What the router did with an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dispatch {
+    /// A handler ran successfully
+    Handled,
+    /// The event ID was already handled; nothing ran
+    Duplicate,
+    /// Another delivery of the event is still being handled; nothing ran
+    ///
+    /// Answer with a non-2xx status (e.g. 409) so the platform redelivers it
+    /// in case that handler fails.
+    InFlight,
+    /// No handler is registered for the event type
+    Unhandled,
+}
+
+/// Routes webhook events to handlers by event type
+///
+/// Events are deduplicated by ID through an in-memory [`DedupeStore`] unless
+/// another store is configured.
+///
+/// # Example
+/// ```rust,no_run
+/// use mailbreeze::{Dispatch, WebhookPayload, WebhookRouter};
+///
+/// # async fn run(body: &[u8]) -> Result<(), mailbreeze::HandlerError> {
+/// let router = WebhookRouter::new()
+///     .on("email.bounced", |event| async move {
+///         if let WebhookPayload::EmailBounced(bounce) = event.payload {
+///             println!("{} bounced", bounce.email);
+///         }
+///         Ok(())
+///     });
+///
+/// // In your HTTP handler: respond 2xx once this returns Ok, unless the
+/// // event is still being handled by another delivery
+/// if router.handle(body).await? == Dispatch::InFlight {
+///     // respond 409
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct WebhookRouter {
+    handlers: HashMap<String, Handler>,
+    fallback: Option<Handler>,
+    dedupe: Option<Arc<dyn DedupeStore>>,
+}
+
+impl Default for WebhookRouter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl std::fmt::Debug for WebhookRouter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut events: Vec<&String> = self.handlers.keys().collect();
+        events.sort();
+        f.debug_struct("WebhookRouter")
+            .field("handlers", &events)
+            .field("fallback", &self.fallback.is_some())
+            .field("dedupe", &self.dedupe)
+            .finish()
+    }
+}
+
+impl WebhookRouter {
+    pub fn new() -> Self {
+        Self {
+            handlers: HashMap::new(),
+            fallback: None,
+            dedupe: Some(Arc::new(MemoryDedupeStore::default())),
+        }
+    }
+
+    /// Handle events of `event_type`, e.g. `email.bounced`
+    pub fn on<F, Fut>(mut self, event_type: impl Into<String>, handler: F) -> Self
+    where
+        F: Fn(WebhookEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = std::result::Result<(), HandlerError>> + Send + 'static,
+    {
+        self.handlers.insert(
+            event_type.into(),
+            Box::new(move |event| Box::pin(handler(event))),
+        );
+        self
+    }
+
+    /// Handle events that have no specific handler
+    pub fn fallback<F, Fut>(mut self, handler: F) -> Self
+    where
+        F: Fn(WebhookEvent) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = std::result::Result<(), HandlerError>> + Send + 'static,
+    {
+        self.fallback = Some(Box::new(move |event| Box::pin(handler(event))));
+        self
+    }
+
+    /// Deduplicate through `store` instead of the in-memory default
+    pub fn dedupe_store(mut self, store: impl DedupeStore + 'static) -> Self {
+        self.dedupe = Some(Arc::new(store));
+        self
+    }
+
+    /// Run handlers for every delivery, including redeliveries
+    pub fn without_dedupe(mut self) -> Self {
+        self.dedupe = None;
+        self
+    }
+
+    /// Parse a webhook request body and dispatch it
+    pub async fn handle(&self, body: &[u8]) -> std::result::Result<Dispatch, HandlerError> {
+        let event = WebhookEvent::from_slice(body)?;
+        self.dispatch(event).await
+    }
+
+    /// Dispatch a parsed event to its handler
+    ///
+    /// If the handler fails, panics or is dropped before finishing (e.g. the
+    /// server cancels the request), the event ID is released so the
+    /// platform's redelivery gets another chance.
+    pub async fn dispatch(
+        &self,
+        event: WebhookEvent,
+    ) -> std::result::Result<Dispatch, HandlerError> {
+        let Some(handler) = self
+            .handlers
+            .get(&event.event_type)
+            .or(self.fallback.as_ref())
+        else {
+            return Ok(Dispatch::Unhandled);
+        };
+
+        let claim = match &self.dedupe {
+            Some(store) => {
+                if !store.claim(&event.id).await? {
+                    return Ok(if store.is_complete(&event.id).await? {
+                        Dispatch::Duplicate
+                    } else {
+                        Dispatch::InFlight
+                    });
+                }
+                Some(Claim {
+                    store: store.clone(),
+                    event_id: Some(event.id.clone()),
+                })
+            }
+            None => None,
+        };
+
+        match handler(event).await {
+            Ok(()) => {
+                if let Some(claim) = claim {
+                    claim.complete().await;
+                }
+                Ok(Dispatch::Handled)
+            }
+            Err(e) => {
+                if let Some(claim) = claim {
+                    claim.release().await;
+                }
+                Err(e)
+            }
+        }
+    }
+}
+
+/// A claimed event ID, released when dropped unless kept
+struct Claim {
+    store: Arc<dyn DedupeStore>,
+    /// `None` once kept or released
+    event_id: Option<String>,
+}
+
+impl Claim {
+    /// The handler succeeded: keep the ID claimed and mark it handled
+    ///
+    /// A store error is only logged; the handler's work is done either way.
+    async fn complete(mut self) {
+        let Some(event_id) = self.event_id.take() else {
+            return;
+        };
+        if let Err(e) = self.store.complete(&event_id).await {
+            log::warn!(target: "mailbreeze", "completing webhook event {} failed: {}", event_id, e);
+        }
+    }
+
+    /// The handler failed: release the ID, logging a store error so the
+    /// handler's error is the one returned
+    async fn release(mut self) {
+        let Some(event_id) = self.event_id.take() else {
+            return;
+        };
+        if let Err(e) = self.store.release(&event_id).await {
+            log::warn!(target: "mailbreeze", "releasing webhook event {} failed: {}", event_id, e);
+        }
+    }
+}
+
+impl Drop for Claim {
+    fn drop(&mut self) {
+        // The handler panicked or its future was dropped mid-run
+        let Some(event_id) = self.event_id.take() else {
+            return;
+        };
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            log::warn!(target: "mailbreeze", "webhook event {} left claimed: no runtime", event_id);
+            return;
+        };
+        let store = self.store.clone();
+        runtime.spawn(async move {
+            if let Err(e) = store.release(&event_id).await {
+                log::warn!(target: "mailbreeze", "releasing webhook event {} failed: {}", event_id, e);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn body(id: &str, event_type: &str) -> Vec<u8> {
+        serde_json::json!({
+            "id": id,
+            "type": event_type,
+            "data": {"messageId": "msg_1", "email": "a@example.com"}
+        })
+        .to_string()
+        .into_bytes()
+    }
+
+    #[tokio::test]
+    async fn test_redelivered_event_is_handled_once() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = calls.clone();
+        let router = WebhookRouter::new().on("email.delivered", move |_| {
+            let counter = counter.clone();
+            async move {
+                counter.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+        });
+
+        let delivery = body("evt_1", "email.delivered");
+        assert_eq!(router.handle(&delivery).await.unwrap(), Dispatch::Handled);
+        assert_eq!(router.handle(&delivery).await.unwrap(), Dispatch::Duplicate);
+        assert_eq!(
+            router.handle(&body("evt_2", "email.opened")).await.unwrap(),
+            Dispatch::Unhandled
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_handler_releases_event() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = calls.clone();
+        let router = WebhookRouter::new().fallback(move |_| {
+            let attempt = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if attempt == 0 {
+                    return Err("database unavailable".into());
+                }
+                Ok(())
+            }
+        });
+
+        let delivery = body("evt_1", "email.bounced");
+        assert!(router.handle(&delivery).await.is_err());
+        assert_eq!(router.handle(&delivery).await.unwrap(), Dispatch::Handled);
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_or_panicking_handler_releases_event() {
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = calls.clone();
+        let router = Arc::new(WebhookRouter::new().fallback(move |_| {
+            let attempt = counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                match attempt {
+                    0 => tokio::time::sleep(std::time::Duration::from_secs(60)).await,
+                    1 => panic!("handler bug"),
+                    _ => {}
+                }
+                Ok(())
+            }
+        }));
+        let delivery = body("evt_1", "email.bounced");
+
+        // The server gives up on the request and drops the future
+        let dispatch = router.handle(&delivery);
+        let cancelled = tokio::time::timeout(std::time::Duration::from_millis(10), dispatch);
+        assert!(cancelled.await.is_err());
+        tokio::task::yield_now().await;
+
+        let panicking = {
+            let router = router.clone();
+            let delivery = delivery.clone();
+            tokio::spawn(async move { router.handle(&delivery).await.map_err(|e| e.to_string()) })
+        };
+        assert!(panicking.await.unwrap_err().is_panic());
+        tokio::task::yield_now().await;
+
+        assert_eq!(router.handle(&delivery).await.unwrap(), Dispatch::Handled);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_memory_store_forgets_oldest() {
+        let store = MemoryDedupeStore::new(2);
+        for id in ["a", "b", "c"] {
+            assert!(store.claim(id).await.unwrap());
+        }
+        assert!(store.claim("a").await.unwrap());
+        assert!(!store.claim("c").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_redelivery_during_handling_is_in_flight() {
+        let (started_tx, started_rx) = tokio::sync::oneshot::channel::<()>();
+        let (finish_tx, finish_rx) = tokio::sync::oneshot::channel::<()>();
+        let started_tx = Mutex::new(Some(started_tx));
+        let finish_rx = Arc::new(tokio::sync::Mutex::new(Some(finish_rx)));
+        let router = Arc::new(WebhookRouter::new().fallback(move |_| {
+            if let Some(tx) = started_tx.lock().unwrap().take() {
+                let _ = tx.send(());
+            }
+            let finish_rx = finish_rx.clone();
+            async move {
+                if let Some(rx) = finish_rx.lock().await.take() {
+                    let _ = rx.await;
+                }
+                Ok(())
+            }
+        }));
+        let delivery = body("evt_1", "email.bounced");
+
+        let first = {
+            let router = router.clone();
+            let delivery = delivery.clone();
+            tokio::spawn(async move { router.handle(&delivery).await.map_err(|e| e.to_string()) })
+        };
+        started_rx.await.unwrap();
+        assert_eq!(router.handle(&delivery).await.unwrap(), Dispatch::InFlight);
+
+        finish_tx.send(()).unwrap();
+        assert_eq!(first.await.unwrap().unwrap(), Dispatch::Handled);
+        assert_eq!(router.handle(&delivery).await.unwrap(), Dispatch::Duplicate);
+    }
+
+    #[tokio::test]
+    async fn test_release_failure_keeps_handler_error() {
+        #[derive(Debug, Default)]
+        struct FailingRelease(MemoryDedupeStore);
+
+        #[async_trait]
+        impl DedupeStore for FailingRelease {
+            async fn claim(&self, event_id: &str) -> Result<bool> {
+                self.0.claim(event_id).await
+            }
+
+            async fn complete(&self, event_id: &str) -> Result<()> {
+                self.0.complete(event_id).await
+            }
+
+            async fn is_complete(&self, event_id: &str) -> Result<bool> {
+                self.0.is_complete(event_id).await
+            }
+
+            async fn release(&self, _event_id: &str) -> Result<()> {
+                Err(crate::Error::Timeout)
+            }
+        }
+
+        let router = WebhookRouter::new()
+            .dedupe_store(FailingRelease::default())
+            .fallback(|_| async { Err("database unavailable".into()) });
+
+        let error = router
+            .handle(&body("evt_1", "email.bounced"))
+            .await
+            .unwrap_err();
+        assert_eq!(error.to_string(), "database unavailable");
+    }
+}
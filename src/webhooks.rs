@@ -0,0 +1,310 @@
+//! Typed webhook events
+//!
+//! MailBreeze POSTs events as `{"id", "type", "createdAt", "data"}`; parse the
+//! raw request body with [`WebhookEvent::from_slice`].
+
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A webhook event delivered by MailBreeze
+///
+/// # Example
+/// ```rust
+/// use mailbreeze::{WebhookEvent, WebhookPayload};
+///
+/// let body = br#"{
+///     "id": "evt_1",
+///     "type": "email.replied",
+///     "createdAt": "2024-01-01T00:00:00Z",
+///     "data": {"messageId": "msg_123", "from": "customer@example.com", "text": "Thanks!"}
+/// }"#;
+///
+/// let event = WebhookEvent::from_slice(body)?;
+/// if let WebhookPayload::EmailReplied(reply) = &event.payload {
+///     println!("{} replied to {}", reply.from, reply.message_id);
+/// }
+/// # Ok::<(), mailbreeze::Error>(())
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "RawWebhookEvent", into = "RawWebhookEvent")]
+pub struct WebhookEvent {
+    /// Unique event ID; redeliveries of the same event keep the same ID
+    pub id: String,
+    /// Event type as sent by the API, e.g. `email.bounced`
+    pub event_type: String,
+    pub created_at: String,
+    pub payload: WebhookPayload,
+}
+
+impl WebhookEvent {
+    /// Parse a webhook request body
+    pub fn from_slice(body: &[u8]) -> Result<Self> {
+        Ok(serde_json::from_slice(body)?)
+    }
+
+    /// Whether this is an account-level event rather than an email event
+    pub fn is_account_event(&self) -> bool {
+        matches!(
+            self.payload,
+            WebhookPayload::AccountCreditLow(_)
+                | WebhookPayload::DomainVerificationChanged(_)
+                | WebhookPayload::ApiKeyCreated(_)
+        )
+    }
+}
+
+/// Event-specific data, selected by the event type
+#[derive(Debug, Clone)]
+pub enum WebhookPayload {
+    EmailSent(EmailEventData),
+    EmailDelivered(EmailEventData),
+    EmailOpened(EmailEventData),
+    EmailClicked(EmailEventData),
+    EmailBounced(BounceEventData),
+    EmailComplained(EmailEventData),
+    /// A recipient replied to a message sent with `reply_tracking` enabled
+    EmailReplied(ReplyEventData),
+    /// Prepaid credit balance fell below the alert threshold
+    AccountCreditLow(CreditLowEventData),
+    /// A sending domain's verification status changed
+    DomainVerificationChanged(DomainVerificationEventData),
+    /// A new API key was created on the account
+    ApiKeyCreated(ApiKeyCreatedEventData),
+    /// An event type this SDK version does not know about, with its raw data
+    Unknown(serde_json::Value),
+}
+
+/// Wire format of a webhook event
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawWebhookEvent {
+    id: String,
+    #[serde(rename = "type")]
+    event_type: String,
+    #[serde(default)]
+    created_at: String,
+    #[serde(default)]
+    data: serde_json::Value,
+}
+
+impl TryFrom<RawWebhookEvent> for WebhookEvent {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawWebhookEvent) -> std::result::Result<Self, Self::Error> {
+        use serde_json::from_value;
+
+        let payload = match raw.event_type.as_str() {
+            "email.sent" => WebhookPayload::EmailSent(from_value(raw.data)?),
+            "email.delivered" => WebhookPayload::EmailDelivered(from_value(raw.data)?),
+            "email.opened" => WebhookPayload::EmailOpened(from_value(raw.data)?),
+            "email.clicked" => WebhookPayload::EmailClicked(from_value(raw.data)?),
+            "email.bounced" => WebhookPayload::EmailBounced(from_value(raw.data)?),
+            "email.complained" => WebhookPayload::EmailComplained(from_value(raw.data)?),
+            "email.replied" => WebhookPayload::EmailReplied(from_value(raw.data)?),
+            "account.credit_low" => WebhookPayload::AccountCreditLow(from_value(raw.data)?),
+            "domain.verification_changed" => {
+                WebhookPayload::DomainVerificationChanged(from_value(raw.data)?)
+            }
+            "api_key.created" => WebhookPayload::ApiKeyCreated(from_value(raw.data)?),
+            _ => WebhookPayload::Unknown(raw.data),
+        };
+
+        Ok(Self {
+            id: raw.id,
+            event_type: raw.event_type,
+            created_at: raw.created_at,
+            payload,
+        })
+    }
+}
+
+impl From<WebhookEvent> for RawWebhookEvent {
+    fn from(event: WebhookEvent) -> Self {
+        use serde_json::to_value;
+
+        let data = match event.payload {
+            WebhookPayload::EmailSent(d)
+            | WebhookPayload::EmailDelivered(d)
+            | WebhookPayload::EmailOpened(d)
+            | WebhookPayload::EmailClicked(d)
+            | WebhookPayload::EmailComplained(d) => to_value(d),
+            WebhookPayload::EmailBounced(d) => to_value(d),
+            WebhookPayload::EmailReplied(d) => to_value(d),
+            WebhookPayload::AccountCreditLow(d) => to_value(d),
+            WebhookPayload::DomainVerificationChanged(d) => to_value(d),
+            WebhookPayload::ApiKeyCreated(d) => to_value(d),
+            WebhookPayload::Unknown(data) => Ok(data),
+        };
+
+        Self {
+            id: event.id,
+            event_type: event.event_type,
+            created_at: event.created_at,
+            data: data.unwrap_or_default(),
+        }
+    }
+}
+
+/// Data common to email lifecycle events
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailEventData {
+    pub message_id: String,
+    /// Recipient address the event applies to
+    #[serde(default)]
+    pub email: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub url: Option<String>,
+    /// `metadata` the email was sent with
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// Data for `email.bounced`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BounceEventData {
+    pub message_id: String,
+    #[serde(default)]
+    pub email: String,
+    /// `hard` or `soft`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub bounce_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub reason: Option<String>,
+    /// `metadata` the email was sent with
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub metadata: Option<HashMap<String, String>>,
+}
+
+/// Data for `email.replied`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplyEventData {
+    /// Message ID of the original email that was replied to
+    pub message_id: String,
+    /// Address the reply came from
+    pub from: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub html: Option<String>,
+    #[serde(default)]
+    pub received_at: String,
+}
+
+/// Data for `account.credit_low`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreditLowEventData {
+    pub balance: f64,
+    pub threshold: f64,
+}
+
+/// Data for `domain.verification_changed`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainVerificationEventData {
+    pub domain: String,
+    /// New status, e.g. `verified` or `failed`
+    pub status: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub previous_status: Option<String>,
+}
+
+/// Data for `api_key.created`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyCreatedEventData {
+    pub key_id: String,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub created_by: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reply_event() {
+        let body = serde_json::json!({
+            "id": "evt_1",
+            "type": "email.replied",
+            "createdAt": "2024-01-01T00:00:00Z",
+            "data": {
+                "messageId": "msg_123",
+                "from": "customer@example.com",
+                "subject": "Re: Your order",
+                "text": "Thanks!",
+                "receivedAt": "2024-01-01T00:00:00Z"
+            }
+        });
+
+        let event = WebhookEvent::from_slice(body.to_string().as_bytes()).unwrap();
+        assert_eq!(event.id, "evt_1");
+        assert_eq!(event.event_type, "email.replied");
+        match event.payload {
+            WebhookPayload::EmailReplied(reply) => {
+                assert_eq!(reply.message_id, "msg_123");
+                assert_eq!(reply.from, "customer@example.com");
+            }
+            other => panic!("unexpected payload: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_bounce_event_with_metadata() {
+        let body = br#"{
+            "id": "evt_2",
+            "type": "email.bounced",
+            "data": {
+                "messageId": "msg_123",
+                "email": "gone@example.com",
+                "bounceType": "hard",
+                "metadata": {"orderId": "1042"}
+            }
+        }"#;
+
+        let event = WebhookEvent::from_slice(body).unwrap();
+        match event.payload {
+            WebhookPayload::EmailBounced(bounce) => {
+                let metadata = bounce.metadata.unwrap();
+                assert_eq!(metadata["orderId"], "1042");
+            }
+            other => panic!("unexpected payload: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_account_event() {
+        let body = br#"{
+            "id": "evt_3",
+            "type": "account.credit_low",
+            "data": {"balance": 12.5, "threshold": 50}
+        }"#;
+
+        let event = WebhookEvent::from_slice(body).unwrap();
+        assert!(event.is_account_event());
+        match event.payload {
+            WebhookPayload::AccountCreditLow(data) => assert_eq!(data.balance, 12.5),
+            other => panic!("unexpected payload: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unknown_event_type() {
+        let body = br#"{"id": "evt_2", "type": "email.teleported", "data": {}}"#;
+        let event = WebhookEvent::from_slice(body).unwrap();
+        assert!(matches!(event.payload, WebhookPayload::Unknown(_)));
+
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "email.teleported");
+    }
+}